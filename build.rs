@@ -12,6 +12,12 @@ fn main() {
         return;
     }
 
+    // The `mock` feature replaces the bindings with a software stub, so there
+    // is nothing to generate or link against
+    if env::var("CARGO_FEATURE_MOCK").is_ok() {
+        return;
+    }
+
     // link hipBLAS
     println!("cargo:rustc-link-lib=dylib=hipblas");
 
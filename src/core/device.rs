@@ -1,19 +1,59 @@
 #[allow(unused_imports)]
 use super::result::{HipError, HipResult, HipStatus};
-use super::{DeviceAttribute, DeviceP2PAttribute, MemPool, PCIBusId};
+use super::{ClockInfo, DeviceAttribute, DeviceP2PAttribute, MemPool, PCIBusId};
 use crate::result::ResultExt;
 use crate::sys;
 use semver::Version;
+use std::collections::BTreeSet;
 use std::ffi::CStr;
 use std::i32;
+use std::sync::Mutex;
 use uuid::Uuid;
 
+/// Per-device reset generation, indexed by device ID.
+///
+/// Bumped every time [`Device::reset`] succeeds, so that resources allocated
+/// before the reset can detect that their handles are no longer valid.
+static DEVICE_GENERATIONS: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+
+/// `(from, to)` device ID pairs for which peer access has been enabled.
+///
+/// HIP has no query for the current state, so [`enable_peer_access`] and
+/// [`disable_peer_access`] record it here for [`peer_access_enabled`].
+static PEER_ACCESS: Mutex<BTreeSet<(i32, i32)>> = Mutex::new(BTreeSet::new());
+
+/// Returns the current reset generation of the device with the given ID.
+pub(crate) fn device_generation(device_id: i32) -> u64 {
+    let generations = DEVICE_GENERATIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    generations.get(device_id as usize).copied().unwrap_or(0)
+}
+
+/// Advances the reset generation of the device with the given ID.
+fn bump_device_generation(device_id: i32) {
+    let mut generations = DEVICE_GENERATIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let index = device_id as usize;
+    if generations.len() <= index {
+        generations.resize(index + 1, 0);
+    }
+    generations[index] += 1;
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Device {
     pub(crate) id: i32,
 }
 
 impl Device {
+    /// Sentinel device representing the host CPU (`hipCpuDeviceId`).
+    ///
+    /// Used as a target for operations such as [`crate::MemoryPointer::prefetch_async`]
+    /// to move managed memory back to host memory.
+    pub const CPU: Device = Device { id: -1 };
+
     /// Creates a new Device handle representing a HIP device.
     ///
     /// # Arguments
@@ -49,7 +89,9 @@ impl Device {
         unsafe {
             let mut major: i32 = -1;
             let mut minor: i32 = -1;
-            let code = sys::hipDeviceComputeCapability(&mut major, &mut minor, self.id);
+            let code = record_call!(sys::hipDeviceComputeCapability(
+                &mut major, &mut minor, self.id
+            ));
             let version = Version::new(major as u64, minor as u64, 0);
             (version, code).to_result()
         }
@@ -67,7 +109,7 @@ impl Device {
     pub fn device_total_mem(&self) -> HipResult<usize> {
         unsafe {
             let mut size: usize = 0;
-            let code = sys::hipDeviceTotalMem(&mut size, self.id);
+            let code = record_call!(sys::hipDeviceTotalMem(&mut size, self.id));
             (size, code).to_result()
         }
     }
@@ -88,7 +130,11 @@ impl Device {
         let mut buffer = vec![0i8; buffer_size];
 
         unsafe {
-            let code = sys::hipDeviceGetName(buffer.as_mut_ptr(), buffer.len() as i32, self.id);
+            let code = record_call!(sys::hipDeviceGetName(
+                buffer.as_mut_ptr(),
+                buffer.len() as i32,
+                self.id
+            ));
             // Convert the C string to a Rust String
             let c_str = CStr::from_ptr(buffer.as_ptr());
             (c_str.to_string_lossy().into_owned(), code).to_result()
@@ -111,7 +157,7 @@ impl Device {
     fn get_device_uuid_bytes(&self) -> HipResult<[i8; 16]> {
         let mut hip_bytes = sys::hipUUID_t { bytes: [0; 16] };
         unsafe {
-            let code = sys::hipDeviceGetUuid(&mut hip_bytes, self.id);
+            let code = record_call!(sys::hipDeviceGetUuid(&mut hip_bytes, self.id));
             (hip_bytes.bytes, code).to_result()
         }
     }
@@ -138,6 +184,36 @@ impl Device {
         })
     }
 
+    /// Gets the locally unique identifier (LUID) of this device.
+    ///
+    /// The LUID is an opaque 8-byte identifier used by graphics APIs to match
+    /// adapters. It cannot be read through [`Device::get_attribute`], which only
+    /// returns a single `i32`, so it is taken from the device properties instead.
+    ///
+    /// # Returns
+    /// * `HipResult<[u8; 8]>` - The LUID bytes if successful
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The platform has no LUIDs (`HipStatus::NotSupported`), which is the case
+    ///   outside of Windows
+    /// * The device is invalid
+    /// * The runtime is not initialized
+    pub fn luid(&self) -> HipResult<[u8; 8]> {
+        let mut props: sys::hipDeviceProp_tR0600 = unsafe { std::mem::zeroed() };
+        unsafe {
+            let code = record_call!(sys::hipGetDevicePropertiesR0600(&mut props, self.id));
+            ((), code).to_result()?;
+        }
+
+        // A zero node mask means the runtime did not report a LUID
+        if props.luidDeviceNodeMask == 0 {
+            return Err(HipError::from_status(HipStatus::NotSupported));
+        }
+
+        Ok(props.luid.map(|b| b as u8))
+    }
+
     /// Gets the PCI bus ID string for a HIP device.
     ///
     /// # Arguments
@@ -154,8 +230,11 @@ impl Device {
     pub fn get_device_pci_bus_id(&self) -> HipResult<PCIBusId> {
         let mut pci_bus_id = PCIBusId::new();
         unsafe {
-            let code =
-                sys::hipDeviceGetPCIBusId(pci_bus_id.as_mut_ptr(), pci_bus_id.len(), self.id);
+            let code = record_call!(sys::hipDeviceGetPCIBusId(
+                pci_bus_id.as_mut_ptr(),
+                pci_bus_id.len(),
+                self.id
+            ));
             (pci_bus_id, code).to_result()
         }
     }
@@ -173,7 +252,7 @@ impl Device {
     pub fn get_default_mem_pool(&self) -> HipResult<MemPool> {
         let mut mem_pool = std::ptr::null_mut();
         unsafe {
-            let code = sys::hipDeviceGetDefaultMemPool(&mut mem_pool, self.id);
+            let code = record_call!(sys::hipDeviceGetDefaultMemPool(&mut mem_pool, self.id));
             (MemPool::from_raw(mem_pool), code).to_result()
         }
     }
@@ -210,11 +289,243 @@ impl Device {
     pub fn get_attribute(&self, attr: DeviceAttribute) -> HipResult<i32> {
         let mut value: i32 = 0;
         unsafe {
-            let code = sys::hipDeviceGetAttribute(&mut value, attr.into(), self.id);
+            let code = record_call!(sys::hipDeviceGetAttribute(&mut value, attr.into(), self.id));
             (value, code).to_result()
         }
     }
 
+    /// Gets the number of asynchronous copy engines of this device.
+    ///
+    /// Each copy engine can perform one host/device transfer concurrently with kernel
+    /// execution, so this is the number of transfers that can overlap with compute.
+    /// Use it to decide how many streams to dedicate to transfers.
+    ///
+    /// # Returns
+    /// * `HipResult<i32>` - The number of asynchronous engines if successful
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn async_engine_count(&self) -> HipResult<i32> {
+        self.get_attribute(DeviceAttribute::AsyncEngineCount)
+    }
+
+    /// Gets the core, memory and wall clock rates of this device.
+    ///
+    /// Profilers need these to convert cycle counts read on the device to time.
+    ///
+    /// # Returns
+    /// * `HipResult<ClockInfo>` - The clock rates in kHz if successful
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn clocks(&self) -> HipResult<ClockInfo> {
+        Ok(ClockInfo {
+            core_clock_khz: self.get_attribute(DeviceAttribute::ClockRate)?,
+            memory_clock_khz: self.get_attribute(DeviceAttribute::MemoryClockRate)?,
+            wall_clock_khz: self.get_attribute(DeviceAttribute::WallClockRate)?,
+        })
+    }
+
+    /// Gets the alignment, in bytes, required for memory bound to a texture.
+    ///
+    /// Texture base addresses must be a multiple of this value.
+    ///
+    /// # Returns
+    /// * `HipResult<usize>` - The texture alignment in bytes if successful
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn texture_alignment(&self) -> HipResult<usize> {
+        self.get_attribute(DeviceAttribute::TextureAlignment)
+            .map(|alignment| alignment as usize)
+    }
+
+    /// Gets the alignment, in bytes, required for the row pitch of pitched memory
+    /// bound to a 2D texture.
+    ///
+    /// Pad each row of a pitched allocation to a multiple of this value before
+    /// binding it to a texture.
+    ///
+    /// # Returns
+    /// * `HipResult<usize>` - The texture pitch alignment in bytes if successful
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn texture_pitch_alignment(&self) -> HipResult<usize> {
+        self.get_attribute(DeviceAttribute::TexturePitchAlignment)
+            .map(|alignment| alignment as usize)
+    }
+
+    /// Gets the ratio of single precision to double precision throughput.
+    ///
+    /// A ratio of 32 means f64 arithmetic runs at 1/32 of the f32 rate, as on many
+    /// consumer cards, while compute accelerators typically report 1 or 2. Use it
+    /// to decide whether an f64 code path is affordable on this device.
+    ///
+    /// # Returns
+    /// * `HipResult<i32>` - The f32 to f64 performance ratio if successful
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn fp32_to_fp64_ratio(&self) -> HipResult<i32> {
+        self.get_attribute(DeviceAttribute::SingleToDoublePrecisionPerfRatio)
+    }
+
+    /// Gets the multi-GPU board group this device belongs to.
+    ///
+    /// Devices on the same multi-GPU board share a group ID and are tightly
+    /// coupled, so schedulers may want to keep work on them together.
+    ///
+    /// # Returns
+    /// * `HipResult<Option<i32>>` - `Some(group_id)` if the device is on a
+    ///   multi-GPU board, `None` otherwise
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn multi_gpu_board_group(&self) -> HipResult<Option<i32>> {
+        if self.get_attribute(DeviceAttribute::IsMultiGpuBoard)? == 0 {
+            return Ok(None);
+        }
+        self.get_attribute(DeviceAttribute::MultiGpuBoardGroupID)
+            .map(Some)
+    }
+
+    /// Checks whether the device supports fine-grained memory.
+    ///
+    /// Fine-grained memory is coherent between the host and the device while a
+    /// kernel is running, which system-scope atomics rely on. It is allocated
+    /// with [`crate::MemoryPointer::alloc_with_flag`] and
+    /// [`crate::DeviceMallocFlag::FINEGRAINED`].
+    ///
+    /// # Returns
+    /// * `HipResult<bool>` - `true` if fine-grained allocations are supported
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn supports_fine_grain(&self) -> HipResult<bool> {
+        Ok(self.get_attribute(DeviceAttribute::FineGrainSupport)? != 0)
+    }
+
+    /// Checks whether the device is an integrated GPU, such as the GPU of an APU.
+    ///
+    /// Integrated GPUs have no memory of their own, so device allocations with
+    /// [`crate::MemoryPointer::alloc`] take memory away from the host and do not
+    /// show the bandwidth characteristics of dedicated VRAM.
+    ///
+    /// # Returns
+    /// * `HipResult<bool>` - `true` if the device is integrated
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn is_integrated(&self) -> HipResult<bool> {
+        Ok(self.get_attribute(DeviceAttribute::Integrated)? != 0)
+    }
+
+    /// Checks whether atomic operations between the host and the device are
+    /// performed natively by the hardware.
+    ///
+    /// Algorithms that synchronize with the host through system-scope atomics on
+    /// shared memory should check this before attempting them. Without native
+    /// support such atomics are not guaranteed to be atomic across the link.
+    ///
+    /// # Returns
+    /// * `HipResult<bool>` - `true` if host native atomics are supported
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn supports_host_native_atomics(&self) -> HipResult<bool> {
+        Ok(self.get_attribute(DeviceAttribute::HostNativeAtomicSupported)? != 0)
+    }
+
+    /// Gets the largest L2 cache carve-out, in bytes, that can be set aside for
+    /// persisting accesses on this device.
+    ///
+    /// HIP's `hipLimit_t` has no counterpart to CUDA's
+    /// `cudaLimitPersistingL2CacheSize`, so the carve-out itself cannot be set.
+    ///
+    /// # Returns
+    /// * `HipResult<i32>` - The maximum persisting L2 size, `0` if unsupported
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The device ID is invalid
+    /// * The runtime is not initialized
+    pub fn persisting_l2_max_size(&self) -> HipResult<i32> {
+        self.get_attribute(DeviceAttribute::PersistingL2CacheMaxSize)
+    }
+
+    /// Gets the size in bytes of the FIFO buffer that device-side `printf` writes to.
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The buffer size in bytes
+    /// * `Err(HipError)` if the operation failed
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The platform does not support the limit (`HipStatus::NotSupported`)
+    /// * The device ID is invalid
+    pub fn printf_fifo_size(&self) -> HipResult<usize> {
+        let mut bytes = 0;
+        let code = with_current_device(*self, || unsafe {
+            record_call!(sys::hipDeviceGetLimit(
+                &mut bytes,
+                sys::hipLimit_t_hipLimitPrintfFifoSize
+            ))
+        })?;
+        if HipError::new(code).status == HipStatus::UnsupportedLimit {
+            return Err(HipError::from_status(HipStatus::NotSupported));
+        }
+        (bytes, code).to_result()
+    }
+
+    /// Sets the size in bytes of the FIFO buffer that device-side `printf` writes to.
+    ///
+    /// Output of kernels that print more than fits in the buffer before the host
+    /// drains it is silently dropped, so enlarge it when debugging with `printf`.
+    /// Set it before launching kernels that use `printf`.
+    ///
+    /// # Arguments
+    /// * `bytes` - The new buffer size in bytes
+    ///
+    /// # Returns
+    /// * `Ok(())` if the limit was set
+    /// * `Err(HipError)` if the operation failed
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The platform does not support the limit (`HipStatus::NotSupported`)
+    /// * The device ID is invalid
+    pub fn set_printf_fifo_size(&self, bytes: usize) -> HipResult<()> {
+        let code = with_current_device(*self, || unsafe {
+            record_call!(sys::hipDeviceSetLimit(
+                sys::hipLimit_t_hipLimitPrintfFifoSize,
+                bytes
+            ))
+        })?;
+        if HipError::new(code).status == HipStatus::UnsupportedLimit {
+            return Err(HipError::from_status(HipStatus::NotSupported));
+        }
+        ((), code).to_result()
+    }
+
     /// Resets the state of this device to a fresh state.
     ///
     /// # Safety and Synchronization
@@ -222,6 +533,10 @@ impl Device {
     /// created for the current device. The caller must ensure that no other thread is using the
     /// device or any resources (streams, memory, kernels, events) associated with it.
     ///
+    /// A successful reset advances the device's reset generation. Any [`crate::MemoryPointer`]
+    /// allocated on this device before the reset will skip `hipFree` when dropped, since its
+    /// memory has already been released by the runtime.
+    ///
     /// # Returns
     /// * `Ok(())` if the reset was successful
     /// * `Err(HipError)` if the operation failed
@@ -249,7 +564,12 @@ impl Device {
         set_device(*self)?;
 
         unsafe {
-            let code = sys::hipDeviceReset();
+            let code = record_call!(sys::hipDeviceReset());
+            if code == 0 {
+                bump_device_generation(self.id);
+                // The reset tears down the peer mappings made from this device
+                peer_access().retain(|&(from, _)| from != self.id);
+            }
             ((), code).to_result()
         }
     }
@@ -299,7 +619,7 @@ impl Device {
     /// * [`MemPool::create`](crate::MemPool::create)
     pub fn set_mem_pool(&self, mem_pool: &MemPool) -> HipResult<()> {
         unsafe {
-            let code = sys::hipDeviceSetMemPool(self.id, mem_pool.handle());
+            let code = record_call!(sys::hipDeviceSetMemPool(self.id, mem_pool.handle()));
             ((), code).to_result()
         }
     }
@@ -322,11 +642,50 @@ impl Device {
 /// * The HIP runtime is not initialized
 pub fn synchronize() -> HipResult<()> {
     unsafe {
-        let code = sys::hipDeviceSynchronize();
+        let code = record_call!(sys::hipDeviceSynchronize());
         ((), code).to_result()
     }
 }
 
+/// Synchronizes the current device like [`synchronize`], with a richer error on failure.
+///
+/// Errors of asynchronous work often only surface at the next synchronization,
+/// with a code that does not point at the failing operation. On failure this
+/// also fetches the runtime's last error with `hipGetLastError` and logs both
+/// errors with their descriptions, see [`HipError::message`].
+///
+/// Note that fetching the last error resets it.
+///
+/// # Returns
+/// * `Ok(())` if synchronization was successful
+/// * `Err(HipError)` - The last error recorded by the runtime if there is one,
+///   otherwise the synchronization error
+pub fn synchronize_diagnostic() -> HipResult<()> {
+    synchronize().map_err(diagnose_sync_failure)
+}
+
+/// Combines a failed synchronization with the runtime's last error.
+fn diagnose_sync_failure(error: HipError) -> HipError {
+    let last_error = unsafe { HipError::new(record_call!(sys::hipGetLastError())) };
+    if last_error.status == HipStatus::Success {
+        log::error!(
+            "Device synchronization failed: {} (code {})",
+            error.message(),
+            error.code
+        );
+        return error;
+    }
+
+    log::error!(
+        "Device synchronization failed: {} (code {}), last error: {} (code {})",
+        error.message(),
+        error.code,
+        last_error.message(),
+        last_error.code
+    );
+    last_error
+}
+
 /// Get the number of available HIP devices.
 ///
 /// # Returns
@@ -339,11 +698,51 @@ pub fn synchronize() -> HipResult<()> {
 pub fn get_device_count() -> HipResult<i32> {
     unsafe {
         let mut count = 0;
-        let code = sys::hipGetDeviceCount(&mut count);
+        let code = record_call!(sys::hipGetDeviceCount(&mut count));
         (count, code).to_result()
     }
 }
 
+/// Returns the current device, failing if no HIP device is available.
+///
+/// [`get_device_count`] reports zero devices as a success, and using
+/// [`Device::new`] without a device only fails later in less obvious ways. Call
+/// this at startup to fail fast with a meaningful error instead.
+///
+/// # Returns
+/// * `Ok(Device)` - The current device
+/// * `Err(HipError)` - `HipStatus::NoDevice` if no device is available, or the
+///   error of the underlying query
+///
+/// # Examples
+/// ```
+/// use hip_rs::require_device;
+///
+/// let device = require_device().expect("this application needs a GPU");
+/// println!("Running on device {}", device.id());
+/// ```
+pub fn require_device() -> HipResult<Device> {
+    check_device_count(get_device_count())?;
+    get_device()
+}
+
+/// Maps a device count of zero, or the runtime's own no-device error, to
+/// `HipStatus::NoDevice`.
+fn check_device_count(count: HipResult<i32>) -> HipResult<i32> {
+    match count {
+        Ok(count) if count > 0 => Ok(count),
+        Ok(_)
+        | Err(HipError {
+            status: HipStatus::NoDevice,
+            ..
+        }) => {
+            log::error!("No HIP device is available");
+            Err(HipError::from_status(HipStatus::NoDevice))
+        }
+        Err(error) => Err(error),
+    }
+}
+
 /// Retrieves a peer-to-peer attribute value between two HIP devices.
 ///
 /// This function queries the specified peer-to-peer attribute between a source and destination device.
@@ -371,12 +770,105 @@ pub fn get_device_p2p_attribute(
 ) -> HipResult<i32> {
     let mut value = -1;
     unsafe {
-        let code =
-            sys::hipDeviceGetP2PAttribute(&mut value, attr.into(), src_device.id, dst_device.id);
+        let code = record_call!(sys::hipDeviceGetP2PAttribute(
+            &mut value,
+            attr.into(),
+            src_device.id,
+            dst_device.id
+        ));
         (value, code).to_result()
     }
 }
 
+/// Locks the set of enabled peer access pairs.
+fn peer_access() -> std::sync::MutexGuard<'static, BTreeSet<(i32, i32)>> {
+    PEER_ACCESS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Runs `f` with `device` as the current device, restoring the previous one afterwards.
+fn with_current_device(device: Device, f: impl FnOnce() -> u32) -> HipResult<u32> {
+    let previous = get_device()?;
+    set_device(device)?;
+    let code = f();
+    set_device(previous)?;
+    Ok(code)
+}
+
+/// Enables `from` to access memory allocated on `to`.
+///
+/// The state is recorded, so it can be queried with [`peer_access_enabled`].
+/// Enabling access that is already enabled fails with
+/// `HipStatus::PeerAccessAlreadyEnabled`, so check first to avoid redundant calls.
+///
+/// # Arguments
+/// * `from` - The device that gains access
+/// * `to` - The device whose memory becomes accessible
+///
+/// # Returns
+/// * `Ok(())` if peer access was enabled
+/// * `Err(HipError)` if either device is invalid, the devices cannot access
+///   each other, or access is already enabled (`HipStatus::PeerAccessAlreadyEnabled`)
+pub fn enable_peer_access(from: Device, to: Device) -> HipResult<()> {
+    let code = with_current_device(from, || unsafe {
+        record_call!(sys::hipDeviceEnablePeerAccess(to.id, 0))
+    })?;
+    let status = HipError::new(code).status;
+    if status == HipStatus::Success || status == HipStatus::PeerAccessAlreadyEnabled {
+        peer_access().insert((from.id, to.id));
+    }
+    ((), code).to_result()
+}
+
+/// Disables access from `from` to memory allocated on `to`.
+///
+/// # Arguments
+/// * `from` - The device that loses access
+/// * `to` - The device whose memory becomes inaccessible
+///
+/// # Returns
+/// * `Ok(())` if peer access was disabled
+/// * `Err(HipError)` if either device is invalid or access was not enabled
+///   (`HipStatus::PeerAccessNotEnabled`)
+pub fn disable_peer_access(from: Device, to: Device) -> HipResult<()> {
+    let code = with_current_device(from, || unsafe {
+        record_call!(sys::hipDeviceDisablePeerAccess(to.id))
+    })?;
+    let status = HipError::new(code).status;
+    if status == HipStatus::Success || status == HipStatus::PeerAccessNotEnabled {
+        peer_access().remove(&(from.id, to.id));
+    }
+    ((), code).to_result()
+}
+
+/// Returns whether `from` currently has access to memory allocated on `to`.
+///
+/// HIP cannot be queried for this, so only access enabled through
+/// [`enable_peer_access`] is reported. A [`Device::reset`] of `from` clears it.
+///
+/// # Arguments
+/// * `from` - The device accessing the memory
+/// * `to` - The device owning the memory
+///
+/// # Returns
+/// * `Ok(bool)` - Whether peer access is enabled
+/// * `Err(HipError)` - `HipStatus::InvalidDevice` if either device does not exist
+pub fn peer_access_enabled(from: Device, to: Device) -> HipResult<bool> {
+    let count = get_device_count()?;
+    for device in [from, to] {
+        if device.id < 0 || device.id >= count {
+            log::error!(
+                "Device {} does not exist, {} devices available",
+                device.id,
+                count
+            );
+            return Err(HipError::from_status(HipStatus::InvalidDevice));
+        }
+    }
+    Ok(peer_access().contains(&(from.id, to.id)))
+}
+
 /// Gets the currently active HIP device.
 ///
 /// # Returns
@@ -392,7 +884,7 @@ pub fn get_device_p2p_attribute(
 pub fn get_device() -> HipResult<Device> {
     unsafe {
         let mut device_id: i32 = -1;
-        let code = sys::hipGetDevice(&mut device_id);
+        let code = record_call!(sys::hipGetDevice(&mut device_id));
         (Device::new(device_id), code).to_result()
     }
 }
@@ -416,7 +908,7 @@ pub fn get_device() -> HipResult<Device> {
 /// * The specified device has encountered a previous error and is in a broken state
 pub fn set_device(device: Device) -> HipResult<Device> {
     unsafe {
-        let code = sys::hipSetDevice(device.id);
+        let code = record_call!(sys::hipSetDevice(device.id));
         (device, code).to_result()
     }
 }
@@ -437,7 +929,10 @@ pub fn set_device(device: Device) -> HipResult<Device> {
 pub fn get_device_by_pci_bus_id(mut pci_bus_id: PCIBusId) -> HipResult<Device> {
     let mut device_id = i32::MAX;
     unsafe {
-        let code = sys::hipDeviceGetByPCIBusId(&mut device_id, pci_bus_id.as_mut_ptr());
+        let code = record_call!(sys::hipDeviceGetByPCIBusId(
+            &mut device_id,
+            pci_bus_id.as_mut_ptr()
+        ));
         (Device::new(device_id), code).to_result()
     }
 }
@@ -467,6 +962,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_multi_gpu_board_group() {
+        let device = Device::new(0);
+        let group = device.multi_gpu_board_group().unwrap();
+        println!("Multi-GPU board group: {:?}", group);
+    }
+
+    #[test]
+    fn test_persisting_l2_max_size() {
+        let device = Device::new(0);
+        let max_size = device.persisting_l2_max_size().unwrap();
+        assert!(max_size >= 0);
+        println!("Persisting L2 max size: {} bytes", max_size);
+    }
+
+    #[test]
+    fn test_printf_fifo_size() {
+        let device = Device::new(0);
+        let size = match device.printf_fifo_size() {
+            Ok(size) => size,
+            Err(e) => {
+                assert_eq!(e.status, HipStatus::NotSupported);
+                println!("Printf FIFO size not supported on this device/platform");
+                return;
+            }
+        };
+        println!("Printf FIFO size: {} bytes", size);
+
+        let enlarged = size.max(1 << 20) * 2;
+        match device.set_printf_fifo_size(enlarged) {
+            Ok(()) => assert_eq!(device.printf_fifo_size().unwrap(), enlarged),
+            Err(e) => assert_eq!(e.status, HipStatus::NotSupported),
+        }
+    }
+
     #[test]
     fn test_get_device_by_pci_bus_id() {
         let device = Device::new(0);
@@ -484,6 +1014,19 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_luid() {
+        let device = Device::new(0);
+        match device.luid() {
+            Ok(luid) => println!("Device LUID: {:?}", luid),
+            Err(e) => {
+                // LUIDs are only reported on some platforms
+                assert_eq!(e.status, HipStatus::NotSupported);
+                println!("LUID not supported on this platform");
+            }
+        }
+    }
+
     #[test]
     fn test_get_device_pci_bus_id() {
         let device = Device::new(0);
@@ -559,6 +1102,66 @@ mod tests {
         assert!(count > 0);
     }
 
+    #[test]
+    fn test_fp32_to_fp64_ratio() {
+        let device = Device::new(0);
+        let ratio = device.fp32_to_fp64_ratio().unwrap();
+        assert!(ratio > 0, "ratio {}", ratio);
+    }
+
+    #[test]
+    fn test_texture_alignment() {
+        let device = Device::new(0);
+
+        let alignment = device.texture_alignment().unwrap();
+        assert!(alignment.is_power_of_two(), "alignment {}", alignment);
+
+        let pitch_alignment = device.texture_pitch_alignment().unwrap();
+        assert!(
+            pitch_alignment.is_power_of_two(),
+            "pitch alignment {}",
+            pitch_alignment
+        );
+    }
+
+    #[test]
+    fn test_require_device() {
+        let device = require_device().unwrap();
+        assert_eq!(device.id(), get_device().unwrap().id());
+    }
+
+    #[test]
+    fn test_synchronize_diagnostic() {
+        synchronize_diagnostic().unwrap();
+
+        // A failed call leaves its error behind for the next synchronization
+        assert!(set_device(Device::new(999)).is_err());
+        let error = diagnose_sync_failure(HipError::from_status(HipStatus::Unknown));
+        assert_eq!(error.status, HipStatus::InvalidDevice);
+        assert!(!error.message().is_empty());
+
+        // The last error is reset once fetched
+        let error = diagnose_sync_failure(HipError::from_status(HipStatus::Unknown));
+        assert_eq!(error.status, HipStatus::Unknown);
+    }
+
+    #[test]
+    fn test_check_device_count_no_device() {
+        // Zero devices, as reported with a success code
+        let error = check_device_count(Ok(0)).unwrap_err();
+        assert_eq!(error.status, HipStatus::NoDevice);
+
+        // hipErrorNoDevice from the runtime
+        let error = check_device_count(Err(HipError::new(100))).unwrap_err();
+        assert_eq!(error.status, HipStatus::NoDevice);
+
+        // Other errors are passed through
+        let error = check_device_count(Err(HipError::new(3))).unwrap_err();
+        assert_eq!(error.status, HipStatus::NotInitialized);
+
+        assert_eq!(check_device_count(Ok(2)).unwrap(), 2);
+    }
+
     #[test]
     fn test_get_device() {
         let result = get_device();
@@ -584,6 +1187,17 @@ mod tests {
         assert_eq!(result.unwrap_err().status, HipStatus::InvalidDevice);
     }
 
+    #[test]
+    fn test_get_attribute_warp_size() {
+        let device = Device::new(0);
+        let warp_size = device.get_attribute(DeviceAttribute::WarpSize).unwrap();
+        assert!(
+            warp_size == 32 || warp_size == 64,
+            "unexpected warp size {}",
+            warp_size
+        );
+    }
+
     #[test]
     fn test_get_attribute() {
         let device = Device::new(0);
@@ -624,6 +1238,69 @@ mod tests {
         assert_eq!(result.unwrap_err().status, HipStatus::InvalidDevice);
     }
 
+    #[test]
+    fn test_async_engine_count() {
+        let device = Device::new(0);
+        let count = device.async_engine_count().unwrap();
+        assert!(count >= 0);
+        println!("Async engine count: {}", count);
+    }
+
+    #[test]
+    fn test_is_integrated() {
+        let device = Device::new(0);
+        let integrated = device.is_integrated().unwrap();
+        println!("Integrated: {}", integrated);
+    }
+
+    #[test]
+    fn test_supports_host_native_atomics() {
+        let device = Device::new(0);
+        let supported = device.supports_host_native_atomics().unwrap();
+        println!("Host native atomics supported: {}", supported);
+    }
+
+    #[test]
+    fn test_clocks() {
+        let device = Device::new(0);
+        let clocks = device.clocks().unwrap();
+        assert!(clocks.core_clock_khz > 0);
+        assert!(clocks.memory_clock_khz >= 0);
+        assert!(clocks.wall_clock_khz >= 0);
+        println!("Clocks: {:?}", clocks);
+    }
+
+    #[test]
+    fn test_peer_access() {
+        if get_device_count().unwrap() < 2 {
+            println!("Fewer than two devices available, skipping test");
+            return;
+        }
+
+        let (first, second) = (Device::new(0), Device::new(1));
+        if get_device_p2p_attribute(DeviceP2PAttribute::AccessSupported, first, second).unwrap()
+            == 0
+        {
+            println!("Devices cannot access each other, skipping test");
+            return;
+        }
+
+        assert!(!peer_access_enabled(first, second).unwrap());
+        enable_peer_access(first, second).unwrap();
+        assert!(peer_access_enabled(first, second).unwrap());
+        assert!(!peer_access_enabled(second, first).unwrap());
+
+        disable_peer_access(first, second).unwrap();
+        assert!(!peer_access_enabled(first, second).unwrap());
+    }
+
+    #[test]
+    fn test_peer_access_enabled_invalid_device() {
+        let count = get_device_count().unwrap();
+        let result = peer_access_enabled(Device::new(0), Device::new(count));
+        assert_eq!(result.unwrap_err().status, HipStatus::InvalidDevice);
+    }
+
     #[test]
     fn test_device_reset() {
         let device = Device::new(0);
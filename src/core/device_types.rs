@@ -57,6 +57,19 @@ impl PCIBusId {
     }
 }
 
+/// Clock rates of a device, as returned by [`crate::Device::clocks`].
+///
+/// All rates are in kHz. Divide a cycle count by a rate to get milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockInfo {
+    /// Peak clock of the compute units, which `clock64()` counts in
+    pub core_clock_khz: i32,
+    /// Peak memory clock
+    pub memory_clock_khz: i32,
+    /// Constant frequency of the wall clock, which `wall_clock64()` counts in
+    pub wall_clock_khz: i32,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DeviceP2PAttribute {
     PerformanceRank,
@@ -0,0 +1,308 @@
+use super::result::{HipError, HipResult, HipStatus};
+use super::EventFlags;
+use crate::result::ResultExt;
+use crate::sys;
+use crate::Stream;
+
+/// A handle to a HIP event, used to synchronize on work submitted to streams.
+#[derive(Debug)]
+pub struct HipEvent {
+    handle: sys::hipEvent_t,
+    flags: EventFlags,
+}
+
+impl HipEvent {
+    /// Creates an event with default flags, which records timing information.
+    ///
+    /// # Returns
+    /// * `Ok(HipEvent)` - A new event
+    /// * `Err(HipError)` - If event creation fails
+    pub fn create() -> HipResult<Self> {
+        let mut handle: sys::hipEvent_t = std::ptr::null_mut();
+        unsafe {
+            let code = record_call!(sys::hipEventCreate(&mut handle));
+            let event = Self {
+                handle,
+                flags: EventFlags::DEFAULT,
+            };
+            (event, code).to_result()
+        }
+    }
+
+    /// Creates an event that can be shared with other processes.
+    ///
+    /// Interprocess events cannot record timing information, so the event is
+    /// created with timing disabled.
+    ///
+    /// # Returns
+    /// * `Ok(HipEvent)` - A new interprocess event
+    /// * `Err(HipError)` - If event creation fails
+    pub fn create_interprocess() -> HipResult<Self> {
+        Self::create_with_flags(EventFlags::INTERPROCESS | EventFlags::DISABLE_TIMING)
+    }
+
+    /// Creates an event with the given flags.
+    ///
+    /// An event created with [`EventFlags::DISABLE_TIMING`] cannot be passed to
+    /// [`HipEvent::elapsed_time`].
+    ///
+    /// # Arguments
+    /// * `flags` - The [`EventFlags`] to create the event with
+    ///
+    /// # Returns
+    /// * `Ok(HipEvent)` - A new event
+    /// * `Err(HipError)` - If the flags are invalid, e.g. `INTERPROCESS` without
+    ///   `DISABLE_TIMING`, or event creation fails
+    pub fn create_with_flags(flags: EventFlags) -> HipResult<Self> {
+        let mut handle: sys::hipEvent_t = std::ptr::null_mut();
+        unsafe {
+            let code = record_call!(sys::hipEventCreateWithFlags(&mut handle, flags.bits()));
+            (Self { handle, flags }, code).to_result()
+        }
+    }
+
+    /// Returns the flags the event was created with.
+    pub fn flags(&self) -> EventFlags {
+        self.flags
+    }
+
+    /// Returns the raw event handle.
+    pub fn handle(&self) -> sys::hipEvent_t {
+        self.handle
+    }
+
+    /// Records the event on a stream.
+    ///
+    /// The event completes once all work submitted to `stream` before this call
+    /// has completed.
+    ///
+    /// # Arguments
+    /// * `stream` - The stream to record the event on
+    pub fn record(&self, stream: &Stream) -> HipResult<()> {
+        unsafe {
+            let code = record_call!(sys::hipEventRecord(self.handle, stream.handle()));
+            ((), code).to_result()
+        }
+    }
+
+    /// Blocks the host until the event has completed.
+    pub fn synchronize(&self) -> HipResult<()> {
+        unsafe {
+            let code = record_call!(sys::hipEventSynchronize(self.handle));
+            ((), code).to_result()
+        }
+    }
+
+    /// Returns `true` if the event records timing information.
+    pub fn has_timing(&self) -> bool {
+        !self.flags.contains(EventFlags::DISABLE_TIMING)
+    }
+
+    /// Computes the time between two completed events, in milliseconds.
+    ///
+    /// # Arguments
+    /// * `start` - The event recorded first
+    /// * `end` - The event recorded last
+    ///
+    /// # Returns
+    /// * `Ok(f32)` - The elapsed time in milliseconds
+    /// * `Err(HipError)` - With [`HipStatus::InvalidHandle`] if either event was
+    ///   created with timing disabled, or if the events have not completed
+    pub fn elapsed_time(start: &HipEvent, end: &HipEvent) -> HipResult<f32> {
+        if !start.has_timing() || !end.has_timing() {
+            log::error!("elapsed_time requires events created with timing enabled");
+            return Err(HipError::from_status(HipStatus::InvalidHandle));
+        }
+
+        let mut milliseconds = 0.0f32;
+        unsafe {
+            let code = record_call!(sys::hipEventElapsedTime(
+                &mut milliseconds,
+                start.handle,
+                end.handle
+            ));
+            (milliseconds, code).to_result()
+        }
+    }
+
+    /// Returns `true` if the event was created for use across processes.
+    pub fn is_interprocess(&self) -> bool {
+        self.flags.contains(EventFlags::INTERPROCESS)
+    }
+
+    /// Gets an inter-process handle for this event.
+    ///
+    /// Another process can open the handle with [`open_ipc_event_handle`] and
+    /// wait on work recorded into the event by this process.
+    ///
+    /// # Returns
+    /// * `Ok(IpcEventHandle)` - The handle if successful
+    /// * `Err(HipError)` - If the event was not created with
+    ///   [`HipEvent::create_interprocess`], or the runtime rejects it
+    pub fn ipc_handle(&self) -> HipResult<IpcEventHandle> {
+        if !self.is_interprocess() {
+            log::error!("ipc_handle requires an event created with the interprocess flag");
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+
+        let mut handle: sys::hipIpcEventHandle_t = unsafe { std::mem::zeroed() };
+        unsafe {
+            let code = record_call!(sys::hipIpcGetEventHandle(&mut handle, self.handle));
+            (IpcEventHandle { handle }, code).to_result()
+        }
+    }
+}
+
+impl Drop for HipEvent {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                let code = record_call!(sys::hipEventDestroy(self.handle));
+                if code != 0 {
+                    log::error!("Failed to destroy HIP event: {}", code);
+                }
+            }
+        }
+    }
+}
+
+/// An inter-process handle to a [`HipEvent`].
+///
+/// Use [`IpcEventHandle::to_bytes`] and [`IpcEventHandle::from_bytes`] to send
+/// it between processes.
+#[derive(Debug, Clone, Copy)]
+pub struct IpcEventHandle {
+    handle: sys::hipIpcEventHandle_t,
+}
+
+impl IpcEventHandle {
+    /// Length of the serialized form returned by [`IpcEventHandle::to_bytes`].
+    pub const SERIALIZED_LEN: usize = 64;
+
+    /// Serializes the handle for sending to another process.
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_LEN] {
+        self.handle.reserved.map(|b| b as u8)
+    }
+
+    /// Reconstructs a handle serialized with [`IpcEventHandle::to_bytes`].
+    pub fn from_bytes(bytes: &[u8; Self::SERIALIZED_LEN]) -> Self {
+        let mut handle: sys::hipIpcEventHandle_t = unsafe { std::mem::zeroed() };
+        handle.reserved = bytes.map(|b| b as _);
+        Self { handle }
+    }
+}
+
+/// Opens an event shared by another process with [`HipEvent::ipc_handle`].
+///
+/// # Arguments
+/// * `handle` - The handle received from the exporting process
+///
+/// # Returns
+/// * `Ok(HipEvent)` - The opened interprocess event
+/// * `Err(HipError)` - If the handle could not be opened
+pub fn open_ipc_event_handle(handle: IpcEventHandle) -> HipResult<HipEvent> {
+    let mut event: sys::hipEvent_t = std::ptr::null_mut();
+    unsafe {
+        let code = record_call!(sys::hipIpcOpenEventHandle(&mut event, handle.handle));
+        let event = HipEvent {
+            handle: event,
+            flags: EventFlags::INTERPROCESS | EventFlags::DISABLE_TIMING,
+        };
+        (event, code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_record_synchronize() {
+        let stream = Stream::create().unwrap();
+        let event = HipEvent::create().unwrap();
+        assert!(!event.handle().is_null());
+        assert!(event.has_timing());
+
+        event.record(&stream).unwrap();
+        event.synchronize().unwrap();
+
+        // Recording again reuses the event for the next point in the stream
+        event.record(&stream).unwrap();
+        event.synchronize().unwrap();
+    }
+
+    #[test]
+    fn test_event_create_with_flags() {
+        let stream = Stream::create().unwrap();
+        for flags in [
+            EventFlags::DEFAULT,
+            EventFlags::BLOCKING_SYNC,
+            EventFlags::DISABLE_TIMING,
+            EventFlags::BLOCKING_SYNC | EventFlags::DISABLE_TIMING,
+        ] {
+            let event = HipEvent::create_with_flags(flags).unwrap();
+            assert!(!event.handle().is_null());
+            assert_eq!(event.flags(), flags);
+            assert_eq!(
+                event.has_timing(),
+                !flags.contains(EventFlags::DISABLE_TIMING)
+            );
+
+            event.record(&stream).unwrap();
+            event.synchronize().unwrap();
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            let flags = EventFlags::INTERPROCESS | EventFlags::DISABLE_TIMING;
+            let event = HipEvent::create_with_flags(flags).unwrap();
+            assert!(event.is_interprocess());
+        }
+    }
+
+    #[test]
+    fn test_event_elapsed_time() {
+        let stream = Stream::create().unwrap();
+        let start = HipEvent::create().unwrap();
+        let end = HipEvent::create().unwrap();
+
+        start.record(&stream).unwrap();
+        crate::synchronize().unwrap();
+        end.record(&stream).unwrap();
+        end.synchronize().unwrap();
+
+        let milliseconds = HipEvent::elapsed_time(&start, &end).unwrap();
+        assert!(milliseconds.is_finite());
+        assert!(milliseconds >= 0.0);
+    }
+
+    #[test]
+    fn test_event_elapsed_time_without_timing() {
+        let stream = Stream::create().unwrap();
+        let start = HipEvent::create().unwrap();
+        let end = HipEvent::create_with_flags(EventFlags::DISABLE_TIMING).unwrap();
+        assert!(!end.has_timing());
+
+        start.record(&stream).unwrap();
+        end.record(&stream).unwrap();
+        end.synchronize().unwrap();
+
+        let result = HipEvent::elapsed_time(&start, &end);
+        assert_eq!(result.unwrap_err().status, HipStatus::InvalidHandle);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_ipc_event_handle_round_trip() {
+        let event = HipEvent::create_interprocess().unwrap();
+        assert!(event.is_interprocess());
+
+        // Serialize as if sending to another process
+        let handle = event.ipc_handle().unwrap();
+        let handle = IpcEventHandle::from_bytes(&handle.to_bytes());
+
+        let opened = open_ipc_event_handle(handle).unwrap();
+        assert!(!opened.handle().is_null());
+        assert!(opened.is_interprocess());
+    }
+}
@@ -9,3 +9,29 @@ bitflags! {
         const CONTIGUOUS = 0x4;
     }
 }
+
+bitflags! {
+    /// Flags for [`crate::HipEvent::create_with_flags`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct EventFlags: u32 {
+        /// Records timing information and busy-waits in `synchronize`
+        const DEFAULT = 0x0; // hipEventDefault
+        /// `synchronize` blocks the host thread instead of busy-waiting
+        const BLOCKING_SYNC = 0x1; // hipEventBlockingSync
+        /// Does not record timing information, which makes recording cheaper
+        const DISABLE_TIMING = 0x2; // hipEventDisableTiming
+        /// Can be shared with other processes, requires `DISABLE_TIMING`
+        const INTERPROCESS = 0x4; // hipEventInterprocess
+    }
+}
+
+bitflags! {
+    /// Flags for [`crate::Stream::create_with_flags`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct StreamFlags: u32 {
+        /// Synchronizes implicitly with the legacy null stream
+        const DEFAULT = 0x0; // hipStreamDefault
+        /// Runs concurrently with the legacy null stream
+        const NON_BLOCKING = 0x1; // hipStreamNonBlocking
+    }
+}
@@ -9,6 +9,7 @@ use {
 macro_rules! hip_call {
     ($call:expr) => {{
         let code: u32 = unsafe { $call };
+        $crate::__record_call(stringify!($call), code == 0);
         let result: HipResult<()> = ((), code).to_result();
         result
     }};
@@ -0,0 +1,170 @@
+use super::result::{HipError, HipResult, HipStatus};
+use crate::result::ResultExt;
+use crate::sys;
+
+/// `hipHostMallocDefault`
+const HOST_MALLOC_DEFAULT: u32 = 0x0;
+
+/// Page-locked (pinned) host memory.
+///
+/// Pinned memory can be transferred to and from the device asynchronously, which
+/// is required for copies to overlap with computation. The memory is freed with
+/// `hipHostFree` when dropped.
+#[derive(Debug)]
+pub struct HostMemory<T> {
+    pointer: *mut T,
+    len: usize,
+}
+
+impl<T: Copy + Default> HostMemory<T> {
+    /// Allocates pinned host memory for `len` elements, initialized to `T::default()`.
+    ///
+    /// A `len` of zero does not allocate and yields empty slices.
+    ///
+    /// # Arguments
+    /// * `len` - Number of elements to allocate
+    ///
+    /// # Returns
+    /// * `Ok(HostMemory<T>)` - The pinned allocation
+    /// * `Err(HipError)` - If the allocation failed
+    pub fn alloc(len: usize) -> HipResult<Self> {
+        // hipHostMalloc(0) is not guaranteed to succeed, so empty allocations
+        // never reach the runtime
+        if len == 0 {
+            return Ok(Self {
+                pointer: std::ptr::null_mut(),
+                len: 0,
+            });
+        }
+
+        let bytes = len
+            .checked_mul(std::mem::size_of::<T>())
+            .ok_or(HipError::from_status(HipStatus::InvalidValue))?;
+
+        let mut ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+        let memory = unsafe {
+            let code = record_call!(sys::hipHostMalloc(&mut ptr, bytes, HOST_MALLOC_DEFAULT));
+            let memory = Self {
+                pointer: ptr as *mut T,
+                len,
+            };
+            (memory, code).to_result()?
+        };
+
+        // Pinned memory is uninitialized, write every element before handing out slices
+        for i in 0..len {
+            unsafe { memory.pointer.add(i).write(T::default()) };
+        }
+        Ok(memory)
+    }
+}
+
+impl<T> HostMemory<T> {
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the allocation holds no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the raw pointer to the pinned memory, null for an empty allocation
+    pub fn as_pointer(&self) -> *mut T {
+        self.pointer
+    }
+
+    /// Returns the pinned memory as a slice of `len()` elements
+    pub fn as_slice(&self) -> &[T] {
+        if self.pointer.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.pointer, self.len) }
+    }
+
+    /// Returns the pinned memory as a mutable slice of `len()` elements
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        if self.pointer.is_null() {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(self.pointer, self.len) }
+    }
+}
+
+impl<T> Drop for HostMemory<T> {
+    fn drop(&mut self) {
+        if self.pointer.is_null() {
+            return;
+        }
+
+        unsafe {
+            let code = record_call!(sys::hipHostFree(self.pointer as *mut std::ffi::c_void));
+            if code != 0 {
+                let error = HipError::new(code);
+                log::error!("HostMemory failed to free pinned memory: {:?}", error);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_memory_alloc() {
+        let mut memory = HostMemory::<f32>::alloc(16).unwrap();
+        assert_eq!(memory.len(), 16);
+        assert!(memory.as_slice().iter().all(|&x| x == 0.0));
+
+        memory.as_mut_slice()[3] = 1.5;
+        assert_eq!(memory.as_slice()[3], 1.5);
+    }
+
+    #[test]
+    fn test_host_memory_zero_size() {
+        let mut memory = HostMemory::<f64>::alloc(0).unwrap();
+        assert!(memory.is_empty());
+        assert!(memory.as_pointer().is_null());
+        assert!(memory.as_slice().is_empty());
+        assert!(memory.as_mut_slice().is_empty());
+    }
+
+    #[test]
+    fn test_host_memory_len_in_elements() {
+        let mut memory = HostMemory::<u64>::alloc(10).unwrap();
+        assert_eq!(memory.len(), 10);
+        assert_eq!(memory.as_slice().len(), 10);
+        assert_eq!(memory.as_mut_slice().len(), 10);
+    }
+
+    #[test]
+    fn test_host_memory_drop_frees() {
+        let memory_type = |pointer: *mut u8| -> HipResult<u32> {
+            let mut memory_type: u32 = 0;
+            let code = unsafe {
+                sys::hipPointerGetAttribute(
+                    &mut memory_type as *mut u32 as *mut std::ffi::c_void,
+                    sys::hipPointer_attribute_HIP_POINTER_ATTRIBUTE_MEMORY_TYPE,
+                    pointer as sys::hipDeviceptr_t,
+                )
+            };
+            (memory_type, code).to_result()
+        };
+
+        let memory = HostMemory::<u8>::alloc(4096).unwrap();
+        let pointer = memory.as_pointer();
+        assert_eq!(
+            memory_type(pointer).unwrap(),
+            sys::hipMemoryType_hipMemoryTypeHost
+        );
+
+        // Once freed the runtime no longer knows the address as pinned memory
+        drop(memory);
+        assert_ne!(
+            memory_type(pointer).ok(),
+            Some(sys::hipMemoryType_hipMemoryTypeHost)
+        );
+    }
+}
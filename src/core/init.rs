@@ -1,5 +1,6 @@
 #[allow(unused_imports)]
 use super::result::{HipResult, HipStatus};
+use super::{get_device_count, Device};
 pub use crate::result::ResultExt;
 use crate::sys;
 use semver::Version;
@@ -19,11 +20,36 @@ use std::i32;
 /// * The runtime is already initialized
 pub fn initialize() -> HipResult<()> {
     unsafe {
-        let code = sys::hipInit(0);
+        let code = record_call!(sys::hipInit(0));
         ((), code).to_result()
     }
 }
 
+/// Initializes the HIP runtime and returns all available devices.
+///
+/// Combines the common startup sequence of [`initialize`] and
+/// [`get_device_count`](crate::get_device_count) into a single call.
+///
+/// # Returns
+/// * `Result<Vec<Device>>` - One [`Device`] per available HIP device, ordered by ID
+///
+/// # Errors
+/// Returns `HipError` if:
+/// * The runtime fails to initialize
+/// * The device count cannot be queried
+///
+/// # Examples
+/// ```
+/// let devices = hip_rs::startup().unwrap();
+/// let device = devices.first().expect("no HIP devices found");
+/// hip_rs::set_device(*device).unwrap();
+/// ```
+pub fn startup() -> HipResult<Vec<Device>> {
+    initialize()?;
+    let count = get_device_count()?;
+    Ok((0..count).map(Device::new).collect())
+}
+
 /// Decodes a HIP version number from its internal integer representation.
 ///
 /// The version is encoded as: major * 1_000_000 + minor * 1_000 + patch
@@ -55,7 +81,7 @@ fn decode_hip_version(version: i32) -> Version {
 pub fn runtime_get_version() -> HipResult<Version> {
     unsafe {
         let mut version: i32 = -1;
-        let code = sys::hipRuntimeGetVersion(&mut version);
+        let code = record_call!(sys::hipRuntimeGetVersion(&mut version));
         let version = decode_hip_version(version);
         (version, code).to_result()
     }
@@ -72,6 +98,15 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_startup() {
+        let devices = startup().unwrap();
+        assert!(!devices.is_empty());
+        for (id, device) in devices.iter().enumerate() {
+            assert_eq!(device.id(), id as i32);
+        }
+    }
+
     #[test]
     fn test_runtime_get_version() {
         let result = runtime_get_version();
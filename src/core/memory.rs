@@ -1,15 +1,134 @@
+use super::device::device_generation;
 use super::flags::DeviceMallocFlag;
+use super::pod::DevicePod;
 use super::result::{HipError, HipResult, HipStatus};
 use crate::result::ResultExt;
 use crate::sys;
-use crate::Stream;
+use crate::{Device, HostMemory, Stream};
 
 /// A wrapper for device memory allocated on the GPU.
 /// Automatically frees the memory when dropped.
-#[derive(Debug, Clone)]
+///
+/// The pointer remembers the device it was allocated on together with that
+/// device's reset generation. If the device is reset with [`crate::Device::reset`]
+/// before the pointer is dropped, the memory has already been released and
+/// `Drop` skips `hipFree` instead of double-freeing.
+///
+/// The pointer is not `Clone`, as every copy would release the same memory.
+#[derive(Debug)]
 pub struct MemoryPointer<T> {
     pointer: *mut T,
     size: usize,
+    device: i32,
+    generation: u64,
+    release: Release,
+}
+
+/// How the memory behind a [`MemoryPointer`] is released when it is dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Release {
+    /// Memory allocated by this process, freed with `hipFree`
+    Free,
+    /// Memory of another process mapped with `hipIpcOpenMemHandle`, unmapped
+    /// with `hipIpcCloseMemHandle` and never freed
+    IpcClose,
+    /// Memory owned by an [`AllocArena`], released when the arena is dropped
+    Arena,
+    /// Memory owned by other code, see [`MemoryPointer::from_raw_borrowed`]
+    Borrowed,
+}
+
+/// `hipIpcMemLazyEnablePeerAccess`, the only flag accepted by `hipIpcOpenMemHandle`.
+const IPC_MEM_LAZY_ENABLE_PEER_ACCESS: u32 = 0x01;
+
+/// Allocations of at least this many bytes warn when made on an integrated GPU.
+const INTEGRATED_WARN_BYTES: usize = 1 << 30;
+
+/// An inter-process handle to a device allocation.
+///
+/// Created with [`MemoryPointer::ipc_handle`] and opened in another process with
+/// [`open_ipc_mem_handle`]. Use [`IpcMemHandle::to_bytes`] and
+/// [`IpcMemHandle::from_bytes`] to send it between processes.
+#[derive(Debug, Clone, Copy)]
+pub struct IpcMemHandle {
+    handle: sys::hipIpcMemHandle_t,
+    bytes: usize,
+}
+
+impl IpcMemHandle {
+    /// Length of the serialized form returned by [`IpcMemHandle::to_bytes`].
+    pub const SERIALIZED_LEN: usize = 64 + std::mem::size_of::<u64>();
+
+    /// Returns the size of the shared allocation in bytes
+    pub fn size_in_bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Serializes the handle, including the allocation size, for sending to another process.
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_LEN] {
+        let mut out = [0u8; Self::SERIALIZED_LEN];
+        for (dst, src) in out.iter_mut().zip(self.handle.reserved.iter()) {
+            *dst = *src as u8;
+        }
+        out[64..].copy_from_slice(&(self.bytes as u64).to_le_bytes());
+        out
+    }
+
+    /// Reconstructs a handle serialized with [`IpcMemHandle::to_bytes`].
+    pub fn from_bytes(bytes: &[u8; Self::SERIALIZED_LEN]) -> Self {
+        let mut handle: sys::hipIpcMemHandle_t = unsafe { std::mem::zeroed() };
+        for (dst, src) in handle.reserved.iter_mut().zip(bytes.iter()) {
+            *dst = *src as _;
+        }
+        let mut size = [0u8; 8];
+        size.copy_from_slice(&bytes[64..]);
+        Self {
+            handle,
+            bytes: u64::from_le_bytes(size) as usize,
+        }
+    }
+}
+
+/// Data identifying a single allocation of a shareable [`MemPool`].
+///
+/// Created with [`MemPool::export_pointer`] and imported in another process with
+/// [`MemPool::import_pointer`] on the pool imported from the exporting process.
+/// Use [`ExportData::to_bytes`] and [`ExportData::from_bytes`] to send it
+/// between processes.
+#[derive(Debug, Clone, Copy)]
+pub struct ExportData {
+    data: sys::hipMemPoolPtrExportData,
+    bytes: usize,
+}
+
+impl ExportData {
+    /// Length of the serialized form returned by [`ExportData::to_bytes`].
+    pub const SERIALIZED_LEN: usize = 64 + std::mem::size_of::<u64>();
+
+    /// Returns the size of the shared allocation in bytes
+    pub fn size_in_bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Serializes the export data, including the allocation size, for sending to another process.
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_LEN] {
+        let mut out = [0u8; Self::SERIALIZED_LEN];
+        out[..64].copy_from_slice(&self.data.reserved);
+        out[64..].copy_from_slice(&(self.bytes as u64).to_le_bytes());
+        out
+    }
+
+    /// Reconstructs export data serialized with [`ExportData::to_bytes`].
+    pub fn from_bytes(bytes: &[u8; Self::SERIALIZED_LEN]) -> Self {
+        let mut data: sys::hipMemPoolPtrExportData = unsafe { std::mem::zeroed() };
+        data.reserved.copy_from_slice(&bytes[..64]);
+        let mut size = [0u8; 8];
+        size.copy_from_slice(&bytes[64..]);
+        Self {
+            data,
+            bytes: u64::from_le_bytes(size) as usize,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -145,6 +264,29 @@ fn to_void_pointer<T>(ptr: *mut *mut T) -> *mut *mut std::ffi::c_void {
     ptr as *mut *mut std::ffi::c_void
 }
 
+/// Checks whether two regions of `len_a` and `len_b` elements of type T overlap.
+///
+/// # Arguments
+/// * `a` - Start of the first region
+/// * `len_a` - Number of elements in the first region
+/// * `b` - Start of the second region
+/// * `len_b` - Number of elements in the second region
+///
+/// # Returns
+/// `true` if the regions share at least one byte, `false` if either is null or empty
+fn regions_overlap<T>(a: *const T, len_a: usize, b: *const T, len_b: usize) -> bool {
+    if a.is_null() || b.is_null() || len_a == 0 || len_b == 0 {
+        return false;
+    }
+
+    let element_size = std::mem::size_of::<T>();
+    let a_start = a as usize;
+    let b_start = b as usize;
+    let a_end = a_start + len_a * element_size;
+    let b_end = b_start + len_b * element_size;
+    a_start < b_end && b_start < a_end
+}
+
 /// Copies data between memory locations.
 ///
 /// # Arguments
@@ -169,7 +311,7 @@ unsafe fn memory_copy(
     size: usize,
     kind: MemoryCopyKind,
 ) -> HipResult<()> {
-    let code = sys::hipMemcpy(dst, src, size, kind.into());
+    let code = record_call!(sys::hipMemcpy(dst, src, size, kind.into()));
     ((), code).to_result()
 }
 
@@ -182,11 +324,18 @@ impl<T> MemoryPointer<T> {
     where
         F: FnOnce(*mut *mut std::ffi::c_void, usize) -> u32,
     {
+        // Record the device and its reset generation at allocation time
+        let device = super::get_device()?.id();
+        let generation = device_generation(device);
+
         // Handle zero size allocation according to spec
         if size == 0 {
             return Ok(MemoryPointer {
                 pointer: std::ptr::null_mut(),
                 size: 0,
+                device,
+                generation,
+                release: Release::Free,
             });
         }
 
@@ -196,6 +345,9 @@ impl<T> MemoryPointer<T> {
         let pointer = Self {
             pointer: ptr as *mut T,
             size,
+            device,
+            generation,
+            release: Release::Free,
         };
 
         (pointer, code).to_result()
@@ -215,9 +367,27 @@ impl<T> MemoryPointer<T> {
     /// # Returns
     /// * `Ok(MemoryPointer)` - Handle to allocated device memory
     /// * `Err(HipError)` - Error occurred during allocation
+    ///
+    /// On an integrated GPU, see [`Device::is_integrated`], device memory is carved
+    /// out of shared system memory. Allocations of 1 GiB or more then log a warning,
+    /// since they compete with the host for RAM.
     /// ```
     pub fn alloc(size: usize) -> HipResult<Self> {
-        Self::allocate_with_fn(size, |ptr, size| unsafe { sys::hipMalloc(ptr, size) })
+        let pointer = Self::allocate_with_fn(size, |ptr, size| unsafe {
+            record_call!(sys::hipMalloc(ptr, size))
+        })?;
+
+        let bytes = size * std::mem::size_of::<T>();
+        if bytes >= INTEGRATED_WARN_BYTES
+            && Device::new(pointer.device).is_integrated().unwrap_or(false)
+        {
+            log::warn!(
+                "Allocated {} bytes on integrated device {}, which draws from shared system memory",
+                bytes,
+                pointer.device
+            );
+        }
+        Ok(pointer)
     }
 
     /// Allocates memory on the default accelerator with specified allocation flags.
@@ -234,10 +404,19 @@ impl<T> MemoryPointer<T> {
     /// # Notes
     /// * If size is 0, returns null pointer with success status
     /// * Invalid flags will result in hipErrorInvalidValue error
+    /// * `FINEGRAINED` fails with `HipStatus::NotSupported` on devices without
+    ///   fine-grain support, see [`Device::supports_fine_grain`]
     ///
     pub fn alloc_with_flag(size: usize, flag: DeviceMallocFlag) -> HipResult<Self> {
+        if flag.bits() == DeviceMallocFlag::FINEGRAINED.bits()
+            && !super::get_device()?.supports_fine_grain()?
+        {
+            log::error!("Fine-grained allocation requested on a device without fine-grain support");
+            return Err(HipError::from_status(HipStatus::NotSupported));
+        }
+
         Self::allocate_with_fn(size, |ptr, size| unsafe {
-            sys::hipExtMallocWithFlags(ptr, size, flag.bits())
+            record_call!(sys::hipExtMallocWithFlags(ptr, size, flag.bits()))
         })
     }
 
@@ -262,7 +441,126 @@ impl<T> MemoryPointer<T> {
     /// ```
     pub fn alloc_async(size: usize, stream: &Stream) -> HipResult<Self> {
         Self::allocate_with_fn(size, |ptr, size| unsafe {
-            sys::hipMallocAsync(ptr, size, stream.handle())
+            record_call!(sys::hipMallocAsync(ptr, size, stream.handle()))
+        })
+    }
+
+    /// Allocates device memory from a specific memory pool in a stream-ordered manner.
+    ///
+    /// Unlike [`MemoryPointer::alloc_async`], which uses the current pool of the
+    /// device, the memory always comes from `pool`.
+    ///
+    /// # Arguments
+    /// * `size` - The size (in elements) of memory to allocate
+    /// * `pool` - The memory pool to allocate from
+    /// * `stream` - The stream to establish stream ordering semantics
+    ///
+    /// # Returns
+    /// * `Ok(MemoryPointer<T>)` - Successfully allocated memory pointer
+    /// * `Err(HipError)` - If allocation fails
+    pub fn alloc_from_pool(size: usize, pool: &MemPool, stream: &Stream) -> HipResult<Self> {
+        Self::allocate_with_fn(size, |ptr, size| unsafe {
+            record_call!(sys::hipMallocFromPoolAsync(
+                ptr,
+                size,
+                pool.handle(),
+                stream.handle()
+            ))
+        })
+    }
+
+    /// Allocates managed memory that is accessible from both the host and the device.
+    ///
+    /// The runtime migrates pages on demand between host and device memory. Use
+    /// [`MemoryPointer::prefetch_async`] to move the data ahead of time and avoid
+    /// page faults when switching between host and device processing.
+    ///
+    /// # Arguments
+    /// * `size` - The size (in elements) of memory to allocate
+    ///
+    /// # Returns
+    /// * `Ok(MemoryPointer<T>)` - Successfully allocated memory pointer
+    /// * `Err(HipError)` - If allocation fails or managed memory is not supported
+    pub fn alloc_managed(size: usize) -> HipResult<Self> {
+        // hipMemAttachGlobal, the memory is accessible from any stream on any device
+        const ATTACH_GLOBAL: u32 = 0x01;
+        Self::allocate_with_fn(size, |ptr, size| unsafe {
+            record_call!(sys::hipMallocManaged(ptr, size, ATTACH_GLOBAL))
+        })
+    }
+
+    /// Queries the device this memory was allocated on from its pointer attributes.
+    ///
+    /// # Returns
+    /// * `Ok(Device)` - The device that owns the memory
+    /// * `Err(HipError)` - If the pointer is not known to the HIP runtime
+    pub fn device(&self) -> HipResult<Device> {
+        pointer_device(self.pointer)
+    }
+
+    /// Asynchronously prefetches managed memory to a device or to the host.
+    ///
+    /// Pass [`Device::CPU`] as `device` to migrate the memory back to host memory
+    /// before accessing it from the CPU.
+    ///
+    /// # Arguments
+    /// * `device` - The [`Device`] to prefetch to, or [`Device::CPU`] for the host
+    /// * `stream` - The stream on which to enqueue the prefetch
+    ///
+    /// # Returns
+    /// * `Ok(())` - The prefetch was enqueued
+    /// * `Err(HipError)` - If the prefetch fails
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The memory was not allocated with [`MemoryPointer::alloc_managed`]
+    /// * The device ID is invalid
+    /// * The device does not support concurrent managed access
+    pub fn prefetch_async(&self, device: Device, stream: &Stream) -> HipResult<()> {
+        if self.size == 0 {
+            return Ok(());
+        }
+
+        unsafe {
+            let code = record_call!(sys::hipMemPrefetchAsync(
+                self.pointer as *const std::ffi::c_void,
+                self.size * std::mem::size_of::<T>(),
+                device.id(),
+                stream.handle(),
+            ));
+            ((), code).to_result()
+        }
+    }
+
+    /// Wraps an existing device pointer without taking ownership of it.
+    ///
+    /// This is intended for FFI interop, where the memory was allocated and is
+    /// managed by other code. The returned `MemoryPointer` is attributed to the
+    /// current device and does not free the memory when dropped.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `pointer` is a device pointer to at least
+    /// `size` elements of `T`, and that the memory outlives the returned
+    /// `MemoryPointer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hip_rs::MemoryPointer;
+    ///
+    /// let owner = MemoryPointer::<f32>::alloc(4).unwrap();
+    /// let borrowed = unsafe { MemoryPointer::from_raw_borrowed(owner.as_pointer(), 4) }.unwrap();
+    /// assert_eq!(borrowed.as_pointer(), owner.as_pointer());
+    /// ```
+    pub unsafe fn from_raw_borrowed(pointer: *mut T, size: usize) -> HipResult<Self> {
+        let device = super::get_device()?.id();
+        Ok(Self {
+            pointer,
+            size,
+            device,
+            generation: device_generation(device),
+            release: Release::Borrowed,
         })
     }
 
@@ -276,8 +574,27 @@ impl<T> MemoryPointer<T> {
         self.size
     }
 
+    /// Checks whether the memory of this pointer overlaps with another pointer.
+    ///
+    /// The address ranges `[pointer, pointer + size)` of both pointers are compared.
+    /// Null and zero sized pointers never overlap with anything.
+    ///
+    /// # Arguments
+    /// * `other` - The memory pointer to compare against
+    ///
+    /// # Returns
+    /// * `true` if the two memory regions share at least one byte
+    pub fn overlaps(&self, other: &MemoryPointer<T>) -> bool {
+        regions_overlap(self.pointer, self.size, other.pointer, other.size)
+    }
+
     /// Copies data from this memory pointer to another destination memory pointer.
     ///
+    /// Exactly `self.size()` elements are copied to the start of `destination`.
+    /// The destination may be larger than the source, in which case the elements
+    /// past `self.size()` are left untouched. Use [`MemoryPointer::copy_prefix_to`]
+    /// to copy fewer elements than the source holds.
+    ///
     /// # Arguments
     /// * `destination` - The destination memory pointer to copy data to
     /// * `kind` - The type of memory copy operation to perform
@@ -290,19 +607,78 @@ impl<T> MemoryPointer<T> {
     /// - Checks that neither pointer is null
     /// - Validates that destination has sufficient size
     /// - Ensures proper size alignment
+    /// - In debug builds, asserts that source and destination do not overlap
+    ///
+    /// # Errors
+    /// Returns `HipError` with `HipStatus::InvalidValue` if `kind` does not match
+    /// where the two allocations live, e.g. [`MemoryCopyKind::HostToDevice`] for
+    /// two device pointers. Prefer [`MemoryPointer::copy_to_device`], which always
+    /// uses the right kind.
     pub fn copy_to(&self, destination: &MemoryPointer<T>, kind: MemoryCopyKind) -> HipResult<()> {
+        self.copy_prefix_to(destination, self.size, kind)
+    }
+
+    /// Copies data from this memory pointer to another device memory pointer.
+    ///
+    /// Same as [`MemoryPointer::copy_to`] with [`MemoryCopyKind::DeviceToDevice`],
+    /// the only kind that applies when both operands are device allocations.
+    ///
+    /// # Arguments
+    /// * `destination` - The destination memory pointer to copy data to
+    ///
+    /// # Returns
+    /// * `Ok(())` if the copy was successful
+    /// * `Err(HipError)` if the operation failed
+    pub fn copy_to_device(&self, destination: &MemoryPointer<T>) -> HipResult<()> {
+        self.copy_to(destination, MemoryCopyKind::DeviceToDevice)
+    }
+
+    /// Copies the first `count` elements of this memory pointer to the start of
+    /// another destination memory pointer.
+    ///
+    /// Elements of the source past `count`, and elements of the destination past
+    /// `count`, are not touched.
+    ///
+    /// # Arguments
+    /// * `destination` - The destination memory pointer to copy data to
+    /// * `count` - Number of elements to copy
+    /// * `kind` - The type of memory copy operation to perform
+    ///
+    /// # Returns
+    /// * `Ok(())` if the copy was successful
+    /// * `Err(HipError)` if the operation failed
+    ///
+    /// # Errors
+    /// Returns `HipError` with `HipStatus::InvalidValue` if:
+    /// * Either pointer is null
+    /// * `count` exceeds the size of the source or the destination
+    /// * `kind` does not match where the two allocations live
+    pub fn copy_prefix_to(
+        &self,
+        destination: &MemoryPointer<T>,
+        count: usize,
+        kind: MemoryCopyKind,
+    ) -> HipResult<()> {
         // Check for null pointers
         if self.pointer.is_null() || destination.pointer.is_null() {
             return Err(HipError::from_status(HipStatus::InvalidValue));
         }
 
-        // Check that destination has sufficient size
-        if destination.size < self.size {
+        // Check that both sides hold at least `count` elements
+        if count > self.size || count > destination.size {
             return Err(HipError::from_status(HipStatus::InvalidValue));
         }
 
+        check_copy_kind(self.pointer, destination.pointer, kind)?;
+
+        // hipMemcpy with overlapping regions is undefined behavior
+        debug_assert!(
+            !regions_overlap(self.pointer, count, destination.pointer, count),
+            "copy source and destination overlap"
+        );
+
         // Calculate total bytes to copy
-        let bytes_to_copy = self.size * std::mem::size_of::<T>();
+        let bytes_to_copy = count * std::mem::size_of::<T>();
 
         unsafe {
             memory_copy(
@@ -314,6 +690,162 @@ impl<T> MemoryPointer<T> {
         }
     }
 
+    /// Gets an inter-process handle for this allocation.
+    ///
+    /// Another process can map the allocation with [`open_ipc_mem_handle`] and
+    /// access it without copying. The memory stays owned by this pointer, so it
+    /// must outlive every mapping in other processes.
+    ///
+    /// # Returns
+    /// * `Ok(IpcMemHandle)` - The handle if successful
+    /// * `Err(HipError)` - If the pointer is null or the runtime rejects it
+    pub fn ipc_handle(&self) -> HipResult<IpcMemHandle> {
+        if self.pointer.is_null() {
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+
+        let mut handle: sys::hipIpcMemHandle_t = unsafe { std::mem::zeroed() };
+        unsafe {
+            let code = record_call!(sys::hipIpcGetMemHandle(
+                &mut handle,
+                self.pointer as *mut std::ffi::c_void
+            ));
+            let ipc_handle = IpcMemHandle {
+                handle,
+                bytes: self.size * std::mem::size_of::<T>(),
+            };
+            (ipc_handle, code).to_result()
+        }
+    }
+
+    /// Copies a host slice into the start of this device allocation.
+    ///
+    /// # Arguments
+    /// * `data` - The host elements to upload
+    ///
+    /// # Returns
+    /// * `Ok(())` if the copy was successful
+    /// * `Err(HipError)` if the operation failed
+    ///
+    /// # Errors
+    /// Returns `HipError` with `HipStatus::InvalidValue` if the pointer is null
+    /// or `data` holds more elements than the allocation.
+    pub fn copy_from_host(&self, data: &[T]) -> HipResult<()> {
+        if self.pointer.is_null() || data.len() > self.size {
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+
+        unsafe {
+            memory_copy(
+                self.pointer as *mut std::ffi::c_void,
+                data.as_ptr() as *const std::ffi::c_void,
+                std::mem::size_of_val(data),
+                MemoryCopyKind::HostToDevice,
+            )
+        }
+    }
+
+    /// Copies the start of this device allocation into a host slice.
+    ///
+    /// # Arguments
+    /// * `data` - The host buffer to fill; `data.len()` elements are copied
+    ///
+    /// # Returns
+    /// * `Ok(())` if the copy was successful
+    /// * `Err(HipError)` if the operation failed
+    ///
+    /// # Errors
+    /// Returns `HipError` with `HipStatus::InvalidValue` if the pointer is null
+    /// or `data` is longer than the allocation.
+    pub fn copy_to_host(&self, data: &mut [T]) -> HipResult<()> {
+        if self.pointer.is_null() || data.len() > self.size {
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+
+        unsafe {
+            memory_copy(
+                data.as_mut_ptr() as *mut std::ffi::c_void,
+                self.pointer as *const std::ffi::c_void,
+                std::mem::size_of_val(data),
+                MemoryCopyKind::DeviceToHost,
+            )
+        }
+    }
+
+    /// Enqueues a copy from pinned host memory into the start of this allocation.
+    ///
+    /// The copy runs asynchronously on `stream`, and still reads `source` after
+    /// this call returns.
+    ///
+    /// # Arguments
+    /// * `source` - The pinned host memory to upload, all of it is copied
+    /// * `stream` - The stream to enqueue the copy on
+    ///
+    /// # Errors
+    /// Returns `HipError` with `HipStatus::InvalidValue` if the pointer is null
+    /// or `source` holds more elements than the allocation.
+    ///
+    /// # Safety
+    /// `source` must not be written or dropped until `stream` has finished the
+    /// copy, e.g. until [`Stream::synchronize`] has returned.
+    pub unsafe fn copy_from_host_async(
+        &self,
+        source: &HostMemory<T>,
+        stream: &Stream,
+    ) -> HipResult<()> {
+        if self.pointer.is_null() || source.len() > self.size {
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+
+        unsafe {
+            let code = record_call!(sys::hipMemcpyAsync(
+                self.pointer as *mut std::ffi::c_void,
+                source.as_pointer() as *const std::ffi::c_void,
+                std::mem::size_of_val(source.as_slice()),
+                MemoryCopyKind::HostToDevice.into(),
+                stream.handle(),
+            ));
+            ((), code).to_result()
+        }
+    }
+
+    /// Enqueues a copy from the start of this allocation into pinned host memory.
+    ///
+    /// The copy runs asynchronously on `stream`, and still writes `destination`
+    /// after this call returns.
+    ///
+    /// # Arguments
+    /// * `destination` - The pinned host memory to fill, all of it is written
+    /// * `stream` - The stream to enqueue the copy on
+    ///
+    /// # Errors
+    /// Returns `HipError` with `HipStatus::InvalidValue` if the pointer is null
+    /// or `destination` is longer than the allocation.
+    ///
+    /// # Safety
+    /// `destination` must not be read, written or dropped until `stream` has
+    /// finished the copy, e.g. until [`Stream::synchronize`] has returned.
+    pub unsafe fn copy_to_host_async(
+        &self,
+        destination: &mut HostMemory<T>,
+        stream: &Stream,
+    ) -> HipResult<()> {
+        if self.pointer.is_null() || destination.len() > self.size {
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+
+        unsafe {
+            let code = record_call!(sys::hipMemcpyAsync(
+                destination.as_pointer() as *mut std::ffi::c_void,
+                self.pointer as *const std::ffi::c_void,
+                std::mem::size_of_val(destination.as_slice()),
+                MemoryCopyKind::DeviceToHost.into(),
+                stream.handle(),
+            ));
+            ((), code).to_result()
+        }
+    }
+
     /// Fills the allocated memory with a specified value.
     ///
     /// # Arguments
@@ -341,48 +873,654 @@ impl<T> MemoryPointer<T> {
         }
 
         unsafe {
-            let code = sys::hipMemset(self.pointer as *mut std::ffi::c_void, value as i32, size);
+            let code = record_call!(sys::hipMemset(
+                self.pointer as *mut std::ffi::c_void,
+                value as i32,
+                size
+            ));
             ((), code).to_result()
         }
     }
-}
 
-// The Drop trait does not return anything by design
-impl<T> Drop for MemoryPointer<T> {
-    fn drop(&mut self) {
-        unsafe {
-            let code = sys::hipFree(self.pointer as *mut std::ffi::c_void);
-            if code != 0 {
-                let error = HipError::new(code);
-                log::error!("MemoryPointer failed to free memory: {:?}", error);
-            }
+    /// Asynchronously fills the allocated memory with a byte value on `stream`.
+    ///
+    /// # Arguments
+    /// * `value` - Value to fill memory with (interpreted as a byte value)
+    /// * `size` - Number of bytes to fill. Must not exceed the allocated size in bytes.
+    /// * `stream` - The stream to enqueue the fill on
+    ///
+    /// # Returns
+    /// * `HipResult<()>` - Success or error status
+    pub fn memset_async(&self, value: u8, size: usize, stream: &Stream) -> HipResult<()> {
+        if size > self.size * std::mem::size_of::<T>() {
+            log::error!(
+                "memset_async: {} bytes exceed the allocation of {} elements",
+                size,
+                self.size
+            );
+            return Err(HipError::from_status(HipStatus::InvalidValue));
         }
-    }
-}
-
-/// Represents a HIP memory pool handle
-#[derive(Debug)]
-pub struct MemPool {
-    handle: sys::hipMemPool_t,
-    props: MemPoolProps,
-}
 
-impl MemPool {
-    /// Creates a new MemPool from a raw handle
-    pub(crate) fn from_raw(handle: sys::hipMemPool_t) -> Self {
-        Self {
-            handle,
-            props: MemPoolProps::default(),
+        if size == 0 {
+            return Ok(());
         }
-    }
-
-    pub fn create(props: MemPoolProps) -> HipResult<Self> {
-        let mut handle = std::ptr::null_mut();
-        let sys_props = props.to_sys_props();
 
         unsafe {
-            let code = sys::hipMemPoolCreate(&mut handle, &sys_props);
-            (Self { handle, props }, code).to_result()
+            let code = record_call!(sys::hipMemsetAsync(
+                self.pointer as *mut std::ffi::c_void,
+                value as i32,
+                size,
+                stream.handle(),
+            ));
+            ((), code).to_result()
+        }
+    }
+}
+
+/// Gets the free and total amount of memory on the current device.
+///
+/// # Returns
+/// * `Result<(usize, usize)>` - The free and total memory in bytes if successful
+///
+/// # Errors
+/// Returns `HipError` if:
+/// * No device is currently active
+/// * The runtime is not initialized
+///
+/// # Examples
+/// ```
+/// let (free, total) = hip_rs::mem_get_info().unwrap();
+/// println!("{} of {} bytes free", free, total);
+/// ```
+pub fn mem_get_info() -> HipResult<(usize, usize)> {
+    let mut free: usize = 0;
+    let mut total: usize = 0;
+    unsafe {
+        let code = record_call!(sys::hipMemGetInfo(&mut free, &mut total));
+        ((free, total), code).to_result()
+    }
+}
+
+/// Allocates one device buffer per host matrix and uploads the matrices.
+///
+/// This sets up the inputs of batched operations such as [`crate::gemm_batched`],
+/// which take one device allocation per matrix in the batch.
+///
+/// # Arguments
+/// * `host_matrices` - The matrices of the batch, each in column-major order
+/// * `elem_per_matrix` - Number of elements in every matrix
+///
+/// # Returns
+/// * `Ok(Vec<MemoryPointer<T>>)` - The device buffers, in the order of `host_matrices`
+/// * `Err(HipError)` - If a matrix does not hold `elem_per_matrix` elements, or an
+///   allocation or upload failed
+pub fn upload_batch<T>(
+    host_matrices: &[&[T]],
+    elem_per_matrix: usize,
+) -> HipResult<Vec<MemoryPointer<T>>> {
+    host_matrices
+        .iter()
+        .enumerate()
+        .map(|(i, matrix)| {
+            if matrix.len() != elem_per_matrix {
+                log::error!(
+                    "upload_batch: matrix {} holds {} elements, expected {}",
+                    i,
+                    matrix.len(),
+                    elem_per_matrix
+                );
+                return Err(HipError::from_status(HipStatus::InvalidValue));
+            }
+
+            // Empty matrices are null pointers, which cannot be copied into
+            let pointer = MemoryPointer::alloc(elem_per_matrix)?;
+            if elem_per_matrix > 0 {
+                pointer.copy_from_host(matrix)?;
+            }
+            Ok(pointer)
+        })
+        .collect()
+}
+
+/// Uploads `data` to a new device buffer, runs `f` on it and downloads the buffer again.
+///
+/// The closure may modify the buffer, for example with hipBLAS calls or kernels.
+/// Its error type only needs to convert from [`HipError`], so closures returning
+/// either a [`HipResult`] or a [`crate::BlasResult`] are accepted. The buffer is
+/// downloaded with a blocking copy, which waits for work on the null stream.
+///
+/// # Arguments
+/// * `data` - The initial contents of the device buffer
+/// * `f` - The computation to run on the buffer
+///
+/// # Returns
+/// * `Ok((Vec<T>, R))` - The downloaded buffer and the result of `f`
+/// * `Err(E)` - If the allocation, a copy or `f` failed
+///
+/// # Examples
+/// ```
+/// use hip_rs::{with_device_buffer, HipResult};
+///
+/// let (data, len) = with_device_buffer(&[1.0f32, 2.0], |buffer| -> HipResult<usize> {
+///     Ok(buffer.size())
+/// })
+/// .unwrap();
+/// assert_eq!(data, [1.0, 2.0]);
+/// assert_eq!(len, 2);
+/// ```
+pub fn with_device_buffer<T, R, E, F>(data: &[T], f: F) -> Result<(Vec<T>, R), E>
+where
+    T: DevicePod,
+    E: From<HipError>,
+    F: FnOnce(&mut MemoryPointer<T>) -> Result<R, E>,
+{
+    // Empty buffers are null pointers, which cannot be copied into or from
+    let mut buffer = MemoryPointer::alloc(data.len())?;
+    if !data.is_empty() {
+        buffer.copy_from_host(data)?;
+    }
+
+    let result = f(&mut buffer)?;
+
+    let mut downloaded = vec![T::default(); data.len()];
+    if !data.is_empty() {
+        buffer.copy_to_host(&mut downloaded)?;
+    }
+    Ok((downloaded, result))
+}
+
+/// Downloads a device buffer and asserts that it starts with `expected`.
+///
+/// Intended for tests, so it is only available in this crate's tests and with
+/// the `test-utils` feature. Elements are compared exactly when `tol` is `None`,
+/// and otherwise must differ by at most `tol` after conversion to `f64`.
+///
+/// # Arguments
+/// * `ptr` - The device buffer to check
+/// * `expected` - The expected leading elements of the buffer
+/// * `tol` - Optional absolute tolerance for floating point data
+///
+/// # Panics
+/// Panics if the download fails, if `expected` is longer than the buffer, or at
+/// the first mismatching element, reporting its index and both values.
+#[cfg(any(test, feature = "test-utils"))]
+pub fn assert_device_eq<T: PartialEq + DevicePod>(
+    ptr: &MemoryPointer<T>,
+    expected: &[T],
+    tol: Option<f64>,
+) {
+    assert!(
+        expected.len() <= ptr.size(),
+        "expected {} elements but the device buffer holds {}",
+        expected.len(),
+        ptr.size()
+    );
+
+    let mut actual = vec![T::default(); expected.len()];
+    ptr.copy_to_host(&mut actual)
+        .expect("failed to download device buffer");
+
+    for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+        let equal = match tol {
+            Some(tol) => (a.to_f64() - e.to_f64()).abs() <= tol,
+            None => a == e,
+        };
+        assert!(
+            equal,
+            "device buffer mismatch at index {}: expected {:?}, got {:?}",
+            i, e, a
+        );
+    }
+}
+
+/// Maps a device allocation shared by another process with [`MemoryPointer::ipc_handle`].
+///
+/// The returned pointer does not own the memory. Dropping it unmaps the
+/// allocation with `hipIpcCloseMemHandle` but never frees it.
+///
+/// # Arguments
+/// * `handle` - The handle received from the exporting process
+///
+/// # Returns
+/// * `Ok(MemoryPointer<T>)` - A pointer covering the whole shared allocation
+/// * `Err(HipError)` - If the handle could not be opened, or the size it carries
+///   exceeds the mapped allocation
+pub fn open_ipc_mem_handle<T>(handle: IpcMemHandle) -> HipResult<MemoryPointer<T>> {
+    let device = super::get_device()?.id();
+    let mut ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+    let pointer: HipResult<MemoryPointer<T>> = unsafe {
+        let code = record_call!(sys::hipIpcOpenMemHandle(
+            &mut ptr,
+            handle.handle,
+            IPC_MEM_LAZY_ENABLE_PEER_ACCESS
+        ));
+        let pointer = MemoryPointer {
+            pointer: ptr as *mut T,
+            size: handle.bytes / std::mem::size_of::<T>(),
+            device,
+            generation: device_generation(device),
+            release: Release::IpcClose,
+        };
+        (pointer, code).to_result()
+    };
+
+    // The size travels next to the handle, so a forged one must not outgrow the mapping
+    let pointer = pointer?;
+    check_mapped_size("open_ipc_mem_handle", &pointer)?;
+    Ok(pointer)
+}
+
+/// Checks that `pointer` does not extend past the end of the allocation it points into.
+///
+/// Pointers reconstructed from data received from another process carry a size
+/// that the runtime has not checked. On failure `pointer` is dropped, which
+/// releases the mapping.
+fn check_mapped_size<T>(name: &str, pointer: &MemoryPointer<T>) -> HipResult<()> {
+    let mut base: sys::hipDeviceptr_t = std::ptr::null_mut();
+    let mut allocation_bytes: usize = 0;
+    unsafe {
+        let code = record_call!(sys::hipMemGetAddressRange(
+            &mut base,
+            &mut allocation_bytes,
+            pointer.pointer as sys::hipDeviceptr_t,
+        ));
+        let result: HipResult<()> = ((), code).to_result();
+        result?;
+    }
+
+    let offset = pointer.pointer as usize - base as usize;
+    let available = allocation_bytes.saturating_sub(offset);
+    let bytes = pointer.size * std::mem::size_of::<T>();
+    if bytes > available {
+        log::error!(
+            "{}: the handle claims {} bytes, but the mapped allocation holds {}",
+            name,
+            bytes,
+            available
+        );
+        return Err(HipError::from_status(HipStatus::InvalidValue));
+    }
+    Ok(())
+}
+
+/// Queries the ordinal of the device that owns the given device pointer.
+///
+/// # Arguments
+/// * `ptr` - A device pointer
+///
+/// # Returns
+/// * `Ok(Device)` - The device the memory was allocated on
+/// * `Err(HipError)` - If the pointer is not known to the HIP runtime
+fn pointer_device<T>(ptr: *const T) -> HipResult<Device> {
+    let mut ordinal: i32 = -1;
+    unsafe {
+        let code = record_call!(sys::hipPointerGetAttribute(
+            &mut ordinal as *mut i32 as *mut std::ffi::c_void,
+            sys::hipPointer_attribute_HIP_POINTER_ATTRIBUTE_DEVICE_ORDINAL,
+            ptr as sys::hipDeviceptr_t,
+        ));
+        (Device::new(ordinal), code).to_result()
+    }
+}
+
+/// Where the memory behind a pointer lives, as reported in [`PointerAttributes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryType {
+    /// Host memory that was not allocated or registered with the runtime
+    Unregistered,
+    /// Page-locked host memory, such as [`HostMemory`]
+    Host,
+    /// Memory on a device, such as [`MemoryPointer::alloc`]
+    Device,
+    /// Managed memory accessible from host and device, see [`MemoryPointer::alloc_managed`]
+    Managed,
+    /// Unified memory accessible from host and device
+    Unified,
+}
+
+impl From<MemoryType> for u32 {
+    fn from(memory_type: MemoryType) -> Self {
+        match memory_type {
+            MemoryType::Unregistered => sys::hipMemoryType_hipMemoryTypeUnregistered,
+            MemoryType::Host => sys::hipMemoryType_hipMemoryTypeHost,
+            MemoryType::Device => sys::hipMemoryType_hipMemoryTypeDevice,
+            MemoryType::Managed => sys::hipMemoryType_hipMemoryTypeManaged,
+            MemoryType::Unified => sys::hipMemoryType_hipMemoryTypeUnified,
+        }
+    }
+}
+
+impl TryFrom<u32> for MemoryType {
+    type Error = HipError;
+
+    fn try_from(value: sys::hipMemoryType) -> Result<Self, Self::Error> {
+        match value {
+            sys::hipMemoryType_hipMemoryTypeUnregistered => Ok(Self::Unregistered),
+            sys::hipMemoryType_hipMemoryTypeHost => Ok(Self::Host),
+            sys::hipMemoryType_hipMemoryTypeDevice => Ok(Self::Device),
+            sys::hipMemoryType_hipMemoryTypeManaged => Ok(Self::Managed),
+            sys::hipMemoryType_hipMemoryTypeUnified => Ok(Self::Unified),
+            _ => Err(HipError::from_status(HipStatus::InvalidValue)),
+        }
+    }
+}
+
+/// Attributes of the allocation containing a pointer, see [`pointer_get_attributes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointerAttributes {
+    /// Where the memory lives
+    pub memory_type: MemoryType,
+    /// The device the memory was allocated on or registered with
+    pub device: Device,
+    /// The address of the memory as seen from the device
+    pub device_pointer: *mut std::ffi::c_void,
+    /// The address of the memory as seen from the host, null for device memory
+    pub host_pointer: *mut std::ffi::c_void,
+    /// Whether the memory is managed
+    pub is_managed: bool,
+}
+
+/// Queries the attributes of the allocation containing the given pointer.
+///
+/// # Arguments
+/// * `ptr` - A host or device pointer, possibly into the middle of an allocation
+///
+/// # Returns
+/// * `Ok(PointerAttributes)` - The attributes of the allocation
+/// * `Err(HipError)` - If the pointer is not known to the runtime, or the
+///   runtime reports an unknown memory type
+///
+/// # Examples
+/// ```
+/// use hip_rs::{pointer_get_attributes, MemoryPointer, MemoryType};
+///
+/// let ptr = MemoryPointer::<f32>::alloc(16).unwrap();
+/// let attributes = pointer_get_attributes(ptr.as_pointer()).unwrap();
+/// assert_eq!(attributes.memory_type, MemoryType::Device);
+/// ```
+pub fn pointer_get_attributes<T>(ptr: *const T) -> HipResult<PointerAttributes> {
+    let mut attributes = std::mem::MaybeUninit::<sys::hipPointerAttribute_t>::zeroed();
+    let attributes = unsafe {
+        let code = record_call!(sys::hipPointerGetAttributes(
+            attributes.as_mut_ptr(),
+            ptr as *const std::ffi::c_void
+        ));
+        let result: HipResult<()> = ((), code).to_result();
+        result?;
+        attributes.assume_init()
+    };
+
+    Ok(PointerAttributes {
+        memory_type: MemoryType::try_from(attributes.type_)?,
+        device: Device::new(attributes.device),
+        device_pointer: attributes.devicePointer,
+        host_pointer: attributes.hostPointer,
+        is_managed: attributes.isManaged != 0,
+    })
+}
+
+/// Queries the [`MemoryType`] of the allocation containing the given pointer.
+fn pointer_memory_type<T>(ptr: *const T) -> HipResult<MemoryType> {
+    let mut memory_type: u32 = 0;
+    unsafe {
+        let code = record_call!(sys::hipPointerGetAttribute(
+            &mut memory_type as *mut u32 as *mut std::ffi::c_void,
+            sys::hipPointer_attribute_HIP_POINTER_ATTRIBUTE_MEMORY_TYPE,
+            ptr as sys::hipDeviceptr_t,
+        ));
+        let result: HipResult<()> = ((), code).to_result();
+        result?;
+    }
+    MemoryType::try_from(memory_type)
+}
+
+/// Checks that `kind` matches the memory types of `src` and `dst`.
+///
+/// Managed and unified memory is accessible from both sides, so it matches any
+/// kind, as does [`MemoryCopyKind::Default`] which lets the runtime infer it.
+fn check_copy_kind<T>(src: *const T, dst: *const T, kind: MemoryCopyKind) -> HipResult<()> {
+    let (src_on_device, dst_on_device) = match kind {
+        MemoryCopyKind::Default => return Ok(()),
+        MemoryCopyKind::HostToHost => (false, false),
+        MemoryCopyKind::HostToDevice => (false, true),
+        MemoryCopyKind::DeviceToHost => (true, false),
+        MemoryCopyKind::DeviceToDevice | MemoryCopyKind::DeviceToDeviceNoCU => (true, true),
+    };
+
+    let matches = |memory_type: MemoryType, on_device: bool| match memory_type {
+        MemoryType::Managed | MemoryType::Unified => true,
+        MemoryType::Device => on_device,
+        MemoryType::Host => !on_device,
+        MemoryType::Unregistered => false,
+    };
+
+    let src_type = pointer_memory_type(src)?;
+    let dst_type = pointer_memory_type(dst)?;
+    if !matches(src_type, src_on_device) || !matches(dst_type, dst_on_device) {
+        log::error!(
+            "copy kind {:?} does not match the memory types of source ({:?}) and destination ({:?})",
+            kind,
+            src_type,
+            dst_type
+        );
+        return Err(HipError::from_status(HipStatus::InvalidValue));
+    }
+    Ok(())
+}
+
+/// Copies all elements of `source` to the start of `destination`, choosing the
+/// appropriate copy for where the two allocations live.
+///
+/// The owning device of both pointers is queried from the runtime. When they are
+/// on the same device a regular device to device copy is performed. When they are
+/// on different devices the copy is routed through `hipMemcpyPeer`, which works
+/// whether or not peer access has been enabled between the devices.
+///
+/// # Arguments
+/// * `source` - The memory pointer to copy from
+/// * `destination` - The memory pointer to copy to
+///
+/// # Returns
+/// * `Ok(())` if the copy was successful
+/// * `Err(HipError)` if the operation failed
+///
+/// # Errors
+/// Returns `HipError` if:
+/// * Either pointer is null
+/// * The destination is smaller than the source
+/// * The owning device of either pointer cannot be determined
+pub fn smart_copy<T>(source: &MemoryPointer<T>, destination: &MemoryPointer<T>) -> HipResult<()> {
+    if source.pointer.is_null() || destination.pointer.is_null() {
+        return Err(HipError::from_status(HipStatus::InvalidValue));
+    }
+
+    if destination.size < source.size {
+        return Err(HipError::from_status(HipStatus::InvalidValue));
+    }
+
+    let src_device = pointer_device(source.pointer)?;
+    let dst_device = pointer_device(destination.pointer)?;
+
+    if src_device == dst_device {
+        return source.copy_to_device(destination);
+    }
+
+    unsafe {
+        let code = record_call!(sys::hipMemcpyPeer(
+            destination.pointer as *mut std::ffi::c_void,
+            dst_device.id(),
+            source.pointer as *const std::ffi::c_void,
+            src_device.id(),
+            source.size * std::mem::size_of::<T>(),
+        ));
+        ((), code).to_result()
+    }
+}
+
+// The Drop trait does not return anything by design
+impl<T> Drop for MemoryPointer<T> {
+    fn drop(&mut self) {
+        // The device was reset after this allocation, so the memory is already gone
+        if self.generation != device_generation(self.device) {
+            log::warn!(
+                "MemoryPointer {:p} outlived a reset of device {}, skipping hipFree",
+                self.pointer,
+                self.device
+            );
+            return;
+        }
+
+        unsafe {
+            match self.release {
+                Release::Free => {
+                    let code = record_call!(sys::hipFree(self.pointer as *mut std::ffi::c_void));
+                    if code != 0 {
+                        let error = HipError::new(code);
+                        log::error!("MemoryPointer failed to free memory: {:?}", error);
+                    }
+                }
+                Release::IpcClose => {
+                    if self.pointer.is_null() {
+                        return;
+                    }
+                    let code = record_call!(sys::hipIpcCloseMemHandle(
+                        self.pointer as *mut std::ffi::c_void
+                    ));
+                    if code != 0 {
+                        let error = HipError::new(code);
+                        log::error!("MemoryPointer failed to close IPC mapping: {:?}", error);
+                    }
+                }
+                Release::Arena | Release::Borrowed => {}
+            }
+        }
+    }
+}
+
+/// Scratch memory whose allocations are all freed together.
+///
+/// Each call to [`AllocArena::alloc`] makes a separate device allocation, owned
+/// by the arena as raw bytes, and hands out a typed [`ArenaBuffer`] borrowing it.
+/// The buffers cannot outlive the arena, and dropping the arena frees every
+/// allocation at once.
+///
+/// # Examples
+/// ```
+/// use hip_rs::AllocArena;
+///
+/// let arena = AllocArena::new();
+/// let indices = arena.alloc::<i32>(16).unwrap();
+/// let values = arena.alloc::<f32>(16).unwrap();
+/// assert_eq!(arena.len(), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct AllocArena {
+    allocations: std::cell::RefCell<Vec<MemoryPointer<u8>>>,
+}
+
+impl AllocArena {
+    /// Creates an empty arena
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocates device memory for `size` elements of type T from the arena.
+    ///
+    /// The memory is only released when the arena is dropped, not when the
+    /// returned buffer is.
+    ///
+    /// # Arguments
+    /// * `size` - Number of elements to allocate
+    ///
+    /// # Returns
+    /// * `Ok(ArenaBuffer<T>)` - The typed allocation, usable as a [`MemoryPointer<T>`]
+    /// * `Err(HipError)` - If the allocation failed or its size overflows
+    pub fn alloc<T>(&self, size: usize) -> HipResult<ArenaBuffer<'_, T>> {
+        let bytes = size.checked_mul(std::mem::size_of::<T>()).ok_or_else(|| {
+            log::error!("AllocArena: {} elements overflow the allocation size", size);
+            HipError::from_status(HipStatus::InvalidValue)
+        })?;
+        let backing = MemoryPointer::<u8>::alloc(bytes)?;
+
+        let pointer = MemoryPointer {
+            pointer: backing.pointer as *mut T,
+            size,
+            device: backing.device,
+            generation: backing.generation,
+            release: Release::Arena,
+        };
+        self.allocations.borrow_mut().push(backing);
+
+        Ok(ArenaBuffer {
+            pointer,
+            _arena: std::marker::PhantomData,
+        })
+    }
+
+    /// Returns the number of allocations made from the arena
+    pub fn len(&self) -> usize {
+        self.allocations.borrow().len()
+    }
+
+    /// Returns true if nothing has been allocated from the arena
+    pub fn is_empty(&self) -> bool {
+        self.allocations.borrow().is_empty()
+    }
+}
+
+/// A typed allocation borrowed from an [`AllocArena`].
+///
+/// Dereferences to a shared [`MemoryPointer<T>`], so it can be passed anywhere a
+/// `&MemoryPointer<T>` is expected. Dropping it does not free the memory.
+///
+/// There is deliberately no mutable access to the pointer, which would allow
+/// moving it out with [`std::mem::replace`] and using it after the arena freed
+/// the memory.
+///
+/// ```compile_fail
+/// use hip_rs::{AllocArena, MemoryPointer};
+///
+/// let arena = AllocArena::new();
+/// let mut buffer = arena.alloc::<f32>(4).unwrap();
+/// let escaped = std::mem::replace(&mut *buffer, MemoryPointer::alloc(4).unwrap());
+/// ```
+#[derive(Debug)]
+pub struct ArenaBuffer<'a, T> {
+    pointer: MemoryPointer<T>,
+    _arena: std::marker::PhantomData<&'a AllocArena>,
+}
+
+impl<T> std::ops::Deref for ArenaBuffer<'_, T> {
+    type Target = MemoryPointer<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pointer
+    }
+}
+
+/// Represents a HIP memory pool handle
+#[derive(Debug)]
+pub struct MemPool {
+    handle: sys::hipMemPool_t,
+    props: MemPoolProps,
+}
+
+impl MemPool {
+    /// Creates a new MemPool from a raw handle
+    pub(crate) fn from_raw(handle: sys::hipMemPool_t) -> Self {
+        Self {
+            handle,
+            props: MemPoolProps::default(),
+        }
+    }
+
+    pub fn create(props: MemPoolProps) -> HipResult<Self> {
+        let mut handle = std::ptr::null_mut();
+        let sys_props = props.to_sys_props();
+
+        unsafe {
+            let code = record_call!(sys::hipMemPoolCreate(&mut handle, &sys_props));
+            (Self { handle, props }, code).to_result()
         }
     }
 
@@ -400,13 +1538,139 @@ impl MemPool {
     pub fn props(&self) -> &MemPoolProps {
         &self.props
     }
+
+    /// Gets the high-water mark of memory reserved by the pool, in bytes.
+    ///
+    /// This is the peak amount of backing memory the pool has held since it was
+    /// created or since [`MemPool::reset_high_water_marks`] was last called.
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The peak reserved memory in bytes
+    /// * `Err(HipError)` - If the attribute could not be queried
+    pub fn reserved_mem_high(&self) -> HipResult<u64> {
+        self.get_u64_attribute(sys::hipMemPoolAttr_hipMemPoolAttrReservedMemHigh)
+    }
+
+    /// Gets the high-water mark of memory handed out to allocations from the
+    /// pool, in bytes.
+    ///
+    /// This is the peak amount of memory in use since the pool was created or
+    /// since [`MemPool::reset_high_water_marks`] was last called.
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The peak used memory in bytes
+    /// * `Err(HipError)` - If the attribute could not be queried
+    pub fn used_mem_high(&self) -> HipResult<u64> {
+        self.get_u64_attribute(sys::hipMemPoolAttr_hipMemPoolAttrUsedMemHigh)
+    }
+
+    /// Resets the reserved and used high-water marks to the pool's current usage.
+    ///
+    /// Call this at the start of a phase to measure its peak memory use with
+    /// [`MemPool::reserved_mem_high`] and [`MemPool::used_mem_high`].
+    ///
+    /// # Returns
+    /// * `Ok(())` - Both marks were reset
+    /// * `Err(HipError)` - If either attribute could not be set
+    pub fn reset_high_water_marks(&self) -> HipResult<()> {
+        // The runtime only accepts zero, which resets the mark to the current value
+        self.set_u64_attribute(sys::hipMemPoolAttr_hipMemPoolAttrReservedMemHigh, 0)?;
+        self.set_u64_attribute(sys::hipMemPoolAttr_hipMemPoolAttrUsedMemHigh, 0)
+    }
+
+    /// Exports an allocation from this pool for use in another process.
+    ///
+    /// The pool must have been created with a shareable
+    /// [`MemPoolProps::handle_types`], and the importing process must have
+    /// imported the pool itself before calling [`MemPool::import_pointer`].
+    ///
+    /// # Arguments
+    /// * `ptr` - An allocation made from this pool
+    ///
+    /// # Returns
+    /// * `Ok(ExportData)` - Data identifying the allocation to the importing process
+    /// * `Err(HipError)` - If the pointer could not be exported
+    pub fn export_pointer<T>(&self, ptr: &MemoryPointer<T>) -> HipResult<ExportData> {
+        let mut data: sys::hipMemPoolPtrExportData = unsafe { std::mem::zeroed() };
+        unsafe {
+            let code = record_call!(sys::hipMemPoolExportPointer(
+                &mut data,
+                ptr.as_pointer() as *mut std::ffi::c_void
+            ));
+            let export = ExportData {
+                data,
+                bytes: ptr.size() * std::mem::size_of::<T>(),
+            };
+            (export, code).to_result()
+        }
+    }
+
+    /// Imports an allocation exported by another process with [`MemPool::export_pointer`].
+    ///
+    /// The returned pointer is freed with `hipFree` when dropped, which must
+    /// happen before the exporting process frees the allocation.
+    ///
+    /// # Arguments
+    /// * `data` - The export data received from the exporting process
+    ///
+    /// # Returns
+    /// * `Ok(MemoryPointer<T>)` - A pointer covering the whole shared allocation
+    /// * `Err(HipError)` - If the allocation could not be imported, or the size
+    ///   carried by `data` exceeds the imported allocation
+    pub fn import_pointer<T>(&self, data: &ExportData) -> HipResult<MemoryPointer<T>> {
+        let device = super::get_device()?.id();
+        let mut ptr: *mut std::ffi::c_void = std::ptr::null_mut();
+        let mut export = data.data;
+        let pointer: HipResult<MemoryPointer<T>> = unsafe {
+            let code = record_call!(sys::hipMemPoolImportPointer(
+                &mut ptr,
+                self.handle,
+                &mut export
+            ));
+            let pointer = MemoryPointer {
+                pointer: ptr as *mut T,
+                size: data.bytes / std::mem::size_of::<T>(),
+                device,
+                generation: device_generation(device),
+                release: Release::Free,
+            };
+            (pointer, code).to_result()
+        };
+
+        let pointer = pointer?;
+        check_mapped_size("import_pointer", &pointer)?;
+        Ok(pointer)
+    }
+
+    fn get_u64_attribute(&self, attr: sys::hipMemPoolAttr) -> HipResult<u64> {
+        let mut value: u64 = 0;
+        unsafe {
+            let code = record_call!(sys::hipMemPoolGetAttribute(
+                self.handle,
+                attr,
+                &mut value as *mut u64 as *mut std::ffi::c_void,
+            ));
+            (value, code).to_result()
+        }
+    }
+
+    fn set_u64_attribute(&self, attr: sys::hipMemPoolAttr, mut value: u64) -> HipResult<()> {
+        unsafe {
+            let code = record_call!(sys::hipMemPoolSetAttribute(
+                self.handle,
+                attr,
+                &mut value as *mut u64 as *mut std::ffi::c_void,
+            ));
+            ((), code).to_result()
+        }
+    }
 }
 
 impl Drop for MemPool {
     fn drop(&mut self) {
         if !self.handle.is_null() {
             unsafe {
-                let code = sys::hipMemPoolDestroy(self.handle);
+                let code = record_call!(sys::hipMemPoolDestroy(self.handle));
                 if code != 0 {
                     log::error!("Failed to destroy memory pool: {}", code);
                 }
@@ -520,6 +1784,23 @@ mod tests {
         assert!(!pool.is_null());
     }
 
+    #[test]
+    fn test_mempool_reset_high_water_marks() {
+        let pool = Device::new(0).get_default_mem_pool().unwrap();
+        let stream = Stream::create().unwrap();
+        let bytes = 4 << 20;
+
+        let ptr = MemoryPointer::<u8>::alloc_async(bytes, &stream).unwrap();
+        stream.synchronize().unwrap();
+        let peak = pool.used_mem_high().unwrap();
+        assert!(peak >= bytes as u64);
+        assert!(pool.reserved_mem_high().unwrap() >= peak);
+
+        drop(ptr);
+        pool.reset_high_water_marks().unwrap();
+        assert!(pool.used_mem_high().unwrap() < peak);
+    }
+
     // #[test]
     // fn test_mempool_drop() {
     //     let props = MemPoolProps::new();
@@ -598,6 +1879,18 @@ mod tests {
         assert!(!ptr.pointer.is_null());
     }
 
+    #[test]
+    fn test_alloc_with_flag_finegrained() {
+        let device = crate::get_device().unwrap();
+        let result = MemoryPointer::<u32>::alloc_with_flag(1024, DeviceMallocFlag::FINEGRAINED);
+
+        if device.supports_fine_grain().unwrap() {
+            assert!(!result.unwrap().pointer.is_null());
+        } else {
+            assert_eq!(result.unwrap_err().status, HipStatus::NotSupported);
+        }
+    }
+
     #[test]
     fn test_alloc_with_flag_zero_size() {
         let result = MemoryPointer::<u8>::alloc_with_flag(0, DeviceMallocFlag::DEFAULT);
@@ -648,6 +1941,9 @@ mod tests {
         let null_ptr = MemoryPointer::<u32> {
             pointer: std::ptr::null_mut(),
             size: 0,
+            device: 0,
+            generation: device_generation(0),
+            release: Release::Free,
         };
         let result = src.copy_to(&null_ptr, MemoryCopyKind::DeviceToDevice);
         assert!(result.is_err());
@@ -657,4 +1953,466 @@ mod tests {
         let result = src.copy_to(&small_dst, MemoryCopyKind::DeviceToDevice);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_copy_to_device() {
+        let src = MemoryPointer::<u32>::alloc(4).unwrap();
+        let dst = MemoryPointer::<u32>::alloc(4).unwrap();
+        src.copy_from_host(&[1, 2, 3, 4]).unwrap();
+
+        src.copy_to_device(&dst).unwrap();
+
+        let mut result = [0u32; 4];
+        dst.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_copy_to_mismatched_kind() {
+        let src = MemoryPointer::<u32>::alloc(4).unwrap();
+        let dst = MemoryPointer::<u32>::alloc(4).unwrap();
+
+        for kind in [
+            MemoryCopyKind::HostToDevice,
+            MemoryCopyKind::DeviceToHost,
+            MemoryCopyKind::HostToHost,
+        ] {
+            let error = src.copy_to(&dst, kind).unwrap_err();
+            assert_eq!(error.status, HipStatus::InvalidValue);
+        }
+    }
+
+    #[test]
+    fn test_drop_after_device_reset() {
+        let device = crate::Device::new(0);
+        crate::set_device(device).unwrap();
+
+        let ptr = MemoryPointer::<u32>::alloc(1024).unwrap();
+        assert!(!ptr.pointer.is_null());
+
+        // Resetting the device releases the allocation behind our back
+        device.reset().unwrap();
+        assert_ne!(ptr.generation, device_generation(device.id()));
+
+        // Dropping must not call hipFree on the stale address
+        drop(ptr);
+
+        // The device is still usable afterwards
+        let ptr = MemoryPointer::<u32>::alloc(1024).unwrap();
+        assert_eq!(ptr.generation, device_generation(device.id()));
+    }
+
+    #[test]
+    fn test_copy_prefix_to() {
+        let size = 8;
+        let prefix = 3;
+        let src_data: Vec<u32> = (1..=size as u32).collect();
+        let dst_data = vec![0xDEADu32; size];
+
+        let src = MemoryPointer::<u32>::alloc(size).unwrap();
+        let dst = MemoryPointer::<u32>::alloc(size).unwrap();
+        unsafe {
+            memory_copy(
+                src.as_pointer() as *mut std::ffi::c_void,
+                src_data.as_ptr() as *const std::ffi::c_void,
+                size * std::mem::size_of::<u32>(),
+                MemoryCopyKind::HostToDevice,
+            )
+            .unwrap();
+            memory_copy(
+                dst.as_pointer() as *mut std::ffi::c_void,
+                dst_data.as_ptr() as *const std::ffi::c_void,
+                size * std::mem::size_of::<u32>(),
+                MemoryCopyKind::HostToDevice,
+            )
+            .unwrap();
+        }
+
+        let result = src.copy_prefix_to(&dst, prefix, MemoryCopyKind::DeviceToDevice);
+        assert!(result.is_ok());
+
+        let mut host = vec![0u32; size];
+        unsafe {
+            memory_copy(
+                host.as_mut_ptr() as *mut std::ffi::c_void,
+                dst.as_pointer() as *const std::ffi::c_void,
+                size * std::mem::size_of::<u32>(),
+                MemoryCopyKind::DeviceToHost,
+            )
+            .unwrap();
+        }
+
+        // Only the prefix is overwritten
+        assert_eq!(&host[..prefix], &src_data[..prefix]);
+        assert!(host[prefix..].iter().all(|&v| v == 0xDEAD));
+    }
+
+    #[test]
+    fn test_copy_prefix_to_out_of_bounds() {
+        let src = MemoryPointer::<u32>::alloc(4).unwrap();
+        let dst = MemoryPointer::<u32>::alloc(8).unwrap();
+
+        // Count larger than the destination
+        let result = dst.copy_prefix_to(&src, 5, MemoryCopyKind::DeviceToDevice);
+        assert!(result.is_err());
+
+        // Count larger than the source
+        let result = src.copy_prefix_to(&dst, 5, MemoryCopyKind::DeviceToDevice);
+        assert!(result.is_err());
+
+        // Copying into a larger destination is fine
+        let result = src.copy_prefix_to(&dst, 4, MemoryCopyKind::DeviceToDevice);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_host_round_trip() {
+        let ptr = MemoryPointer::<u32>::alloc(4).unwrap();
+        let data = vec![1u32, 2, 3, 4];
+        ptr.copy_from_host(&data).unwrap();
+
+        let mut result = vec![0u32; 4];
+        ptr.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, data);
+
+        // Longer than the allocation
+        assert!(ptr.copy_from_host(&[0u32; 5]).is_err());
+        assert!(ptr.copy_to_host(&mut [0u32; 5]).is_err());
+    }
+
+    #[test]
+    fn test_async_host_round_trip() {
+        let stream = Stream::create().unwrap();
+        let ptr = MemoryPointer::<u32>::alloc(4).unwrap();
+
+        let mut upload = HostMemory::<u32>::alloc(4).unwrap();
+        upload.as_mut_slice().copy_from_slice(&[1, 2, 3, 4]);
+        let mut download = HostMemory::<u32>::alloc(4).unwrap();
+
+        // Neither buffer is touched until the stream is synchronized
+        unsafe {
+            ptr.copy_from_host_async(&upload, &stream).unwrap();
+            ptr.copy_to_host_async(&mut download, &stream).unwrap();
+        }
+        stream.synchronize().unwrap();
+
+        assert_eq!(download.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_assert_device_eq() {
+        let ptr = MemoryPointer::<f32>::alloc(3).unwrap();
+        ptr.copy_from_host(&[1.0, 2.0, 3.0]).unwrap();
+
+        assert_device_eq(&ptr, &[1.0, 2.0, 3.0], None);
+        assert_device_eq(&ptr, &[1.0, 2.0], None);
+        assert_device_eq(&ptr, &[1.0, 2.0, 3.001], Some(1e-2));
+    }
+
+    #[test]
+    fn test_upload_batch() {
+        let a1 = [1.0f32, 2.0, 3.0, 4.0];
+        let a2 = [0.5f32, 1.0, 1.5, 2.0];
+
+        let batch = upload_batch(&[&a1, &a2], 4).unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_device_eq(&batch[0], &a1, None);
+        assert_device_eq(&batch[1], &a2, None);
+
+        // Matrices of the wrong size are rejected
+        let result = upload_batch(&[&a1, &a2[..3]], 4);
+        assert_eq!(result.unwrap_err().status, HipStatus::InvalidValue);
+    }
+
+    #[test]
+    fn test_with_device_buffer() {
+        use crate::{scal, BlasHandle, BlasResult};
+
+        let handle = BlasHandle::new().unwrap();
+        let (data, ()) = with_device_buffer(&[1.0f32, 2.0, 3.0], |x| -> BlasResult<()> {
+            scal(&handle, 3, &2.0, x, 1)
+        })
+        .unwrap();
+        assert_eq!(data, [2.0, 4.0, 6.0]);
+
+        let (data, len) =
+            with_device_buffer(&[] as &[f32], |x| -> HipResult<usize> { Ok(x.size()) }).unwrap();
+        assert!(data.is_empty());
+        assert_eq!(len, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "device buffer mismatch at index 2: expected 4.0, got 3.0")]
+    fn test_assert_device_eq_mismatch() {
+        let ptr = MemoryPointer::<f32>::alloc(3).unwrap();
+        ptr.copy_from_host(&[1.0, 2.0, 3.0]).unwrap();
+
+        assert_device_eq(&ptr, &[1.0, 2.0, 4.0], Some(1e-3));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_ipc_mem_handle_round_trip() {
+        let ptr = MemoryPointer::<u32>::alloc(4).unwrap();
+        ptr.copy_from_host(&[1, 2, 3, 4]).unwrap();
+
+        let handle = ptr.ipc_handle().unwrap();
+        assert_eq!(handle.size_in_bytes(), 16);
+
+        // Serialize as if sending to another process
+        let handle = IpcMemHandle::from_bytes(&handle.to_bytes());
+        let opened = open_ipc_mem_handle::<u32>(handle).unwrap();
+        assert_eq!(opened.size(), 4);
+        assert_device_eq(&opened, &[1, 2, 3, 4], None);
+    }
+
+    #[test]
+    fn test_open_ipc_mem_handle_forged_size() {
+        let ptr = MemoryPointer::<u32>::alloc(4).unwrap();
+        let mut bytes = ptr.ipc_handle().unwrap().to_bytes();
+
+        // Claim a 1 MiB allocation behind a 16 byte one
+        bytes[64..].copy_from_slice(&(1u64 << 20).to_le_bytes());
+        let handle = IpcMemHandle::from_bytes(&bytes);
+
+        let result = open_ipc_mem_handle::<u32>(handle);
+        assert_eq!(result.unwrap_err().status, HipStatus::InvalidValue);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_mempool_export_import_pointer() {
+        let pool = MemPool::create(MemPoolProps::new()).unwrap();
+        let stream = Stream::create().unwrap();
+        let ptr = MemoryPointer::<u32>::alloc_from_pool(4, &pool, &stream).unwrap();
+        stream.synchronize().unwrap();
+        ptr.copy_from_host(&[1, 2, 3, 4]).unwrap();
+
+        let data = pool.export_pointer(&ptr).unwrap();
+        assert_eq!(data.size_in_bytes(), 16);
+
+        // Serialize as if sending to another process
+        let data = ExportData::from_bytes(&data.to_bytes());
+        let imported = pool.import_pointer::<u32>(&data).unwrap();
+        assert_eq!(imported.size(), 4);
+        assert_device_eq(&imported, &[1, 2, 3, 4], None);
+
+        // Within one process the import aliases the allocation owned by `ptr`
+        std::mem::forget(imported);
+    }
+
+    #[test]
+    fn test_prefetch_managed_to_host() {
+        let device = crate::get_device().unwrap();
+        let supported = device
+            .get_attribute(crate::DeviceAttribute::ConcurrentManagedAccess)
+            .unwrap();
+        if supported == 0 {
+            println!("Managed memory prefetch not supported on this device, skipping test");
+            return;
+        }
+
+        let size = 256;
+        let ptr = MemoryPointer::<u32>::alloc_managed(size).unwrap();
+        let stream = Stream::create().unwrap();
+
+        // Managed memory can be written directly from the host
+        let host = unsafe { std::slice::from_raw_parts_mut(ptr.as_pointer(), size) };
+        for (i, value) in host.iter_mut().enumerate() {
+            *value = i as u32;
+        }
+
+        // Move to the device and back to the host
+        ptr.prefetch_async(device, &stream).unwrap();
+        ptr.prefetch_async(Device::CPU, &stream).unwrap();
+        crate::synchronize().unwrap();
+
+        let host = unsafe { std::slice::from_raw_parts(ptr.as_pointer(), size) };
+        for (i, value) in host.iter().enumerate() {
+            assert_eq!(*value, i as u32);
+        }
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let size = 16;
+        let first = MemoryPointer::<u32>::alloc(size).unwrap();
+        let second = MemoryPointer::<u32>::alloc(size).unwrap();
+
+        // Views into the same allocation must never be freed themselves
+        let view = |offset: usize, len: usize| {
+            std::mem::ManuallyDrop::new(MemoryPointer::<u32> {
+                pointer: unsafe { first.as_pointer().add(offset) },
+                size: len,
+                device: first.device,
+                generation: first.generation,
+                release: Release::Free,
+            })
+        };
+        let head = view(0, 8);
+        let middle = view(4, 8);
+        let tail = view(8, 8);
+
+        assert!(first.overlaps(&first));
+        assert!(head.overlaps(&middle));
+        assert!(middle.overlaps(&tail));
+        assert!(first.overlaps(&tail));
+
+        // Adjacent regions do not overlap
+        assert!(!head.overlaps(&tail));
+
+        // Separate allocations do not overlap
+        assert!(!first.overlaps(&second));
+        assert!(!second.overlaps(&first));
+
+        // Empty pointers never overlap
+        let empty = MemoryPointer::<u32>::alloc(0).unwrap();
+        assert!(!empty.overlaps(&first));
+    }
+
+    #[test]
+    fn test_alloc_arena() {
+        let arena = AllocArena::new();
+        assert!(arena.is_empty());
+
+        let floats = arena.alloc::<f32>(4).unwrap();
+        let indices = arena.alloc::<u32>(3).unwrap();
+        let bytes = arena.alloc::<u8>(8).unwrap();
+        let empty = arena.alloc::<f64>(0).unwrap();
+        assert_eq!(arena.len(), 4);
+        assert_eq!(floats.size(), 4);
+        assert_eq!(empty.size(), 0);
+
+        floats.copy_from_host(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        indices.copy_from_host(&[7, 8, 9]).unwrap();
+        bytes.memset(0xab, 8).unwrap();
+
+        // Dropping a buffer leaves its memory to the arena
+        let scratch = arena.alloc::<f32>(4).unwrap();
+        floats.copy_to_device(&scratch).unwrap();
+        let device_copy = scratch.as_pointer();
+        drop(scratch);
+        assert_eq!(arena.len(), 5);
+
+        // Read the copy back through the arena's own byte allocation
+        let allocations = arena.allocations.borrow();
+        let backing = &allocations[4];
+        assert_eq!(backing.as_pointer() as *mut f32, device_copy);
+        let mut result = [0u8; 16];
+        backing.copy_to_host(&mut result).unwrap();
+        let expected: Vec<u8> = [1.0f32, 2.0, 3.0, 4.0]
+            .iter()
+            .flat_map(|x| x.to_ne_bytes())
+            .collect();
+        assert_eq!(result.as_slice(), expected.as_slice());
+        drop(allocations);
+
+        let mut index_result = [0u32; 3];
+        indices.copy_to_host(&mut index_result).unwrap();
+        assert_eq!(index_result, [7, 8, 9]);
+        let mut byte_result = [0u8; 8];
+        bytes.copy_to_host(&mut byte_result).unwrap();
+        assert_eq!(byte_result, [0xab; 8]);
+
+        drop((floats, indices, bytes, empty));
+        drop(arena);
+    }
+
+    #[test]
+    fn test_mem_get_info() {
+        let (free, total) = mem_get_info().unwrap();
+        assert!(total > 0);
+        assert!(free <= total);
+    }
+
+    #[test]
+    fn test_pointer_get_attributes() {
+        let device = MemoryPointer::<f32>::alloc(16).unwrap();
+        let attributes = pointer_get_attributes(device.as_pointer()).unwrap();
+        assert_eq!(attributes.memory_type, MemoryType::Device);
+        assert_eq!(attributes.device_pointer, device.as_pointer() as *mut _);
+        assert!(!attributes.is_managed);
+
+        let managed = MemoryPointer::<f32>::alloc_managed(16).unwrap();
+        let attributes = pointer_get_attributes(managed.as_pointer()).unwrap();
+        assert_eq!(attributes.memory_type, MemoryType::Managed);
+        assert!(attributes.is_managed);
+
+        let host = HostMemory::<f32>::alloc(16).unwrap();
+        let attributes = pointer_get_attributes(host.as_pointer()).unwrap();
+        assert_eq!(attributes.memory_type, MemoryType::Host);
+        assert_eq!(attributes.host_pointer, host.as_pointer() as *mut _);
+    }
+
+    #[test]
+    fn test_memory_type_conversion() {
+        for memory_type in [
+            MemoryType::Unregistered,
+            MemoryType::Host,
+            MemoryType::Device,
+            MemoryType::Managed,
+            MemoryType::Unified,
+        ] {
+            assert_eq!(
+                MemoryType::try_from(u32::from(memory_type)),
+                Ok(memory_type)
+            );
+        }
+        assert!(MemoryType::try_from(42).is_err());
+    }
+
+    #[test]
+    fn test_smart_copy_same_device() {
+        let size = 64;
+        let src = MemoryPointer::<u32>::alloc(size).unwrap();
+        let dst = MemoryPointer::<u32>::alloc(size).unwrap();
+
+        let result = smart_copy(&src, &dst);
+        assert!(result.is_ok());
+
+        let small = MemoryPointer::<u32>::alloc(size / 2).unwrap();
+        assert!(smart_copy(&src, &small).is_err());
+    }
+
+    #[test]
+    fn test_smart_copy_cross_device() {
+        if crate::get_device_count().unwrap() < 2 {
+            println!("Fewer than two devices available, skipping test");
+            return;
+        }
+
+        let size = 256;
+        let data: Vec<u32> = (0..size as u32).collect();
+
+        crate::set_device(Device::new(0)).unwrap();
+        let src = MemoryPointer::<u32>::alloc(size).unwrap();
+        unsafe {
+            memory_copy(
+                src.as_pointer() as *mut std::ffi::c_void,
+                data.as_ptr() as *const std::ffi::c_void,
+                size * std::mem::size_of::<u32>(),
+                MemoryCopyKind::HostToDevice,
+            )
+            .unwrap();
+        }
+
+        crate::set_device(Device::new(1)).unwrap();
+        let dst = MemoryPointer::<u32>::alloc(size).unwrap();
+        assert_eq!(pointer_device(dst.as_pointer()).unwrap(), Device::new(1));
+
+        crate::set_device(Device::new(0)).unwrap();
+        smart_copy(&src, &dst).unwrap();
+
+        let mut host = vec![0u32; size];
+        unsafe {
+            memory_copy(
+                host.as_mut_ptr() as *mut std::ffi::c_void,
+                dst.as_pointer() as *const std::ffi::c_void,
+                size * std::mem::size_of::<u32>(),
+                MemoryCopyKind::DeviceToHost,
+            )
+            .unwrap();
+        }
+        assert_eq!(host, data);
+    }
 }
@@ -1,20 +1,32 @@
 mod device;
 mod device_types;
+mod event;
 mod flags;
 mod hip_call;
+mod host_memory;
 mod init;
 mod memory;
+mod module;
+mod pod;
 mod result;
+mod scheduler;
+mod signal_memory;
 mod stream;
 
 // use crate::sys::*;
 // Re-export core functionality
 pub use device::*;
 pub use device_types::*;
+pub use event::*;
 pub use flags::*;
 #[allow(unused_imports)]
 pub use hip_call::*;
+pub use host_memory::*;
 pub use init::*;
 pub use memory::*;
+pub use module::*;
+pub use pod::*;
 pub use result::*;
+pub use scheduler::*;
+pub use signal_memory::*;
 pub use stream::*;
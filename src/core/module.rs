@@ -0,0 +1,205 @@
+use super::result::{HipError, HipResult, HipStatus};
+use crate::result::ResultExt;
+use crate::sys;
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// A loaded code object containing device functions.
+///
+/// The code object is unloaded when the module is dropped, which invalidates
+/// every [`Function`] retrieved from it. The borrow checker enforces this, as a
+/// `Function` borrows the module it came from.
+#[derive(Debug)]
+pub struct Module {
+    handle: sys::hipModule_t,
+}
+
+/// A device function of a loaded [`Module`].
+#[derive(Debug, Clone, Copy)]
+pub struct Function<'a> {
+    handle: sys::hipFunction_t,
+    module: PhantomData<&'a Module>,
+}
+
+/// Magic numbers of the code object formats `hipModuleLoadData` accepts.
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+const OFFLOAD_BUNDLE_MAGIC: &[u8] = b"__CLANG_OFFLOAD_BUNDLE__";
+
+/// Converts `s` to a C string, rejecting interior NUL bytes.
+fn to_c_string(what: &str, s: &str) -> HipResult<CString> {
+    CString::new(s).map_err(|_| {
+        log::error!("{} {:?} contains a NUL byte", what, s);
+        HipError::from_status(HipStatus::InvalidValue)
+    })
+}
+
+impl Module {
+    /// Loads a code object from a file on the current device.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the code object, e.g. a `.hsaco` or `.co` file
+    ///
+    /// # Returns
+    /// * `Ok(Module)` - The loaded module
+    /// * `Err(HipError)` - If the file does not exist (`HipStatus::FileNotFound`),
+    ///   is not a valid code object for the device, or the path is not valid UTF-8
+    ///   or contains a NUL byte
+    pub fn load(path: impl AsRef<Path>) -> HipResult<Self> {
+        let path = path.as_ref();
+        let path = path.to_str().ok_or_else(|| {
+            log::error!("Module path {:?} is not valid UTF-8", path);
+            HipError::from_status(HipStatus::InvalidValue)
+        })?;
+        let path = to_c_string("Module path", path)?;
+
+        let mut handle: sys::hipModule_t = std::ptr::null_mut();
+        unsafe {
+            let code = record_call!(sys::hipModuleLoad(&mut handle, path.as_ptr()));
+            (Self { handle }, code).to_result()
+        }
+    }
+
+    /// Loads a code object from memory on the current device.
+    ///
+    /// This allows embedding code objects in the binary with [`include_bytes!`],
+    /// so no files need to be shipped alongside it.
+    ///
+    /// # Arguments
+    /// * `image` - The contents of a code object file
+    ///
+    /// # Returns
+    /// * `Ok(Module)` - The loaded module
+    /// * `Err(HipError)` - If `image` does not start with an ELF or offload bundle
+    ///   header (`HipStatus::InvalidValue`), or is not a valid code object for the device
+    ///
+    /// # Safety
+    /// `hipModuleLoadData` takes no length and trusts the offsets in the image
+    /// headers, so only the magic number is checked here. The caller must ensure
+    /// that:
+    /// * `image` is a complete, well-formed code object, with every header and
+    ///   section it describes lying within the slice
+    /// * `image` stays alive until the module is dropped
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use hip_rs::Module;
+    ///
+    /// static KERNELS: &[u8] = include_bytes!("kernels.hsaco");
+    ///
+    /// // SAFETY: the image is a complete code object that lives for the whole program
+    /// let module = unsafe { Module::load_data(KERNELS) }.unwrap();
+    /// let function = module.get_function("vector_add").unwrap();
+    /// ```
+    pub unsafe fn load_data(image: &[u8]) -> HipResult<Self> {
+        if !image.starts_with(ELF_MAGIC) && !image.starts_with(OFFLOAD_BUNDLE_MAGIC) {
+            log::error!("Module::load_data: the image is not an ELF or offload bundle code object");
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+
+        let mut handle: sys::hipModule_t = std::ptr::null_mut();
+        unsafe {
+            let code = record_call!(sys::hipModuleLoadData(
+                &mut handle,
+                image.as_ptr() as *const std::ffi::c_void
+            ));
+            (Self { handle }, code).to_result()
+        }
+    }
+
+    /// Looks up a device function of the module by name.
+    ///
+    /// # Arguments
+    /// * `name` - The symbol name of the function, mangled unless declared `extern "C"`
+    ///
+    /// # Returns
+    /// * `Ok(Function)` - The function
+    /// * `Err(HipError)` - If the module has no function of that name
+    ///   (`HipStatus::NotFound`), or `name` contains a NUL byte
+    pub fn get_function(&self, name: &str) -> HipResult<Function<'_>> {
+        let name = to_c_string("Function name", name)?;
+
+        let mut handle: sys::hipFunction_t = std::ptr::null_mut();
+        unsafe {
+            let code = record_call!(sys::hipModuleGetFunction(
+                &mut handle,
+                self.handle,
+                name.as_ptr()
+            ));
+            let function = Function {
+                handle,
+                module: PhantomData,
+            };
+            (function, code).to_result()
+        }
+    }
+
+    /// Returns the raw module handle.
+    pub fn handle(&self) -> sys::hipModule_t {
+        self.handle
+    }
+}
+
+impl Function<'_> {
+    /// Returns the raw function handle.
+    pub fn handle(&self) -> sys::hipFunction_t {
+        self.handle
+    }
+}
+
+impl Drop for Module {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                let code = record_call!(sys::hipModuleUnload(self.handle));
+                if code != 0 {
+                    log::error!("Failed to unload HIP module: {}", code);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_load_data() {
+        // No code object is bundled, as it would have to be built for the test device
+        let (Ok(path), Ok(kernel)) = (
+            std::env::var("HIP_RS_TEST_CODE_OBJECT"),
+            std::env::var("HIP_RS_TEST_KERNEL"),
+        ) else {
+            println!("HIP_RS_TEST_CODE_OBJECT or HIP_RS_TEST_KERNEL not set, skipping test");
+            return;
+        };
+
+        let image = std::fs::read(path).unwrap();
+        // SAFETY: the image is a whole code object file and outlives the module
+        let module = unsafe { Module::load_data(&image) }.unwrap();
+        let function = module.get_function(&kernel).unwrap();
+        assert!(!function.handle().is_null());
+
+        let result = module.get_function("hip_rs_no_such_kernel");
+        assert_eq!(result.unwrap_err().status, HipStatus::NotFound);
+    }
+
+    #[test]
+    fn test_module_load_data_invalid() {
+        // SAFETY: images without a known magic number never reach the runtime
+        let result = unsafe { Module::load_data(&[]) };
+        assert_eq!(result.unwrap_err().status, HipStatus::InvalidValue);
+
+        let result = unsafe { Module::load_data(b"not a code object") };
+        assert_eq!(result.unwrap_err().status, HipStatus::InvalidValue);
+        let result = unsafe { Module::load_data(b"\x7fEL") };
+        assert_eq!(result.unwrap_err().status, HipStatus::InvalidValue);
+    }
+
+    #[test]
+    fn test_module_load_missing_file() {
+        let result = Module::load("/nonexistent/hip_rs_kernels.hsaco");
+        assert_eq!(result.unwrap_err().status, HipStatus::FileNotFound);
+    }
+}
@@ -0,0 +1,35 @@
+/// Plain element types that can be copied byte-for-byte between host and device.
+///
+/// # Safety
+/// Implementors must be valid for any bit pattern the device may write and must
+/// not contain pointers or references into host memory.
+pub unsafe trait DevicePod: Copy + Default + std::fmt::Debug {
+    /// Returns the value as an `f64`, used for tolerance based comparisons.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_device_pod {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl DevicePod for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_device_pod!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(3u8.to_f64(), 3.0);
+        assert_eq!((-2i32).to_f64(), -2.0);
+        assert_eq!(0.5f32.to_f64(), 0.5);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::result::{ResultExt, StatusCode};
+use crate::sys;
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8,9 +9,16 @@ pub enum HipStatus {
     MemoryAllocation = 2,
     NotInitialized = 3,
     Deinitialized = 4,
+    NoDevice = 100,
     InvalidDevice = 101,
+    InvalidImage = 200,
+    UnsupportedLimit = 215,
     FileNotFound = 301,
+    InvalidHandle = 400,
+    NotFound = 500,
     NotReady = 600,
+    PeerAccessAlreadyEnabled = 704,
+    PeerAccessNotEnabled = 705,
     NotSupported = 801,
     Unknown = 999,
 }
@@ -23,9 +31,16 @@ impl HipStatus {
             2 => HipStatus::MemoryAllocation,
             3 => HipStatus::NotInitialized,
             4 => HipStatus::Deinitialized,
+            100 => HipStatus::NoDevice,
             101 => HipStatus::InvalidDevice,
+            200 => HipStatus::InvalidImage,
+            215 => HipStatus::UnsupportedLimit,
             301 => HipStatus::FileNotFound,
+            400 => HipStatus::InvalidHandle,
+            500 => HipStatus::NotFound,
             600 => HipStatus::NotReady,
+            704 => HipStatus::PeerAccessAlreadyEnabled,
+            705 => HipStatus::PeerAccessNotEnabled,
             801 => HipStatus::NotSupported,
             _ => HipStatus::Unknown,
         }
@@ -52,6 +67,19 @@ impl HipError {
             code: status as u32,
         }
     }
+
+    /// Returns the runtime's description of the raw error code, from `hipGetErrorString`.
+    pub fn message(&self) -> &'static str {
+        unsafe {
+            let message = sys::hipGetErrorString(self.code);
+            if message.is_null() {
+                return "unknown error";
+            }
+            std::ffi::CStr::from_ptr(message)
+                .to_str()
+                .unwrap_or("unknown error")
+        }
+    }
 }
 
 impl StatusCode for HipError {
@@ -74,9 +102,16 @@ impl StatusCode for HipError {
             HipStatus::MemoryAllocation => "MemoryAllocation",
             HipStatus::NotInitialized => "NotInitialized",
             HipStatus::Deinitialized => "Deinitialized",
+            HipStatus::NoDevice => "NoDevice",
             HipStatus::InvalidDevice => "InvalidDevice",
+            HipStatus::InvalidImage => "InvalidImage",
+            HipStatus::UnsupportedLimit => "UnsupportedLimit",
             HipStatus::FileNotFound => "FileNotFound",
+            HipStatus::InvalidHandle => "InvalidHandle",
+            HipStatus::NotFound => "NotFound",
             HipStatus::NotReady => "NotReady",
+            HipStatus::PeerAccessAlreadyEnabled => "PeerAccessAlreadyEnabled",
+            HipStatus::PeerAccessNotEnabled => "PeerAccessNotEnabled",
             HipStatus::NotSupported => "NotSupported",
             HipStatus::Unknown => "Unknown",
         }
@@ -104,9 +139,15 @@ mod tests {
         assert_eq!(HipStatus::from(2), HipStatus::MemoryAllocation);
         assert_eq!(HipStatus::from(3), HipStatus::NotInitialized);
         assert_eq!(HipStatus::from(4), HipStatus::Deinitialized);
+        assert_eq!(HipStatus::from(100), HipStatus::NoDevice);
         assert_eq!(HipStatus::from(101), HipStatus::InvalidDevice);
+        assert_eq!(HipStatus::from(200), HipStatus::InvalidImage);
+        assert_eq!(HipStatus::from(215), HipStatus::UnsupportedLimit);
         assert_eq!(HipStatus::from(301), HipStatus::FileNotFound);
+        assert_eq!(HipStatus::from(500), HipStatus::NotFound);
         assert_eq!(HipStatus::from(600), HipStatus::NotReady);
+        assert_eq!(HipStatus::from(704), HipStatus::PeerAccessAlreadyEnabled);
+        assert_eq!(HipStatus::from(705), HipStatus::PeerAccessNotEnabled);
         assert_eq!(HipStatus::from(801), HipStatus::NotSupported);
         assert_eq!(HipStatus::from(1000), HipStatus::Unknown);
     }
@@ -125,6 +166,16 @@ mod tests {
         assert_eq!(error.code, 1);
     }
 
+    #[test]
+    fn test_hip_error_message() {
+        let error = HipError::from_status(HipStatus::InvalidValue);
+        assert!(!error.message().is_empty());
+        assert_ne!(
+            error.message(),
+            HipError::from_status(HipStatus::Success).message()
+        );
+    }
+
     #[test]
     fn test_hip_error_status_code() {
         let error = HipError::new(0);
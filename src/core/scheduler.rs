@@ -0,0 +1,68 @@
+use super::flags::StreamFlags;
+use super::result::HipResult;
+use super::stream::{stream_priority_range, Stream};
+
+/// A pair of streams for overlapping latency-sensitive compute with background transfers.
+///
+/// The compute stream gets the highest priority the current device supports and
+/// the transfer stream the lowest, so kernels are scheduled ahead of pending copies.
+/// On devices without priority support both streams share the same priority.
+///
+/// # Examples
+/// ```
+/// use hip_rs::Scheduler;
+///
+/// let scheduler = Scheduler::new().unwrap();
+/// let compute = scheduler.compute_stream().get_priority().unwrap();
+/// let transfer = scheduler.transfer_stream().get_priority().unwrap();
+/// assert!(compute <= transfer);
+/// ```
+#[derive(Debug)]
+pub struct Scheduler {
+    compute: Stream,
+    transfer: Stream,
+}
+
+impl Scheduler {
+    /// Creates the compute and transfer streams on the current device.
+    ///
+    /// # Returns
+    /// * `Ok(Scheduler)` - The scheduler owning both streams
+    /// * `Err(HipError)` - If querying the priority range or creating a stream fails
+    pub fn new() -> HipResult<Self> {
+        let (least, greatest) = stream_priority_range()?;
+        Ok(Self {
+            compute: Stream::create_with_priority(StreamFlags::DEFAULT, greatest)?,
+            transfer: Stream::create_with_priority(StreamFlags::DEFAULT, least)?,
+        })
+    }
+
+    /// Returns the high priority stream for latency-sensitive compute.
+    pub fn compute_stream(&self) -> &Stream {
+        &self.compute
+    }
+
+    /// Returns the low priority stream for background transfers.
+    pub fn transfer_stream(&self) -> &Stream {
+        &self.transfer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheduler_priorities() {
+        let (least, greatest) = stream_priority_range().unwrap();
+        if least == greatest {
+            println!("Stream priorities not supported on this device, skipping test");
+            return;
+        }
+
+        let scheduler = Scheduler::new().unwrap();
+        let compute = scheduler.compute_stream().get_priority().unwrap();
+        let transfer = scheduler.transfer_stream().get_priority().unwrap();
+        assert!(compute < transfer);
+    }
+}
@@ -0,0 +1,76 @@
+use super::flags::DeviceMallocFlag;
+use super::memory::MemoryPointer;
+use super::result::HipResult;
+
+/// A 64-bit signal allocated with [`DeviceMallocFlag::SIGNAL_MEMORY`].
+///
+/// Signal memory is fine-grained and coherent between the host and the device,
+/// which makes it suitable for flags that one side polls while the other side
+/// writes them. Pass [`SignalMemory::pointer`] to [`crate::Stream::wait_value_64`]
+/// and [`crate::Stream::write_value_64`] to build producer-consumer handshakes
+/// between the host and streams.
+#[derive(Debug)]
+pub struct SignalMemory {
+    pointer: MemoryPointer<u64>,
+}
+
+impl SignalMemory {
+    /// Allocates a signal on the current device, initialized to zero.
+    ///
+    /// # Returns
+    /// * `Ok(SignalMemory)` - The allocated signal
+    /// * `Err(HipError)` - If the device does not support signal memory or the
+    ///   allocation failed
+    pub fn new() -> HipResult<Self> {
+        let signal = Self {
+            pointer: MemoryPointer::alloc_with_flag(1, DeviceMallocFlag::SIGNAL_MEMORY)?,
+        };
+        signal.write(0)?;
+        Ok(signal)
+    }
+
+    /// Sets the signal to `value`.
+    ///
+    /// The write is synchronous, a stream waiting on the signal observes it once
+    /// this call returns.
+    pub fn write(&self, value: u64) -> HipResult<()> {
+        self.pointer.copy_from_host(&[value])
+    }
+
+    /// Reads the current value of the signal.
+    pub fn read(&self) -> HipResult<u64> {
+        let mut value = [0u64];
+        self.pointer.copy_to_host(&mut value)?;
+        Ok(value[0])
+    }
+
+    /// Returns the memory backing the signal, for use with the stream wait and
+    /// write value operations.
+    pub fn pointer(&self) -> &MemoryPointer<u64> {
+        &self.pointer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Stream;
+
+    #[test]
+    fn test_signal_memory_write_read() {
+        let Ok(signal) = SignalMemory::new() else {
+            println!("Signal memory is not supported, skipping");
+            return;
+        };
+        assert_eq!(signal.read().unwrap(), 0);
+
+        signal.write(42).unwrap();
+        assert_eq!(signal.read().unwrap(), 42);
+
+        // A stream signals the host once its preceding work is done
+        let stream = Stream::create().unwrap();
+        stream.write_value_64(signal.pointer(), 7).unwrap();
+        stream.synchronize().unwrap();
+        assert_eq!(signal.read().unwrap(), 7);
+    }
+}
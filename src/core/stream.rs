@@ -2,18 +2,141 @@
 use super::result::{HipResult, HipStatus};
 use crate::result::ResultExt;
 use crate::sys;
+use crate::{MemoryPointer, StreamFlags};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Comparison used by [`Stream::wait_value_32`] and [`Stream::wait_value_64`].
+///
+/// The stream blocks until the comparison between the value in device memory
+/// and the provided value holds.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitValueCompare {
+    /// Wait until `*ptr >= value`
+    Geq = 0x0, // hipStreamWaitValueGte
+    /// Wait until `*ptr == value`
+    Eq = 0x1, // hipStreamWaitValueEq
+    /// Wait until `(*ptr & value) != 0`
+    And = 0x2, // hipStreamWaitValueAnd
+    /// Wait until `!(*ptr | value) != 0`
+    Nor = 0x3, // hipStreamWaitValueNor
+}
+
+impl From<WaitValueCompare> for u32 {
+    fn from(compare: WaitValueCompare) -> Self {
+        compare as u32
+    }
+}
+
+/// Which streams may access managed memory attached with [`Stream::attach_managed`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachFlag {
+    /// Accessible from any stream on any device
+    Global = 0x01, // hipMemAttachGlobal
+    /// Accessible from the host only, until attached otherwise
+    Host = 0x02, // hipMemAttachHost
+    /// Accessible from the attaching stream only
+    Single = 0x04, // hipMemAttachSingle
+}
+
+impl From<AttachFlag> for u32 {
+    fn from(flag: AttachFlag) -> Self {
+        flag as u32
+    }
+}
+
+/// Which stream a null stream handle resolves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultStreamMode {
+    /// The legacy null stream, which synchronizes with all blocking streams on the device
+    Legacy,
+    /// A per-thread default stream, which behaves like a regular stream created by each host thread
+    PerThread,
+}
+
+/// Handle value of the `hipStreamPerThread` sentinel from `hip_runtime_api.h`.
+///
+/// HIP defines it as a macro, so it does not appear in the generated bindings.
+const HIP_STREAM_PER_THREAD: usize = 0x2;
+
+/// Returns which stream a null stream handle resolves to.
+///
+/// HIP selects per-thread default streams at compile time, by mapping each API
+/// call to its `_spt` variant when `HIP_API_PER_THREAD_DEFAULT_STREAM` is defined.
+/// The bindings of this crate call the plain entry points, so the null stream is
+/// always the legacy null stream. Use [`Stream::per_thread_default()`] to target
+/// the per-thread default stream explicitly.
+///
+/// # Examples
+/// ```
+/// use hip_rs::{default_stream_mode, DefaultStreamMode};
+///
+/// assert_eq!(default_stream_mode(), DefaultStreamMode::Legacy);
+/// ```
+pub fn default_stream_mode() -> DefaultStreamMode {
+    DefaultStreamMode::Legacy
+}
+
+/// Returns the range of stream priorities supported by the current device.
+///
+/// Lower numbers are higher priorities, so `greatest` is numerically less than
+/// or equal to `least`. Devices without priority support report `(0, 0)`.
+///
+/// # Returns
+/// * `Ok((least, greatest))` - The lowest and the highest stream priority
+/// * `Err(HipError)` - If the query failed
+pub fn stream_priority_range() -> HipResult<(i32, i32)> {
+    let mut least = 0;
+    let mut greatest = 0;
+    unsafe {
+        let code = record_call!(sys::hipDeviceGetStreamPriorityRange(
+            &mut least,
+            &mut greatest
+        ));
+        ((least, greatest), code).to_result()
+    }
+}
 
 /// A handle to a HIP stream that executes commands in order.
 #[derive(Debug)]
 pub struct Stream {
     handle: sys::hipStream_t,
+    owned: bool,
+    callback_panicked: Arc<AtomicBool>,
+}
+
+/// A closure enqueued with [`Stream::add_callback`], together with the flag of
+/// the stream to set if it panics.
+struct HostCallback {
+    callback: Box<dyn FnOnce() + Send>,
+    panicked: Arc<AtomicBool>,
+}
+
+/// Runs a [`HostCallback`] from HIP's callback thread.
+///
+/// Unwinding out of an `extern "C"` function is undefined behavior, so a panic
+/// of the closure is caught here, logged and recorded on the stream instead.
+unsafe extern "C" fn run_host_callback(user_data: *mut std::ffi::c_void) {
+    let HostCallback { callback, panicked } = *Box::from_raw(user_data as *mut HostCallback);
+    if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback)) {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("non-string panic payload");
+        log::error!("Stream callback panicked: {}", message);
+        panicked.store(true, Ordering::SeqCst);
+    }
 }
 
 impl Stream {
     /// Creates a new asynchronous stream.
     ///
-    /// The stream is allocated on the heap and will remain allocated even if the handle goes out of scope.
-    /// To release the memory used by the stream, the application must call [`Stream::destroy()`].
+    /// The returned stream is owned and destroyed with `hipStreamDestroy` when it
+    /// is dropped. Borrowed streams, such as those from [`Stream::from_raw_borrowed`],
+    /// and the null stream are left alone on drop.
     ///
     /// # Returns
     /// * `Ok(Stream)` - A new asynchronous stream
@@ -28,16 +151,165 @@ impl Stream {
     pub fn create() -> HipResult<Self> {
         let mut stream: sys::hipStream_t = std::ptr::null_mut();
         unsafe {
-            let code = sys::hipStreamCreate(&mut stream);
-            (Self { handle: stream }, code).to_result()
+            let code = record_call!(sys::hipStreamCreate(&mut stream));
+            (
+                Self {
+                    handle: stream,
+                    owned: true,
+                    callback_panicked: Arc::default(),
+                },
+                code,
+            )
+                .to_result()
         }
     }
 
+    /// Creates a new asynchronous stream with the given flags.
+    ///
+    /// Streams made by [`Stream::create`] wait for work on the legacy null stream
+    /// and the other way around. Pass [`StreamFlags::NON_BLOCKING`] to let the
+    /// stream run concurrently with it.
+    ///
+    /// # Arguments
+    /// * `flags` - How the stream synchronizes with the null stream
+    ///
+    /// # Returns
+    /// * `Ok(Stream)` - A new asynchronous stream
+    /// * `Err(HipError)` - If stream creation fails
+    ///
+    /// # Examples
+    /// ```
+    /// use hip_rs::{Stream, StreamFlags};
+    ///
+    /// let stream = Stream::create_with_flags(StreamFlags::NON_BLOCKING).unwrap();
+    /// ```
+    pub fn create_with_flags(flags: StreamFlags) -> HipResult<Self> {
+        let mut stream: sys::hipStream_t = std::ptr::null_mut();
+        unsafe {
+            let code = record_call!(sys::hipStreamCreateWithFlags(&mut stream, flags.bits()));
+            (
+                Self {
+                    handle: stream,
+                    owned: true,
+                    callback_panicked: Arc::default(),
+                },
+                code,
+            )
+                .to_result()
+        }
+    }
+
+    /// Creates a new asynchronous stream with the given flags and priority.
+    ///
+    /// Work on higher priority streams is scheduled ahead of pending work on lower
+    /// priority streams. Priorities outside [`stream_priority_range`] are clamped
+    /// to the nearest supported value.
+    ///
+    /// # Arguments
+    /// * `flags` - How the stream synchronizes with the null stream
+    /// * `priority` - The stream priority, lower numbers are higher priorities
+    ///
+    /// # Returns
+    /// * `Ok(Stream)` - A new asynchronous stream
+    /// * `Err(HipError)` - If stream creation fails
+    ///
+    /// # Examples
+    /// ```
+    /// use hip_rs::{stream_priority_range, Stream, StreamFlags};
+    ///
+    /// let (_, greatest) = stream_priority_range().unwrap();
+    /// let stream = Stream::create_with_priority(StreamFlags::NON_BLOCKING, greatest).unwrap();
+    /// assert_eq!(stream.get_priority().unwrap(), greatest);
+    /// ```
+    pub fn create_with_priority(flags: StreamFlags, priority: i32) -> HipResult<Self> {
+        let mut stream: sys::hipStream_t = std::ptr::null_mut();
+        unsafe {
+            let code = record_call!(sys::hipStreamCreateWithPriority(
+                &mut stream,
+                flags.bits(),
+                priority
+            ));
+            (
+                Self {
+                    handle: stream,
+                    owned: true,
+                    callback_panicked: Arc::default(),
+                },
+                code,
+            )
+                .to_result()
+        }
+    }
+
+    /// Wraps an existing raw stream handle without taking ownership of it.
+    ///
+    /// This is intended for FFI interop, where the stream was created and is
+    /// managed by other code. The returned `Stream` does not destroy the stream
+    /// when dropped.
+    ///
+    /// # Safety
+    /// The caller must ensure that `handle` is a valid stream (or the null stream)
+    /// and that it outlives the returned `Stream`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hip_rs::Stream;
+    ///
+    /// let owner = Stream::create().unwrap();
+    /// let borrowed = unsafe { Stream::from_raw_borrowed(owner.handle()) };
+    /// assert!(!borrowed.is_owned());
+    /// ```
+    pub unsafe fn from_raw_borrowed(handle: sys::hipStream_t) -> Self {
+        Self {
+            handle,
+            owned: false,
+            callback_panicked: Arc::default(),
+        }
+    }
+
+    /// Returns the null stream, which resolves according to [`default_stream_mode()`].
+    ///
+    /// The returned `Stream` is borrowed and is never destroyed.
+    pub fn null() -> Self {
+        Self {
+            handle: std::ptr::null_mut(),
+            owned: false,
+            callback_panicked: Arc::default(),
+        }
+    }
+
+    /// Returns the per-thread default stream of the calling host thread.
+    ///
+    /// Unlike the legacy null stream, this stream does not synchronize with
+    /// other streams on the device. The returned `Stream` is borrowed and is
+    /// never destroyed.
+    pub fn per_thread_default() -> Self {
+        Self {
+            handle: HIP_STREAM_PER_THREAD as sys::hipStream_t,
+            owned: false,
+            callback_panicked: Arc::default(),
+        }
+    }
+
+    /// Returns `true` if the stream is destroyed when this `Stream` is dropped.
+    pub fn is_owned(&self) -> bool {
+        self.owned
+    }
+
     /// Returns the raw stream handle.
     pub fn handle(&self) -> sys::hipStream_t {
         self.handle
     }
 
+    /// Gets the priority of the stream, lower numbers are higher priorities.
+    pub fn get_priority(&self) -> HipResult<i32> {
+        let mut priority = 0;
+        unsafe {
+            let code = record_call!(sys::hipStreamGetPriority(self.handle, &mut priority));
+            (priority, code).to_result()
+        }
+    }
+
     /// Queries the completion status of all operations in the stream.
     ///
     /// This function provides a snapshot of the current state of the stream. It checks if all
@@ -54,7 +326,197 @@ impl Stream {
     ///   - `HipErrorKind::InvalidHandle` if the stream handle is invalid
     pub fn query_stream(&self) -> HipResult<()> {
         unsafe {
-            let code = sys::hipStreamQuery(self.handle);
+            let code = record_call!(sys::hipStreamQuery(self.handle));
+            ((), code).to_result()
+        }
+    }
+
+    /// Blocks the host until all work submitted to this stream has completed.
+    ///
+    /// Unlike [`crate::synchronize`], this only waits for this stream, so work
+    /// on other streams keeps running.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Once the stream is idle
+    /// * `Err(HipError)` - If the stream handle is invalid or earlier work failed
+    pub fn synchronize(&self) -> HipResult<()> {
+        unsafe {
+            let code = record_call!(sys::hipStreamSynchronize(self.handle));
+            ((), code).to_result()
+        }
+    }
+
+    /// Enqueues a host closure that runs once all previously submitted work has completed.
+    ///
+    /// Work submitted to the stream after the callback waits for it to return.
+    /// The closure runs on a runtime thread and must not call HIP functions.
+    ///
+    /// A panic in the closure must not unwind into the HIP runtime, so it is
+    /// caught and logged, and [`Stream::callback_panicked`] reports it afterwards.
+    ///
+    /// # Arguments
+    /// * `callback` - The closure to run
+    ///
+    /// # Returns
+    /// * `Ok(())` - The callback was enqueued
+    /// * `Err(HipError)` - If enqueuing the callback fails
+    pub fn add_callback<F>(&self, callback: F) -> HipResult<()>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let user_data = Box::into_raw(Box::new(HostCallback {
+            callback: Box::new(callback),
+            panicked: Arc::clone(&self.callback_panicked),
+        }));
+        unsafe {
+            let code = record_call!(sys::hipLaunchHostFunc(
+                self.handle,
+                Some(run_host_callback),
+                user_data as *mut std::ffi::c_void,
+            ));
+            if code != 0 {
+                // The runtime never calls the callback, so reclaim it here
+                drop(Box::from_raw(user_data));
+            }
+            ((), code).to_result()
+        }
+    }
+
+    /// Returns `true` if a closure enqueued with [`Stream::add_callback`] on this
+    /// `Stream` has panicked.
+    ///
+    /// Only completed callbacks are taken into account, so synchronize the
+    /// stream first.
+    pub fn callback_panicked(&self) -> bool {
+        self.callback_panicked.load(Ordering::SeqCst)
+    }
+
+    /// Attaches a managed allocation to this stream.
+    ///
+    /// Managed memory is accessible from every stream by default, so the runtime
+    /// has to serialize access from concurrent streams. Attaching an allocation
+    /// with [`AttachFlag::Single`] limits it to this stream, which lets work on
+    /// other streams run concurrently. The attachment takes effect once the
+    /// work already submitted to the stream has completed.
+    ///
+    /// # Arguments
+    /// * `ptr` - Managed memory allocated with [`MemoryPointer::alloc_managed`]
+    /// * `flags` - The [`AttachFlag`] selecting which streams may access the memory
+    ///
+    /// # Returns
+    /// * `Ok(())` - The attachment was enqueued
+    /// * `Err(HipError)` - If the memory is not managed or the attachment fails
+    pub fn attach_managed<T>(&self, ptr: &MemoryPointer<T>, flags: AttachFlag) -> HipResult<()> {
+        unsafe {
+            // A length of zero attaches the whole allocation
+            let code = record_call!(sys::hipStreamAttachMemAsync(
+                self.handle,
+                ptr.as_pointer() as *mut std::ffi::c_void,
+                0,
+                flags.into(),
+            ));
+            ((), code).to_result()
+        }
+    }
+
+    /// Enqueues a wait on a 32-bit value in device memory.
+    ///
+    /// All work submitted to the stream after this call is held back until the
+    /// value at `ptr` satisfies `compare` against `value`. Together with
+    /// [`Stream::write_value_32`] this allows lightweight flag based
+    /// synchronization between streams without events.
+    ///
+    /// Only devices reporting [`crate::DeviceAttribute::CanUseStreamWaitValue`]
+    /// support this operation.
+    ///
+    /// # Arguments
+    /// * `ptr` - Device memory holding the value to wait on
+    /// * `value` - The value to compare against
+    /// * `compare` - The [`WaitValueCompare`] to apply
+    ///
+    /// # Returns
+    /// * `Ok(())` - The wait was enqueued
+    /// * `Err(HipError)` - If enqueuing the wait fails
+    ///
+    /// # Errors
+    /// Returns `HipError` if:
+    /// * The pointer is null
+    /// * The device does not support stream wait values
+    pub fn wait_value_32(
+        &self,
+        ptr: &MemoryPointer<u32>,
+        value: u32,
+        compare: WaitValueCompare,
+    ) -> HipResult<()> {
+        unsafe {
+            let code = record_call!(sys::hipStreamWaitValue32(
+                self.handle,
+                ptr.as_pointer() as *mut std::ffi::c_void,
+                value,
+                compare.into(),
+                u32::MAX,
+            ));
+            ((), code).to_result()
+        }
+    }
+
+    /// Enqueues a wait on a 64-bit value in device memory.
+    ///
+    /// See [`Stream::wait_value_32`] for details.
+    pub fn wait_value_64(
+        &self,
+        ptr: &MemoryPointer<u64>,
+        value: u64,
+        compare: WaitValueCompare,
+    ) -> HipResult<()> {
+        unsafe {
+            let code = record_call!(sys::hipStreamWaitValue64(
+                self.handle,
+                ptr.as_pointer() as *mut std::ffi::c_void,
+                value,
+                compare.into(),
+                u64::MAX,
+            ));
+            ((), code).to_result()
+        }
+    }
+
+    /// Enqueues a write of a 32-bit value to device memory.
+    ///
+    /// The write happens once all previously submitted work in the stream has
+    /// completed, which makes it suitable to signal a stream blocked in
+    /// [`Stream::wait_value_32`].
+    ///
+    /// # Arguments
+    /// * `ptr` - Device memory to write to
+    /// * `value` - The value to write
+    ///
+    /// # Returns
+    /// * `Ok(())` - The write was enqueued
+    /// * `Err(HipError)` - If enqueuing the write fails
+    pub fn write_value_32(&self, ptr: &MemoryPointer<u32>, value: u32) -> HipResult<()> {
+        unsafe {
+            let code = record_call!(sys::hipStreamWriteValue32(
+                self.handle,
+                ptr.as_pointer() as *mut std::ffi::c_void,
+                value,
+                0,
+            ));
+            ((), code).to_result()
+        }
+    }
+
+    /// Enqueues a write of a 64-bit value to device memory.
+    ///
+    /// See [`Stream::write_value_32`] for details.
+    pub fn write_value_64(&self, ptr: &MemoryPointer<u64>, value: u64) -> HipResult<()> {
+        unsafe {
+            let code = record_call!(sys::hipStreamWriteValue64(
+                self.handle,
+                ptr.as_pointer() as *mut std::ffi::c_void,
+                value,
+                0,
+            ));
             ((), code).to_result()
         }
     }
@@ -62,9 +524,10 @@ impl Stream {
 
 impl Drop for Stream {
     fn drop(&mut self) {
-        if !self.handle.is_null() {
+        // Borrowed streams are destroyed by their owner
+        if self.owned && !self.handle.is_null() {
             unsafe {
-                let code = sys::hipStreamDestroy(self.handle);
+                let code = record_call!(sys::hipStreamDestroy(self.handle));
                 if code != 0 {
                     log::error!("Failed to destroy HIP stream: {}", code);
                 }
@@ -86,6 +549,16 @@ mod tests {
         assert!(result.is_ok(), "Empty stream should report as complete");
     }
 
+    #[test]
+    fn test_stream_synchronize() {
+        let stream = Stream::create().unwrap();
+        let ptr = MemoryPointer::<f32>::alloc_async(1024, &stream).unwrap();
+
+        stream.synchronize().unwrap();
+        assert!(stream.query_stream().is_ok());
+        assert!(!ptr.as_pointer().is_null());
+    }
+
     #[test]
     fn test_stream_create() {
         let stream = Stream::create();
@@ -94,9 +567,187 @@ mod tests {
         assert!(!stream.handle().is_null());
     }
 
+    #[test]
+    fn test_stream_create_with_flags() {
+        let stream = Stream::create_with_flags(StreamFlags::NON_BLOCKING).unwrap();
+        assert!(!stream.handle().is_null());
+        assert!(stream.is_owned());
+        assert!(stream.query_stream().is_ok());
+    }
+
+    #[test]
+    fn test_stream_create_with_priority() {
+        let (least, greatest) = stream_priority_range().unwrap();
+        assert!(greatest <= least);
+
+        let stream = Stream::create_with_priority(StreamFlags::DEFAULT, greatest).unwrap();
+        assert_eq!(stream.get_priority().unwrap(), greatest);
+
+        // Out of range priorities are clamped
+        let stream = Stream::create_with_priority(StreamFlags::DEFAULT, least + 1).unwrap();
+        assert_eq!(stream.get_priority().unwrap(), least);
+    }
+
+    #[test]
+    fn test_stream_null() {
+        let stream = Stream::null();
+        assert!(stream.handle().is_null());
+
+        let ptr = MemoryPointer::<f32>::alloc_async(16, &stream).unwrap();
+        stream.synchronize().unwrap();
+        drop(ptr);
+
+        // Dropping the null stream must not destroy it
+        drop(stream);
+        Stream::null().synchronize().unwrap();
+    }
+
+    #[test]
+    fn test_default_stream_mode() {
+        let null = Stream::null();
+        let per_thread = Stream::per_thread_default();
+        assert!(!null.is_owned());
+        assert!(!per_thread.is_owned());
+
+        // The null handle only means the per-thread stream in per-thread mode
+        match default_stream_mode() {
+            DefaultStreamMode::Legacy => assert_ne!(null.handle(), per_thread.handle()),
+            DefaultStreamMode::PerThread => assert!(null.handle().is_null()),
+        }
+        assert!(null.handle().is_null());
+        assert!(!per_thread.handle().is_null());
+
+        null.synchronize().unwrap();
+        per_thread.synchronize().unwrap();
+    }
+
     #[test]
     fn test_stream_drop() {
         let stream = Stream::create().unwrap();
         drop(stream); // Stream should be properly destroyed here
     }
+
+    #[test]
+    fn test_stream_wait_value_32() {
+        let device = crate::get_device().unwrap();
+        let supported = device
+            .get_attribute(crate::DeviceAttribute::CanUseStreamWaitValue)
+            .unwrap();
+        if supported == 0 {
+            println!("Stream wait value not supported on this device, skipping test");
+            return;
+        }
+
+        // Signal memory is fine-grained, so the host sees the stream writes
+        // without a device-wide synchronization
+        let (Ok(flag), Ok(done)) = (
+            MemoryPointer::<u32>::alloc_with_flag(1, crate::DeviceMallocFlag::SIGNAL_MEMORY),
+            MemoryPointer::<u32>::alloc_with_flag(1, crate::DeviceMallocFlag::SIGNAL_MEMORY),
+        ) else {
+            println!("Signal memory is not supported, skipping test");
+            return;
+        };
+        flag.copy_from_host(&[0]).unwrap();
+        done.copy_from_host(&[0]).unwrap();
+
+        // Non-blocking streams, so host copies on the null stream do not wait
+        // for the blocked consumer
+        let producer = Stream::create_with_flags(StreamFlags::NON_BLOCKING).unwrap();
+        let consumer = Stream::create_with_flags(StreamFlags::NON_BLOCKING).unwrap();
+
+        // The wait is enqueued before the flag is raised, so the work behind
+        // it must be held back
+        consumer
+            .wait_value_32(&flag, 1, WaitValueCompare::Eq)
+            .unwrap();
+        consumer.write_value_32(&done, 1).unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        let result = consumer.query_stream();
+        assert_eq!(result.unwrap_err().status, HipStatus::NotReady);
+        let mut value = [0u32];
+        done.copy_to_host(&mut value).unwrap();
+        assert_eq!(value[0], 0);
+
+        // Raising the flag releases the consumer
+        producer.write_value_32(&flag, 1).unwrap();
+        consumer.synchronize().unwrap();
+        done.copy_to_host(&mut value).unwrap();
+        assert_eq!(value[0], 1);
+    }
+
+    #[test]
+    fn test_stream_attach_managed() {
+        let device = crate::get_device().unwrap();
+        let supported = device
+            .get_attribute(crate::DeviceAttribute::ManagedMemory)
+            .unwrap();
+        if supported == 0 {
+            println!("Managed memory not supported on this device, skipping test");
+            return;
+        }
+
+        let size = 256;
+        let ptr = MemoryPointer::<u32>::alloc_managed(size).unwrap();
+        let stream = Stream::create().unwrap();
+
+        stream.attach_managed(&ptr, AttachFlag::Single).unwrap();
+        ptr.memset_async(0xff, size * std::mem::size_of::<u32>(), &stream)
+            .unwrap();
+        stream.synchronize().unwrap();
+
+        // The stream is idle, so the host may read memory attached to it
+        let host = unsafe { std::slice::from_raw_parts(ptr.as_pointer(), size) };
+        assert!(host.iter().all(|&value| value == u32::MAX));
+    }
+
+    #[test]
+    fn test_stream_add_callback() {
+        let stream = Stream::create().unwrap();
+        let called = Arc::new(AtomicBool::new(false));
+
+        let flag = Arc::clone(&called);
+        stream
+            .add_callback(move || flag.store(true, Ordering::SeqCst))
+            .unwrap();
+        stream.synchronize().unwrap();
+
+        assert!(called.load(Ordering::SeqCst));
+        // The closure, and the clone of the flag it captured, has been dropped
+        assert_eq!(Arc::strong_count(&called), 1);
+    }
+
+    #[test]
+    fn test_stream_callback_panic() {
+        let stream = Stream::create().unwrap();
+        assert!(!stream.callback_panicked());
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        stream
+            .add_callback(|| panic!("deliberate panic in a stream callback"))
+            .unwrap();
+        stream
+            .add_callback(move || sender.send(42).unwrap())
+            .unwrap();
+        stream.synchronize().unwrap();
+
+        // The panic was contained, and the stream kept running callbacks
+        assert!(stream.callback_panicked());
+        assert_eq!(receiver.recv().unwrap(), 42);
+    }
+
+    #[test]
+    fn test_stream_from_raw_borrowed() {
+        let owner = Stream::create().unwrap();
+        assert!(owner.is_owned());
+
+        let borrowed = unsafe { Stream::from_raw_borrowed(owner.handle()) };
+        assert!(!borrowed.is_owned());
+        assert_eq!(borrowed.handle(), owner.handle());
+        assert!(borrowed.query_stream().is_ok());
+
+        // Dropping the borrowed stream must leave the original intact
+        drop(borrowed);
+        assert!(owner.query_stream().is_ok());
+    }
 }
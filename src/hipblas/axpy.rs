@@ -0,0 +1,254 @@
+use super::{check_device, check_vector_len, BlasError, BlasHandle, BlasResult, BlasStatus};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by AXPY operations
+pub trait AxpyDatatype {
+    /// Calls the appropriate HIPBLAS AXPY function for this datatype
+    ///
+    /// # Safety
+    /// `x` and `y` must be device pointers to at least `1 + (n - 1) * |inc|` elements.
+    unsafe fn hipblas_axpy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl AxpyDatatype for f32 {
+    unsafe fn hipblas_axpy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSaxpy(handle, n, alpha, x, incx, y, incy))
+    }
+}
+
+impl AxpyDatatype for f64 {
+    unsafe fn hipblas_axpy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDaxpy(handle, n, alpha, x, incx, y, incy))
+    }
+}
+
+impl AxpyDatatype for Complex32 {
+    unsafe fn hipblas_axpy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCaxpy(
+            handle,
+            n,
+            alpha as *const sys::hipblasComplex,
+            x as *const sys::hipblasComplex,
+            incx,
+            y as *mut sys::hipblasComplex,
+            incy,
+        ))
+    }
+}
+
+impl AxpyDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_axpy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZaxpy(handle, n, alpha, x, incx, y, incy))
+    }
+}
+
+/// Performs a vector update: y = alpha * x + y
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x and y
+/// * `alpha` - Scalar multiplier for x
+/// * `x` - Input vector x
+/// * `incx` - Stride between consecutive elements of x, must be non-zero
+/// * `y` - Input/output vector y
+/// * `incy` - Stride between consecutive elements of y, must be non-zero
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if a stride is zero, `x` or `y` is too short for `n` elements,
+///   or the operation failed
+pub fn axpy<T: AxpyDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    alpha: &T,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    y: &mut MemoryPointer<T>,
+    incy: i32,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+
+    check_vector_len("x", n, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+
+    if incx == 0 || incy == 0 {
+        log::error!("axpy: incx ({}) and incy ({}) must be non-zero", incx, incy);
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+
+    unsafe {
+        let code = T::hipblas_axpy(
+            handle.handle(),
+            n,
+            alpha,
+            x.as_pointer(),
+            incx,
+            y.as_pointer(),
+            incy,
+        );
+        ((), code).to_result()
+    }
+}
+
+/// Computes `y = alpha * x + y` on the device for host slices
+///
+/// A zero-setup entry point: creates a handle, uploads `x` and `y`, runs [`axpy`]
+/// and downloads the result back into `y`. Every call pays for the allocations
+/// and transfers, so keep data on the device with [`axpy`] for repeated work.
+///
+/// # Arguments
+/// * `alpha` - Scalar multiplier for x
+/// * `x` - Input vector x
+/// * `y` - Input/output vector y, of the same length as x
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if the lengths differ, a transfer failed or the operation failed
+///
+/// # Examples
+/// ```
+/// use hip_rs::axpy_host;
+///
+/// let x = [1.0, 2.0, 3.0];
+/// let mut y = [1.0, 1.0, 1.0];
+/// axpy_host(2.0, &x, &mut y).unwrap();
+/// assert_eq!(y, [3.0, 5.0, 7.0]);
+/// ```
+pub fn axpy_host(alpha: f32, x: &[f32], y: &mut [f32]) -> BlasResult<()> {
+    if x.len() != y.len() {
+        log::error!(
+            "axpy_host: x has {} elements, but y has {}",
+            x.len(),
+            y.len()
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    if x.is_empty() {
+        return Ok(());
+    }
+    let n = i32::try_from(x.len()).map_err(|_| {
+        log::error!("axpy_host: {} elements exceed i32::MAX", x.len());
+        BlasError::from_status(BlasStatus::InvalidValue)
+    })?;
+
+    let handle = BlasHandle::new()?;
+    let device_x = MemoryPointer::<f32>::alloc(x.len())?;
+    let mut device_y = MemoryPointer::<f32>::alloc(y.len())?;
+    device_x.copy_from_host(x)?;
+    device_y.copy_from_host(y)?;
+
+    axpy(&handle, n, &alpha, &device_x, 1, &mut device_y, 1)?;
+
+    device_y.copy_to_host(y)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saxpy() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut y = MemoryPointer::<f32>::alloc(4).unwrap();
+        x.copy_from_host(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        y.copy_from_host(&[10.0, 20.0, 30.0, 40.0]).unwrap();
+
+        axpy(&handle, 4, &2.0, &x, 1, &mut y, 1).unwrap();
+
+        let mut result = [0.0f32; 4];
+        y.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [12.0, 24.0, 36.0, 48.0]);
+    }
+
+    #[test]
+    fn test_caxpy() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<Complex32>::alloc(2).unwrap();
+        let mut y = MemoryPointer::<Complex32>::alloc(2).unwrap();
+        x.copy_from_host(&[Complex32::new(1.0, 1.0), Complex32::new(2.0, -1.0)])
+            .unwrap();
+        y.copy_from_host(&[Complex32::new(1.0, 0.0), Complex32::new(0.0, 1.0)])
+            .unwrap();
+
+        // alpha = i
+        let alpha = Complex32::new(0.0, 1.0);
+        axpy(&handle, 2, &alpha, &x, 1, &mut y, 1).unwrap();
+
+        let mut result = [Complex32::default(); 2];
+        y.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [Complex32::new(0.0, 1.0), Complex32::new(1.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_axpy_host() {
+        let x: Vec<f32> = (0..16).map(|i| i as f32 * 0.5).collect();
+        let mut y: Vec<f32> = (0..16).map(|i| 10.0 - i as f32).collect();
+
+        let expected: Vec<f32> = x.iter().zip(&y).map(|(x, y)| 2.0 * x + y).collect();
+        axpy_host(2.0, &x, &mut y).unwrap();
+        assert_eq!(y, expected);
+
+        axpy_host(2.0, &[], &mut []).unwrap();
+
+        let result = axpy_host(2.0, &x, &mut y[..8]);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+
+    #[test]
+    fn test_axpy_zero_increment() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut y = MemoryPointer::<f32>::alloc(4).unwrap();
+
+        let result = axpy(&handle, 4, &1.0, &x, 0, &mut y, 1);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        let result = axpy(&handle, 4, &1.0, &x, 1, &mut y, 0);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
@@ -37,6 +37,7 @@ use {
 macro_rules! blas_call {
     ($call:expr) => {{
         let code: u32 = unsafe { $call };
+        $crate::__record_call(stringify!($call), code == 0);
         let result: BlasResult<()> = ((), code).to_result();
         result
     }};
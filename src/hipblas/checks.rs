@@ -0,0 +1,91 @@
+use super::{BlasError, BlasResult, BlasStatus};
+use crate::MemoryPointer;
+
+/// Checks that `vector` holds `n` elements spaced `inc` apart.
+pub(crate) fn check_vector_len<T>(
+    name: &str,
+    n: i32,
+    vector: &MemoryPointer<T>,
+    inc: i32,
+) -> BlasResult<()> {
+    if n <= 0 {
+        return Ok(());
+    }
+
+    let required = (n as u64 - 1) * inc.unsigned_abs() as u64 + 1;
+    if required > vector.size() as u64 {
+        log::error!(
+            "{} holds {} elements, but {} with increment {} needs {}",
+            name,
+            vector.size(),
+            n,
+            inc,
+            required
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    Ok(())
+}
+
+/// Checks that `matrix` holds the `ld * cols` elements of a column-major
+/// `rows x cols` matrix.
+pub(crate) fn check_matrix_len<T>(
+    name: &str,
+    rows: i32,
+    cols: i32,
+    matrix: &MemoryPointer<T>,
+    ld: i32,
+) -> BlasResult<()> {
+    if rows <= 0 || cols <= 0 {
+        return Ok(());
+    }
+
+    let required = ld.max(0) as u64 * cols as u64;
+    if ld < rows || required > matrix.size() as u64 {
+        log::error!(
+            "{} holds {} elements, but a {}x{} matrix with leading dimension {} needs {}",
+            name,
+            matrix.size(),
+            rows,
+            cols,
+            ld,
+            required
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_vector_len() {
+        let x = MemoryPointer::<f32>::alloc(5).unwrap();
+
+        assert!(check_vector_len("x", 5, &x, 1).is_ok());
+        assert!(check_vector_len("x", 3, &x, 2).is_ok());
+        assert!(check_vector_len("x", 3, &x, -2).is_ok());
+        assert!(check_vector_len("x", 100, &x, 0).is_ok());
+        assert!(check_vector_len("x", 0, &x, 10).is_ok());
+
+        let result = check_vector_len("x", 6, &x, 1);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+        assert!(check_vector_len("x", 3, &x, 3).is_err());
+    }
+
+    #[test]
+    fn test_check_matrix_len() {
+        let a = MemoryPointer::<f32>::alloc(6).unwrap();
+
+        assert!(check_matrix_len("a", 2, 3, &a, 2).is_ok());
+        assert!(check_matrix_len("a", 3, 2, &a, 3).is_ok());
+        assert!(check_matrix_len("a", 0, 3, &a, 1).is_ok());
+
+        let result = check_matrix_len("a", 2, 3, &a, 3);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+        // The leading dimension is smaller than the number of rows
+        assert!(check_matrix_len("a", 3, 2, &a, 2).is_err());
+    }
+}
@@ -0,0 +1,272 @@
+use super::{check_device, check_vector_len, BlasHandle, BlasResult};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by COPY and SWAP operations
+pub trait CopyDatatype {
+    /// Calls the appropriate HIPBLAS COPY function for this datatype
+    ///
+    /// # Safety
+    /// `x` and `y` must be device pointers to at least `1 + (n - 1) * |inc|` elements.
+    unsafe fn hipblas_copy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t;
+
+    /// Calls the appropriate HIPBLAS SWAP function for this datatype
+    ///
+    /// # Safety
+    /// Same requirements as [`CopyDatatype::hipblas_copy`].
+    unsafe fn hipblas_swap(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl CopyDatatype for f32 {
+    unsafe fn hipblas_copy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasScopy(handle, n, x, incx, y, incy))
+    }
+
+    unsafe fn hipblas_swap(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSswap(handle, n, x, incx, y, incy))
+    }
+}
+
+impl CopyDatatype for f64 {
+    unsafe fn hipblas_copy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDcopy(handle, n, x, incx, y, incy))
+    }
+
+    unsafe fn hipblas_swap(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDswap(handle, n, x, incx, y, incy))
+    }
+}
+
+impl CopyDatatype for Complex32 {
+    unsafe fn hipblas_copy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCcopy(
+            handle,
+            n,
+            x as *const sys::hipblasComplex,
+            incx,
+            y as *mut sys::hipblasComplex,
+            incy,
+        ))
+    }
+
+    unsafe fn hipblas_swap(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCswap(
+            handle,
+            n,
+            x as *mut sys::hipblasComplex,
+            incx,
+            y as *mut sys::hipblasComplex,
+            incy,
+        ))
+    }
+}
+
+impl CopyDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_copy(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZcopy(handle, n, x, incx, y, incy))
+    }
+
+    unsafe fn hipblas_swap(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZswap(handle, n, x, incx, y, incy))
+    }
+}
+
+/// Copies a vector into another: y = x
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements to copy
+/// * `x` - Input vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Output vector y
+/// * `incy` - Stride between consecutive elements of y
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `x` or `y` is too small for `n` elements or the operation failed
+pub fn copy<T: CopyDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    y: &mut MemoryPointer<T>,
+    incy: i32,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+
+    check_vector_len("x", n, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+
+    unsafe {
+        let code = T::hipblas_copy(
+            handle.handle(),
+            n,
+            x.as_pointer(),
+            incx,
+            y.as_pointer(),
+            incy,
+        );
+        ((), code).to_result()
+    }
+}
+
+/// Exchanges the elements of two vectors: x <-> y
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements to exchange
+/// * `x` - Input/output vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Input/output vector y
+/// * `incy` - Stride between consecutive elements of y
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `x` or `y` is too small for `n` elements or the operation failed
+pub fn swap<T: CopyDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &mut MemoryPointer<T>,
+    incx: i32,
+    y: &mut MemoryPointer<T>,
+    incy: i32,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+
+    check_vector_len("x", n, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+
+    unsafe {
+        let code = T::hipblas_swap(
+            handle.handle(),
+            n,
+            x.as_pointer(),
+            incx,
+            y.as_pointer(),
+            incy,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlasStatus;
+
+    #[test]
+    fn test_scopy() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(3).unwrap();
+        let mut y = MemoryPointer::<f32>::alloc(3).unwrap();
+        x.copy_from_host(&[1.0, 2.0, 3.0]).unwrap();
+        y.copy_from_host(&[0.0; 3]).unwrap();
+
+        copy(&handle, 3, &x, 1, &mut y, 1).unwrap();
+
+        let mut result = [0.0f32; 3];
+        y.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_sswap() {
+        let handle = BlasHandle::new().unwrap();
+        let mut x = MemoryPointer::<f32>::alloc(3).unwrap();
+        let mut y = MemoryPointer::<f32>::alloc(3).unwrap();
+        x.copy_from_host(&[1.0, 2.0, 3.0]).unwrap();
+        y.copy_from_host(&[4.0, 5.0, 6.0]).unwrap();
+
+        swap(&handle, 3, &mut x, 1, &mut y, 1).unwrap();
+
+        let mut result = [0.0f32; 3];
+        x.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [4.0, 5.0, 6.0]);
+        y.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_copy_destination_too_small() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(6).unwrap();
+        let mut y = MemoryPointer::<f32>::alloc(4).unwrap();
+
+        // Three elements with an increment of 2 need 5 elements
+        let result = copy(&handle, 3, &x, 1, &mut y, 2);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
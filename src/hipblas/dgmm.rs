@@ -0,0 +1,220 @@
+use super::{check_device, check_matrix_len, check_vector_len, BlasHandle, BlasResult, SideMode};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by DGMM operations
+pub trait DgmmDatatype {
+    /// Calls the appropriate HIPBLAS DGMM function for this datatype
+    ///
+    /// # Safety
+    /// `a` and `c` must be device pointers to `lda x n` and `ldc x n` matrices, and
+    /// `x` a device pointer to `m` (left) or `n` (right) elements spaced `incx` apart.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_dgmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        m: i32,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl DgmmDatatype for f32 {
+    unsafe fn hipblas_dgmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        m: i32,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSdgmm(
+            handle, side, m, n, a, lda, x, incx, c, ldc
+        ))
+    }
+}
+
+impl DgmmDatatype for f64 {
+    unsafe fn hipblas_dgmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        m: i32,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDdgmm(
+            handle, side, m, n, a, lda, x, incx, c, ldc
+        ))
+    }
+}
+
+impl DgmmDatatype for Complex32 {
+    unsafe fn hipblas_dgmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        m: i32,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCdgmm(
+            handle,
+            side,
+            m,
+            n,
+            a as *const sys::hipblasComplex,
+            lda,
+            x as *const sys::hipblasComplex,
+            incx,
+            c as *mut sys::hipblasComplex,
+            ldc,
+        ))
+    }
+}
+
+impl DgmmDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_dgmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        m: i32,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZdgmm(
+            handle, side, m, n, a, lda, x, incx, c, ldc
+        ))
+    }
+}
+
+/// Multiplies a matrix with a diagonal matrix: C = diag(x) * A or C = A * diag(x)
+///
+/// With [`SideMode::Left`] row `i` of A is scaled by `x[i]`, with
+/// [`SideMode::Right`] column `j` of A is scaled by `x[j]`.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `side` - Whether the diagonal matrix is on the left or on the right of A
+/// * `m` - Number of rows in A and C
+/// * `n` - Number of columns in A and C
+/// * `a` - Input matrix A
+/// * `lda` - Leading dimension of A
+/// * `x` - Diagonal of the diagonal matrix, `m` elements for the left side and `n` for the right
+/// * `incx` - Stride between consecutive elements of x
+/// * `c` - Output matrix C
+/// * `ldc` - Leading dimension of C
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if a buffer is too short for the shape or operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn dgmm<T: DgmmDatatype>(
+    handle: &BlasHandle,
+    side: SideMode,
+    m: i32,
+    n: i32,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    c: &mut MemoryPointer<T>,
+    ldc: i32,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "x", x)?;
+    check_device(handle, "c", c)?;
+
+    let x_len = match side {
+        SideMode::Left => m,
+        SideMode::Right => n,
+    };
+    check_matrix_len("a", m, n, a, lda)?;
+    check_vector_len("x", x_len, x, incx)?;
+    check_matrix_len("c", m, n, c, ldc)?;
+
+    unsafe {
+        let code = T::hipblas_dgmm(
+            handle.handle(),
+            side.into(),
+            m,
+            n,
+            a.as_pointer(),
+            lda,
+            x.as_pointer(),
+            incx,
+            c.as_pointer(),
+            ldc,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sdgmm_scale_columns() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major A = [1 2]
+        //                  [3 4]
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let x = MemoryPointer::<f32>::alloc(2).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+        a.copy_from_host(&[1.0, 3.0, 2.0, 4.0]).unwrap();
+        x.copy_from_host(&[2.0, 3.0]).unwrap();
+
+        dgmm(&handle, SideMode::Right, 2, 2, &a, 2, &x, 1, &mut c, 2).unwrap();
+
+        // A * diag(2, 3) = [2  6]
+        //                  [6 12]
+        let mut result = [0.0f32; 4];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [2.0, 6.0, 6.0, 12.0]);
+    }
+
+    #[test]
+    fn test_ddgmm_scale_rows() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major A = [1 2]
+        //                  [3 4]
+        let a = MemoryPointer::<f64>::alloc(4).unwrap();
+        let x = MemoryPointer::<f64>::alloc(2).unwrap();
+        let mut c = MemoryPointer::<f64>::alloc(4).unwrap();
+        a.copy_from_host(&[1.0, 3.0, 2.0, 4.0]).unwrap();
+        x.copy_from_host(&[2.0, 3.0]).unwrap();
+
+        dgmm(&handle, SideMode::Left, 2, 2, &a, 2, &x, 1, &mut c, 2).unwrap();
+
+        // diag(2, 3) * A = [2  4]
+        //                  [9 12]
+        let mut result = [0.0f64; 4];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [2.0, 9.0, 4.0, 12.0]);
+    }
+}
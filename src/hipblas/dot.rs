@@ -0,0 +1,290 @@
+use super::{check_device, check_vector_len, BlasError, BlasHandle, BlasResult, BlasStatus};
+use crate::result::ResultExt;
+use crate::{sys, Complex32, MemoryPointer};
+
+/// Trait for real types supported by DOT operations
+pub trait DotDatatype: Default {
+    /// Calls the appropriate HIPBLAS DOT function for this datatype
+    ///
+    /// # Safety
+    /// `x` and `y` must be device pointers to at least `1 + (n - 1) * |inc|` elements,
+    /// and `result` must be valid for a write.
+    unsafe fn hipblas_dot(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl DotDatatype for f32 {
+    unsafe fn hipblas_dot(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSdot(handle, n, x, incx, y, incy, result))
+    }
+}
+
+impl DotDatatype for f64 {
+    unsafe fn hipblas_dot(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDdot(handle, n, x, incx, y, incy, result))
+    }
+}
+
+/// Computes the dot product of two real vectors: x · y
+///
+/// The result is returned to the host, so the call blocks until the stream of
+/// the handle has finished computing it.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x and y
+/// * `x` - Input vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Input vector y
+/// * `incy` - Stride between consecutive elements of y
+///
+/// # Returns
+/// * `Ok(T)` - The dot product, zero if `n` is zero
+/// * `Err(BlasError)` - If the handle is null, `x` or `y` is too short for `n`
+///   elements, or the operation failed
+pub fn dot<T: DotDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    y: &MemoryPointer<T>,
+    incy: i32,
+) -> BlasResult<T> {
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+
+    check_vector_len("x", n, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+
+    if handle.handle().is_null() {
+        return Err(BlasError::from_status(BlasStatus::NotInitialized));
+    }
+    if n == 0 {
+        return Ok(T::default());
+    }
+
+    let mut result = T::default();
+    unsafe {
+        let code = T::hipblas_dot(
+            handle.handle(),
+            n,
+            x.as_pointer(),
+            incx,
+            y.as_pointer(),
+            incy,
+            &mut result,
+        );
+        (result, code).to_result()
+    }
+}
+
+/// Trait for complex types supported by conjugated DOT operations
+pub trait DotcDatatype: Sized {
+    /// The additive identity, returned for empty vectors
+    const ZERO: Self;
+
+    /// Calls the appropriate HIPBLAS DOTC function for this datatype
+    ///
+    /// # Safety
+    /// `x` and `y` must be device pointers to at least `1 + (n - 1) * |inc|` elements,
+    /// and `result` must be valid for a write.
+    unsafe fn hipblas_dotc(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl DotcDatatype for Complex32 {
+    const ZERO: Self = Complex32::new(0.0, 0.0);
+
+    unsafe fn hipblas_dotc(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCdotc(
+            handle,
+            n,
+            x as *const sys::hipblasComplex,
+            incx,
+            y as *const sys::hipblasComplex,
+            incy,
+            result as *mut sys::hipblasComplex,
+        ))
+    }
+}
+
+impl DotcDatatype for sys::hipblasDoubleComplex {
+    const ZERO: Self = sys::hipblasDoubleComplex { x: 0.0, y: 0.0 };
+
+    unsafe fn hipblas_dotc(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZdotc(handle, n, x, incx, y, incy, result))
+    }
+}
+
+/// Computes the conjugated dot product of two complex vectors: conj(x) · y
+///
+/// This is the complex inner product, where each element of `x` is conjugated
+/// before it is multiplied with the matching element of `y`.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x and y
+/// * `x` - Input vector x, conjugated
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Input vector y
+/// * `incy` - Stride between consecutive elements of y
+///
+/// # Returns
+/// * `Ok(T)` - The dot product, zero if `n` is zero
+/// * `Err(BlasError)` - If the handle is null, `x` or `y` is too short for `n`
+///   elements, or the operation failed
+pub fn dotc<T: DotcDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    y: &MemoryPointer<T>,
+    incy: i32,
+) -> BlasResult<T> {
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+
+    check_vector_len("x", n, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+
+    if handle.handle().is_null() {
+        return Err(BlasError::from_status(BlasStatus::NotInitialized));
+    }
+    if n == 0 {
+        return Ok(T::ZERO);
+    }
+
+    let mut result = T::ZERO;
+    unsafe {
+        let code = T::hipblas_dotc(
+            handle.handle(),
+            n,
+            x.as_pointer(),
+            incx,
+            y.as_pointer(),
+            incy,
+            &mut result,
+        );
+        (result, code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sdot() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(3).unwrap();
+        let y = MemoryPointer::<f32>::alloc(3).unwrap();
+        x.copy_from_host(&[1.0, 2.0, 3.0]).unwrap();
+        y.copy_from_host(&[4.0, 5.0, 6.0]).unwrap();
+
+        assert_eq!(dot(&handle, 3, &x, 1, &y, 1).unwrap(), 32.0);
+    }
+
+    #[test]
+    fn test_ddot() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f64>::alloc(3).unwrap();
+        let y = MemoryPointer::<f64>::alloc(3).unwrap();
+        x.copy_from_host(&[1.0, 2.0, 3.0]).unwrap();
+        y.copy_from_host(&[4.0, 5.0, 6.0]).unwrap();
+
+        assert_eq!(dot(&handle, 3, &x, 1, &y, 1).unwrap(), 32.0);
+    }
+
+    #[test]
+    fn test_cdotc() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<Complex32>::alloc(1).unwrap();
+        let y = MemoryPointer::<Complex32>::alloc(1).unwrap();
+        x.copy_from_host(&[Complex32::new(1.0, 2.0)]).unwrap();
+        y.copy_from_host(&[Complex32::new(3.0, 4.0)]).unwrap();
+
+        // (1 - 2i)(3 + 4i) = 11 - 2i
+        let result = dotc(&handle, 1, &x, 1, &y, 1).unwrap();
+        assert_eq!(result, Complex32::new(11.0, -2.0));
+    }
+
+    #[test]
+    fn test_dot_empty() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(0).unwrap();
+        let y = MemoryPointer::<f32>::alloc(0).unwrap();
+
+        assert_eq!(dot(&handle, 0, &x, 1, &y, 1).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_dot_null_handle() {
+        let handle = unsafe { BlasHandle::from_raw_borrowed(std::ptr::null_mut()) }.unwrap();
+        let x = MemoryPointer::<f32>::alloc(3).unwrap();
+        let y = MemoryPointer::<f32>::alloc(3).unwrap();
+
+        let result = dot(&handle, 3, &x, 1, &y, 1);
+        assert_eq!(result.unwrap_err().status, BlasStatus::NotInitialized);
+    }
+
+    #[test]
+    fn test_dot_short_vector() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(5).unwrap();
+        let y = MemoryPointer::<f32>::alloc(4).unwrap();
+
+        let result = dot(&handle, 5, &x, 1, &y, 1);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        // Three elements two apart span five
+        let result = dot(&handle, 3, &x, 2, &y, 2);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+        assert!(dot(&handle, 3, &x, 2, &y, 1).is_ok());
+    }
+}
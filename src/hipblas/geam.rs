@@ -0,0 +1,324 @@
+use super::{check_device, check_matrix_len, BlasHandle, BlasResult, Operation};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by GEAM operations
+pub trait GeamDatatype: Sized {
+    /// The multiplicative identity
+    const ONE: Self;
+    /// The additive identity
+    const ZERO: Self;
+
+    /// Calls the appropriate HIPBLAS GEAM function for this datatype
+    ///
+    /// # Safety
+    /// `a`, `b` and `c` must be device pointers to matrices of the dimensions
+    /// implied by the operations, `m`, `n` and the leading dimensions.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_geam(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        beta: *const Self,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl GeamDatatype for f32 {
+    const ONE: Self = 1.0;
+    const ZERO: Self = 0.0;
+
+    unsafe fn hipblas_geam(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        beta: *const Self,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSgeam(
+            handle, trans_a, trans_b, m, n, alpha, a, lda, beta, b, ldb, c, ldc,
+        ))
+    }
+}
+
+impl GeamDatatype for f64 {
+    const ONE: Self = 1.0;
+    const ZERO: Self = 0.0;
+
+    unsafe fn hipblas_geam(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        beta: *const Self,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDgeam(
+            handle, trans_a, trans_b, m, n, alpha, a, lda, beta, b, ldb, c, ldc,
+        ))
+    }
+}
+
+impl GeamDatatype for Complex32 {
+    const ONE: Self = Complex32::new(1.0, 0.0);
+    const ZERO: Self = Complex32::new(0.0, 0.0);
+
+    unsafe fn hipblas_geam(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        beta: *const Self,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCgeam(
+            handle,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            alpha as *const sys::hipblasComplex,
+            a as *const sys::hipblasComplex,
+            lda,
+            beta as *const sys::hipblasComplex,
+            b as *const sys::hipblasComplex,
+            ldb,
+            c as *mut sys::hipblasComplex,
+            ldc,
+        ))
+    }
+}
+
+impl GeamDatatype for sys::hipblasDoubleComplex {
+    const ONE: Self = sys::hipblasDoubleComplex { x: 1.0, y: 0.0 };
+    const ZERO: Self = sys::hipblasDoubleComplex { x: 0.0, y: 0.0 };
+
+    unsafe fn hipblas_geam(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        beta: *const Self,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZgeam(
+            handle, trans_a, trans_b, m, n, alpha, a, lda, beta, b, ldb, c, ldc,
+        ))
+    }
+}
+
+/// Performs matrix-matrix addition: C = alpha * op(A) + beta * op(B)
+///
+/// With `beta` set to zero and `trans_a` set to [`Operation::Transpose`] this
+/// computes an out-of-place transpose of A.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `trans_a` - How to transform matrix A
+/// * `trans_b` - How to transform matrix B
+/// * `m` - Number of rows in op(A), op(B) and C
+/// * `n` - Number of columns in op(A), op(B) and C
+/// * `alpha` - Scalar multiplier for op(A)
+/// * `a` - Input matrix A
+/// * `lda` - Leading dimension of A
+/// * `beta` - Scalar multiplier for op(B)
+/// * `b` - Input matrix B
+/// * `ldb` - Leading dimension of B
+/// * `c` - Output matrix C
+/// * `ldc` - Leading dimension of C
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if a buffer is too short for the shape or operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn geam<T: GeamDatatype>(
+    handle: &BlasHandle,
+    trans_a: Operation,
+    trans_b: Operation,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    beta: &T,
+    b: &MemoryPointer<T>,
+    ldb: i32,
+    c: &mut MemoryPointer<T>,
+    ldc: i32,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "b", b)?;
+    check_device(handle, "c", c)?;
+
+    let stored = |trans: Operation| match trans {
+        Operation::None => (m, n),
+        Operation::Transpose | Operation::Conjugate => (n, m),
+    };
+    let ((a_rows, a_cols), (b_rows, b_cols)) = (stored(trans_a), stored(trans_b));
+    check_matrix_len("a", a_rows, a_cols, a, lda)?;
+    check_matrix_len("b", b_rows, b_cols, b, ldb)?;
+    check_matrix_len("c", m, n, c, ldc)?;
+
+    unsafe {
+        let code = T::hipblas_geam(
+            handle.handle(),
+            trans_a.into(),
+            trans_b.into(),
+            m,
+            n,
+            alpha,
+            a.as_pointer(),
+            lda,
+            beta,
+            b.as_pointer(),
+            ldb,
+            c.as_pointer(),
+            ldc,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlasStatus;
+
+    #[test]
+    fn test_sgeam() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major A = [1 3]  B = [1 0]
+        //                  [2 4]      [0 1]
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let b = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+        a.copy_from_host(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        b.copy_from_host(&[1.0, 0.0, 0.0, 1.0]).unwrap();
+
+        // C = 2 * A^T + 10 * B = [12  4]
+        //                        [ 6 18]
+        geam(
+            &handle,
+            Operation::Transpose,
+            Operation::None,
+            2,
+            2,
+            &2.0,
+            &a,
+            2,
+            &10.0,
+            &b,
+            2,
+            &mut c,
+            2,
+        )
+        .unwrap();
+
+        let mut result = [0.0f32; 4];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [12.0, 6.0, 4.0, 18.0]);
+    }
+
+    #[test]
+    fn test_sgeam_transpose_2x3() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major 2x3 A = [1 3 5]
+        //                      [2 4 6]
+        let a = MemoryPointer::<f32>::alloc(6).unwrap();
+        let b = MemoryPointer::<f32>::alloc(6).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(6).unwrap();
+        a.copy_from_host(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        b.copy_from_host(&[0.0; 6]).unwrap();
+
+        // C = A^T is 3x2, B only takes part with a zero weight
+        geam(
+            &handle,
+            Operation::Transpose,
+            Operation::None,
+            3,
+            2,
+            &1.0,
+            &a,
+            2,
+            &0.0,
+            &b,
+            3,
+            &mut c,
+            3,
+        )
+        .unwrap();
+
+        // Column-major C = [1 2]
+        //                  [3 4]
+        //                  [5 6]
+        let mut result = [0.0f32; 6];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_geam_short_matrix() {
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<f32>::alloc(6).unwrap();
+        let b = MemoryPointer::<f32>::alloc(6).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+
+        // C = A^T is 3x2, which does not fit in four elements
+        let result = geam(
+            &handle,
+            Operation::Transpose,
+            Operation::None,
+            3,
+            2,
+            &1.0,
+            &a,
+            2,
+            &0.0,
+            &b,
+            3,
+            &mut c,
+            3,
+        );
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
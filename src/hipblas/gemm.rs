@@ -1,10 +1,16 @@
-use super::{BlasHandle, BlasResult, Operation};
+use super::{
+    check_device, BlasError, BlasHandle, BlasResult, BlasStatus, ComputeType, DataType, GemmAlgo,
+    Operation,
+};
 use crate::result::ResultExt;
 use crate::Complex32;
-use crate::{sys, MemoryPointer};
+use crate::{sys, HipEvent, HipResult, MemoryPointer, Stream};
 
 /// Trait for types supported by GEMM operations
 pub trait GemmDatatype {
+    /// The multiplicative identity, used as `alpha` when no scaling is wanted
+    const ONE: Self;
+
     /// Calls the appropriate HIPBLAS GEMM function for this datatype
     unsafe fn hipblas_gemm(
         handle: sys::hipblasHandle_t,
@@ -41,10 +47,40 @@ pub trait GemmDatatype {
         ldc: i32,
         batch_count: i32,
     ) -> sys::hipblasStatus_t;
+
+    /// Calls the appropriate HIPBLAS GEMM Strided Batched function for this datatype
+    ///
+    /// # Safety
+    /// `a`, `b` and `c` must be device pointers to `batch_count` matrices spaced
+    /// `stride_a`, `stride_b` and `stride_c` elements apart.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_gemm_strided_batched(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        stride_a: i64,
+        b: *const Self,
+        ldb: i32,
+        stride_b: i64,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+        stride_c: i64,
+        batch_count: i32,
+    ) -> sys::hipblasStatus_t;
 }
 
 // u16
 impl GemmDatatype for sys::hipblasHalf {
+    // 1.0 in IEEE 754 half precision
+    const ONE: Self = 0x3C00;
+
     unsafe fn hipblas_gemm(
         handle: sys::hipblasHandle_t,
         trans_a: sys::hipblasOperation_t,
@@ -61,9 +97,9 @@ impl GemmDatatype for sys::hipblasHalf {
         c: *mut Self,
         ldc: i32,
     ) -> sys::hipblasStatus_t {
-        sys::hipblasHgemm(
+        record_call!(sys::hipblasHgemm(
             handle, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
-        )
+        ))
     }
 
     unsafe fn hipblas_gemm_batched(
@@ -83,7 +119,46 @@ impl GemmDatatype for sys::hipblasHalf {
         ldc: i32,
         batch_count: i32,
     ) -> sys::hipblasStatus_t {
-        sys::hipblasHgemmBatched(
+        record_call!(sys::hipblasHgemmBatched(
+            handle,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            c,
+            ldc,
+            batch_count,
+        ))
+    }
+
+    unsafe fn hipblas_gemm_strided_batched(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        stride_a: i64,
+        b: *const Self,
+        ldb: i32,
+        stride_b: i64,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+        stride_c: i64,
+        batch_count: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasHgemmStridedBatched(
             handle,
             trans_a,
             trans_b,
@@ -93,17 +168,140 @@ impl GemmDatatype for sys::hipblasHalf {
             alpha,
             a,
             lda,
+            stride_a,
             b,
             ldb,
+            stride_b,
             beta,
             c,
             ldc,
+            stride_c,
+            batch_count,
+        ))
+    }
+}
+
+// Same bits as hipblasHalf, so the calls forward to its implementation
+#[cfg(feature = "half")]
+impl GemmDatatype for half::f16 {
+    const ONE: Self = half::f16::ONE;
+
+    unsafe fn hipblas_gemm(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        sys::hipblasHalf::hipblas_gemm(
+            handle,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha as *const sys::hipblasHalf,
+            a as *const sys::hipblasHalf,
+            lda,
+            b as *const sys::hipblasHalf,
+            ldb,
+            beta as *const sys::hipblasHalf,
+            c as *mut sys::hipblasHalf,
+            ldc,
+        )
+    }
+
+    unsafe fn hipblas_gemm_batched(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const Self,
+        a: *const *const Self,
+        lda: i32,
+        b: *const *const Self,
+        ldb: i32,
+        beta: *const Self,
+        c: *mut *mut Self,
+        ldc: i32,
+        batch_count: i32,
+    ) -> sys::hipblasStatus_t {
+        sys::hipblasHalf::hipblas_gemm_batched(
+            handle,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha as *const sys::hipblasHalf,
+            a as *const *const sys::hipblasHalf,
+            lda,
+            b as *const *const sys::hipblasHalf,
+            ldb,
+            beta as *const sys::hipblasHalf,
+            c as *mut *mut sys::hipblasHalf,
+            ldc,
+            batch_count,
+        )
+    }
+
+    unsafe fn hipblas_gemm_strided_batched(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        stride_a: i64,
+        b: *const Self,
+        ldb: i32,
+        stride_b: i64,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+        stride_c: i64,
+        batch_count: i32,
+    ) -> sys::hipblasStatus_t {
+        sys::hipblasHalf::hipblas_gemm_strided_batched(
+            handle,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha as *const sys::hipblasHalf,
+            a as *const sys::hipblasHalf,
+            lda,
+            stride_a,
+            b as *const sys::hipblasHalf,
+            ldb,
+            stride_b,
+            beta as *const sys::hipblasHalf,
+            c as *mut sys::hipblasHalf,
+            ldc,
+            stride_c,
             batch_count,
         )
     }
 }
 
 impl GemmDatatype for f32 {
+    const ONE: Self = 1.0;
+
     unsafe fn hipblas_gemm(
         handle: sys::hipblasHandle_t,
         trans_a: sys::hipblasOperation_t,
@@ -120,9 +318,9 @@ impl GemmDatatype for f32 {
         c: *mut Self,
         ldc: i32,
     ) -> sys::hipblasStatus_t {
-        sys::hipblasSgemm(
+        record_call!(sys::hipblasSgemm(
             handle, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
-        )
+        ))
     }
 
     unsafe fn hipblas_gemm_batched(
@@ -142,7 +340,7 @@ impl GemmDatatype for f32 {
         ldc: i32,
         batch_count: i32,
     ) -> sys::hipblasStatus_t {
-        sys::hipblasSgemmBatched(
+        record_call!(sys::hipblasSgemmBatched(
             handle,
             trans_a,
             trans_b,
@@ -158,11 +356,55 @@ impl GemmDatatype for f32 {
             c,
             ldc,
             batch_count,
-        )
+        ))
+    }
+
+    unsafe fn hipblas_gemm_strided_batched(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        stride_a: i64,
+        b: *const Self,
+        ldb: i32,
+        stride_b: i64,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+        stride_c: i64,
+        batch_count: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSgemmStridedBatched(
+            handle,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            stride_a,
+            b,
+            ldb,
+            stride_b,
+            beta,
+            c,
+            ldc,
+            stride_c,
+            batch_count,
+        ))
     }
 }
 
 impl GemmDatatype for f64 {
+    const ONE: Self = 1.0;
+
     unsafe fn hipblas_gemm(
         handle: sys::hipblasHandle_t,
         trans_a: sys::hipblasOperation_t,
@@ -179,9 +421,9 @@ impl GemmDatatype for f64 {
         c: *mut Self,
         ldc: i32,
     ) -> sys::hipblasStatus_t {
-        sys::hipblasDgemm(
+        record_call!(sys::hipblasDgemm(
             handle, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
-        )
+        ))
     }
 
     unsafe fn hipblas_gemm_batched(
@@ -201,7 +443,7 @@ impl GemmDatatype for f64 {
         ldc: i32,
         batch_count: i32,
     ) -> sys::hipblasStatus_t {
-        sys::hipblasDgemmBatched(
+        record_call!(sys::hipblasDgemmBatched(
             handle,
             trans_a,
             trans_b,
@@ -217,11 +459,55 @@ impl GemmDatatype for f64 {
             c,
             ldc,
             batch_count,
-        )
+        ))
+    }
+
+    unsafe fn hipblas_gemm_strided_batched(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        stride_a: i64,
+        b: *const Self,
+        ldb: i32,
+        stride_b: i64,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+        stride_c: i64,
+        batch_count: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDgemmStridedBatched(
+            handle,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            stride_a,
+            b,
+            ldb,
+            stride_b,
+            beta,
+            c,
+            ldc,
+            stride_c,
+            batch_count,
+        ))
     }
 }
 
 impl GemmDatatype for Complex32 {
+    const ONE: Self = Complex32::new(1.0, 0.0);
+
     unsafe fn hipblas_gemm(
         handle: sys::hipblasHandle_t,
         trans_a: sys::hipblasOperation_t,
@@ -245,10 +531,10 @@ impl GemmDatatype for Complex32 {
         let beta_ptr = beta as *const sys::hipblasComplex;
         let c_ptr = c as *mut sys::hipblasComplex;
 
-        sys::hipblasCgemm(
+        record_call!(sys::hipblasCgemm(
             handle, trans_a, trans_b, m, n, k, alpha_ptr, a_ptr, lda, b_ptr, ldb, beta_ptr, c_ptr,
             ldc,
-        )
+        ))
     }
 
     unsafe fn hipblas_gemm_batched(
@@ -268,7 +554,7 @@ impl GemmDatatype for Complex32 {
         ldc: i32,
         batch_count: i32,
     ) -> sys::hipblasStatus_t {
-        sys::hipblasCgemmBatched(
+        record_call!(sys::hipblasCgemmBatched(
             handle,
             trans_a,
             trans_b,
@@ -284,11 +570,55 @@ impl GemmDatatype for Complex32 {
             c as *mut *mut sys::hipblasComplex,
             ldc,
             batch_count,
-        )
+        ))
+    }
+
+    unsafe fn hipblas_gemm_strided_batched(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        stride_a: i64,
+        b: *const Self,
+        ldb: i32,
+        stride_b: i64,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+        stride_c: i64,
+        batch_count: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCgemmStridedBatched(
+            handle,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha as *const sys::hipblasComplex,
+            a as *const sys::hipblasComplex,
+            lda,
+            stride_a,
+            b as *const sys::hipblasComplex,
+            ldb,
+            stride_b,
+            beta as *const sys::hipblasComplex,
+            c as *mut sys::hipblasComplex,
+            ldc,
+            stride_c,
+            batch_count,
+        ))
     }
 }
 
 impl GemmDatatype for sys::hipblasDoubleComplex {
+    const ONE: Self = sys::hipblasDoubleComplex { x: 1.0, y: 0.0 };
+
     unsafe fn hipblas_gemm(
         handle: sys::hipblasHandle_t,
         trans_a: sys::hipblasOperation_t,
@@ -305,9 +635,9 @@ impl GemmDatatype for sys::hipblasDoubleComplex {
         c: *mut Self,
         ldc: i32,
     ) -> sys::hipblasStatus_t {
-        sys::hipblasZgemm(
+        record_call!(sys::hipblasZgemm(
             handle, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
-        )
+        ))
     }
 
     unsafe fn hipblas_gemm_batched(
@@ -327,7 +657,7 @@ impl GemmDatatype for sys::hipblasDoubleComplex {
         ldc: i32,
         batch_count: i32,
     ) -> sys::hipblasStatus_t {
-        sys::hipblasZgemmBatched(
+        record_call!(sys::hipblasZgemmBatched(
             handle,
             trans_a,
             trans_b,
@@ -343,16 +673,295 @@ impl GemmDatatype for sys::hipblasDoubleComplex {
             c,
             ldc,
             batch_count,
-        )
+        ))
     }
-}
 
-/// Performs matrix-matrix multiplication: C = alpha * op(A) * op(B) + beta * C
-///
-/// # Arguments
-/// * `handle` - HIPBLAS library handle
-/// * `trans_a` - How to transform matrix A
-/// * `trans_b` - How to transform matrix B
+    unsafe fn hipblas_gemm_strided_batched(
+        handle: sys::hipblasHandle_t,
+        trans_a: sys::hipblasOperation_t,
+        trans_b: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        stride_a: i64,
+        b: *const Self,
+        ldb: i32,
+        stride_b: i64,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+        stride_c: i64,
+        batch_count: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZgemmStridedBatched(
+            handle,
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            alpha,
+            a,
+            lda,
+            stride_a,
+            b,
+            ldb,
+            stride_b,
+            beta,
+            c,
+            ldc,
+            stride_c,
+            batch_count,
+        ))
+    }
+}
+
+/// Computes the number of elements of `T` needed to store a column-major matrix.
+///
+/// The size in bytes must not exceed `isize::MAX`, the limit of any allocation.
+///
+/// # Arguments
+/// * `rows` - Number of rows of the stored matrix
+/// * `cols` - Number of columns of the stored matrix
+/// * `ld` - Leading dimension, must be at least `max(1, rows)`
+fn column_major_len<T>(rows: i32, cols: i32, ld: i32) -> BlasResult<usize> {
+    if rows < 0 || cols < 0 || ld < rows.max(1) {
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+
+    (ld as usize)
+        .checked_mul(cols as usize)
+        .filter(|len| {
+            len.checked_mul(std::mem::size_of::<T>())
+                .is_some_and(|bytes| bytes <= isize::MAX as usize)
+        })
+        .ok_or(BlasError::from_status(BlasStatus::InvalidValue))
+}
+
+/// Computes the number of elements required for the output matrix C of a GEMM.
+///
+/// C is an `m` x `n` column-major matrix with leading dimension `ldc`, so it
+/// needs `ldc * n` elements.
+///
+/// # Arguments
+/// * `m` - Number of rows in C
+/// * `n` - Number of columns in C
+/// * `ldc` - Leading dimension of C
+///
+/// # Returns
+/// * `Ok(usize)` - The number of elements to allocate
+/// * `Err(BlasError)` - If a dimension is negative, `ldc < max(1, m)` or the size
+///   in bytes exceeds `isize::MAX`
+///
+/// # Examples
+/// ```
+/// use hip_rs::gemm_output_len;
+///
+/// assert_eq!(gemm_output_len::<f32>(3, 4, 8).unwrap(), 32);
+/// ```
+pub fn gemm_output_len<T>(m: i32, n: i32, ldc: i32) -> BlasResult<usize> {
+    column_major_len::<T>(m, n, ldc)
+}
+
+/// Computes the number of elements required for an input matrix A or B of a GEMM.
+///
+/// `rows` and `cols` describe `op(X)` as passed to [`gemm`], i.e. `m` x `k` for A
+/// and `k` x `n` for B. When `trans` is not [`Operation::None`] the matrix is
+/// stored transposed, which changes how many columns the leading dimension spans.
+///
+/// # Arguments
+/// * `trans` - The operation applied to the matrix
+/// * `rows` - Number of rows in op(X)
+/// * `cols` - Number of columns in op(X)
+/// * `ld` - Leading dimension of the stored matrix
+///
+/// # Returns
+/// * `Ok(usize)` - The number of elements to allocate
+/// * `Err(BlasError)` - If a dimension is negative, the leading dimension is too small
+///   or the size in bytes exceeds `isize::MAX`
+///
+/// # Examples
+/// ```
+/// use hip_rs::{gemm_operand_len, Operation};
+///
+/// // A is 3 x 2, stored as-is
+/// assert_eq!(gemm_operand_len::<f32>(Operation::None, 3, 2, 3).unwrap(), 6);
+/// // A is 3 x 2, stored as its 2 x 3 transpose
+/// assert_eq!(gemm_operand_len::<f32>(Operation::Transpose, 3, 2, 2).unwrap(), 6);
+/// ```
+pub fn gemm_operand_len<T>(trans: Operation, rows: i32, cols: i32, ld: i32) -> BlasResult<usize> {
+    match trans {
+        Operation::None => column_major_len::<T>(rows, cols, ld),
+        Operation::Transpose | Operation::Conjugate => column_major_len::<T>(cols, rows, ld),
+    }
+}
+
+/// Checks that the matrices of a GEMM hold enough elements for its shape.
+///
+/// # Arguments
+/// * `name` - Name of the calling function, used in the log message
+/// * `a_len`, `b_len`, `c_len` - Number of elements in A, B and C
+///
+/// See [`gemm`] for the remaining arguments.
+#[allow(clippy::too_many_arguments)]
+fn check_gemm_lens<A, C>(
+    name: &str,
+    trans_a: Operation,
+    trans_b: Operation,
+    m: i32,
+    n: i32,
+    k: i32,
+    a_len: usize,
+    lda: i32,
+    b_len: usize,
+    ldb: i32,
+    c_len: usize,
+    ldc: i32,
+) -> BlasResult<()> {
+    let matrices = [
+        ("a", gemm_operand_len::<A>(trans_a, m, k, lda), a_len, lda),
+        ("b", gemm_operand_len::<A>(trans_b, k, n, ldb), b_len, ldb),
+        ("c", gemm_output_len::<C>(m, n, ldc), c_len, ldc),
+    ];
+    for (matrix, required, len, ld) in matrices {
+        let required = required.inspect_err(|_| {
+            log::error!(
+                "{}: invalid shape for {} with m = {}, n = {}, k = {} and leading dimension {}",
+                name,
+                matrix,
+                m,
+                n,
+                k,
+                ld
+            );
+        })?;
+        if required > len {
+            log::error!(
+                "{}: {} holds {} elements, but the shape needs {}",
+                name,
+                matrix,
+                len,
+                required
+            );
+            return Err(BlasError::from_status(BlasStatus::InvalidValue));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that each operand of a strided batched GEMM holds `batch_count`
+/// matrices spaced `stride` elements apart.
+///
+/// See [`gemm_strided_batched`] for the arguments.
+#[allow(clippy::too_many_arguments)]
+fn check_strided_gemm_lens<T>(
+    trans_a: Operation,
+    trans_b: Operation,
+    m: i32,
+    n: i32,
+    k: i32,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    stride_a: i64,
+    b: &MemoryPointer<T>,
+    ldb: i32,
+    stride_b: i64,
+    c: &MemoryPointer<T>,
+    ldc: i32,
+    stride_c: i64,
+    batch_count: i32,
+) -> BlasResult<()> {
+    if batch_count < 0 || stride_a < 0 || stride_b < 0 || stride_c < 0 {
+        log::error!(
+            "gemm_strided_batched: batch_count = {} and strides {}, {}, {} must not be negative",
+            batch_count,
+            stride_a,
+            stride_b,
+            stride_c
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+
+    let matrices = [
+        (
+            "a",
+            gemm_operand_len::<T>(trans_a, m, k, lda)?,
+            a.size(),
+            stride_a,
+        ),
+        (
+            "b",
+            gemm_operand_len::<T>(trans_b, k, n, ldb)?,
+            b.size(),
+            stride_b,
+        ),
+        ("c", gemm_output_len::<T>(m, n, ldc)?, c.size(), stride_c),
+    ];
+    if batch_count == 0 {
+        return Ok(());
+    }
+    for (matrix, matrix_len, len, stride) in matrices {
+        if matrix_len == 0 {
+            continue;
+        }
+        let required = (batch_count as u64 - 1) * stride as u64 + matrix_len as u64;
+        if required > len as u64 {
+            log::error!(
+                "gemm_strided_batched: {} holds {} elements, but {} matrices with stride {} need {}",
+                matrix,
+                len,
+                batch_count,
+                stride,
+                required
+            );
+            return Err(BlasError::from_status(BlasStatus::InvalidValue));
+        }
+    }
+    Ok(())
+}
+
+/// Preferred alignment, in bytes, of the leading dimensions passed to GEMM.
+///
+/// hipBLAS performs best when `lda`, `ldb` and `ldc` multiplied by the element size
+/// are multiples of this value. Pad allocations accordingly for best performance.
+pub const GEMM_LEADING_DIMENSION_ALIGNMENT: usize = 16;
+
+/// Checks whether a leading dimension is aligned to [`GEMM_LEADING_DIMENSION_ALIGNMENT`]
+/// bytes for elements of type `T`, logging a debug message if it is not.
+///
+/// This is advisory only, unaligned leading dimensions are still valid.
+///
+/// # Arguments
+/// * `name` - Name of the parameter, used in the log message
+/// * `ld` - The leading dimension in elements
+///
+/// # Returns
+/// * `true` if the leading dimension is aligned
+fn check_leading_dimension_alignment<T>(name: &str, ld: i32) -> bool {
+    // Negative leading dimensions are rejected by hipBLAS itself
+    let bytes = ld.max(0) as usize * std::mem::size_of::<T>();
+    let aligned = bytes.is_multiple_of(GEMM_LEADING_DIMENSION_ALIGNMENT);
+    if !aligned {
+        log::debug!(
+            "gemm: {} = {} ({} bytes) is not a multiple of {} bytes, consider padding the allocation for better performance",
+            name,
+            ld,
+            bytes,
+            GEMM_LEADING_DIMENSION_ALIGNMENT
+        );
+    }
+    aligned
+}
+
+/// Performs matrix-matrix multiplication: C = alpha * op(A) * op(B) + beta * C
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `trans_a` - How to transform matrix A
+/// * `trans_b` - How to transform matrix B
 /// * `m` - Number of rows in op(A) and C
 /// * `n` - Number of columns in op(B) and C
 /// * `k` - Number of columns in op(A) and rows in op(B)
@@ -365,9 +974,12 @@ impl GemmDatatype for sys::hipblasDoubleComplex {
 /// * `c` - Input/output matrix C
 /// * `ldc` - Leading dimension of C
 ///
+/// Leading dimensions that are not aligned to [`GEMM_LEADING_DIMENSION_ALIGNMENT`]
+/// bytes are logged at debug level, as they may result in reduced performance.
+///
 /// # Returns
 /// * `Ok(())` if successful
-/// * `Err(HipError)` if operation failed
+/// * `Err(BlasError)` if a matrix is too short for the shape or operation failed
 pub fn gemm<T: GemmDatatype>(
     handle: &BlasHandle,
     trans_a: Operation,
@@ -384,6 +996,29 @@ pub fn gemm<T: GemmDatatype>(
     c: &mut MemoryPointer<T>,
     ldc: i32,
 ) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "b", b)?;
+    check_device(handle, "c", c)?;
+
+    check_gemm_lens::<T, T>(
+        "gemm",
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        a.size(),
+        lda,
+        b.size(),
+        ldb,
+        c.size(),
+        ldc,
+    )?;
+
+    check_leading_dimension_alignment::<T>("lda", lda);
+    check_leading_dimension_alignment::<T>("ldb", ldb);
+    check_leading_dimension_alignment::<T>("ldc", ldc);
+
     unsafe {
         let code = T::hipblas_gemm(
             handle.handle(),
@@ -405,9 +1040,544 @@ pub fn gemm<T: GemmDatatype>(
     }
 }
 
+/// Performs [`gemm`] on `stream` and measures how long it took on the device.
+///
+/// The multiplication is enclosed by two events recorded on `stream`, and the
+/// call blocks until it has completed. The handle's stream is restored before
+/// returning.
+///
+/// See [`gemm`] for the remaining arguments.
+///
+/// # Returns
+/// * `Ok(f32)` - The device time of the multiplication in milliseconds
+/// * `Err(BlasError)` - If the events could not be created or recorded, or the operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_timed<T: GemmDatatype>(
+    handle: &BlasHandle,
+    stream: &Stream,
+    trans_a: Operation,
+    trans_b: Operation,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    b: &MemoryPointer<T>,
+    ldb: i32,
+    beta: &T,
+    c: &mut MemoryPointer<T>,
+    ldc: i32,
+) -> BlasResult<f32> {
+    let start = HipEvent::create()?;
+    let end = HipEvent::create()?;
+
+    let previous_stream = handle.stream()?;
+    handle.set_stream(stream)?;
+    let mut timed = || -> BlasResult<f32> {
+        start.record(stream)?;
+        gemm(
+            handle, trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+        )?;
+        end.record(stream)?;
+        end.synchronize()?;
+        Ok(HipEvent::elapsed_time(&start, &end)?)
+    };
+    let result = timed();
+    handle.set_stream(&previous_stream)?;
+    result
+}
+
+/// Required alignment, in elements, of `k` and the input leading dimensions for int8 GEMM.
+///
+/// The int8 path of hipBLAS packs four 8 bit values together, so `k`, `lda` and `ldb`
+/// must be multiples of this value when the inputs are [`DataType::R8I`] or [`DataType::R8U`].
+pub const INT8_GEMM_ALIGNMENT: i32 = 4;
+
+/// Performs mixed-precision matrix-matrix multiplication: C = alpha * op(A) * op(B) + beta * C
+///
+/// Wraps `hipblasGemmEx`, where the element types of the matrices and the precision of the
+/// computation are given explicitly. The element types `A` and `C` must match the size of
+/// `a_type`/`b_type` and `c_type` respectively, and the scalars `alpha` and `beta` must be
+/// of `compute_type`.
+///
+/// Common combinations are:
+/// * fp16 inputs with fp32 accumulation: `A = u16` ([`DataType::R16F`]), `C = f32`
+///   ([`DataType::R32F`]), [`ComputeType::F32`]
+/// * int8 inputs with int32 accumulation: `A = i8` ([`DataType::R8I`]), `C = i32`
+///   ([`DataType::R32I`]), [`ComputeType::I32`]. This path requires `k`, `lda` and `ldb`
+///   to be multiples of [`INT8_GEMM_ALIGNMENT`].
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `trans_a` - How to transform matrix A
+/// * `trans_b` - How to transform matrix B
+/// * `m` - Number of rows in op(A) and C
+/// * `n` - Number of columns in op(B) and C
+/// * `k` - Number of columns in op(A) and rows in op(B)
+/// * `alpha` - Scalar multiplier for AB
+/// * `a` - Input matrix A
+/// * `a_type` - Element type of A
+/// * `lda` - Leading dimension of A
+/// * `b` - Input matrix B
+/// * `b_type` - Element type of B
+/// * `ldb` - Leading dimension of B
+/// * `beta` - Scalar multiplier for C
+/// * `c` - Input/output matrix C
+/// * `c_type` - Element type of C
+/// * `ldc` - Leading dimension of C
+/// * `compute_type` - Precision of the computation
+/// * `algo` - The GEMM algorithm to use
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if operation failed
+///
+/// # Errors
+/// Returns `BlasError` with `BlasStatus::InvalidValue` if:
+/// * The size of `A` or `C` does not match the given data types
+/// * The size of `S` does not match `compute_type`
+/// * `a`, `b` or `c` holds fewer elements than the shape requires
+/// * The inputs are int8 and `k`, `lda` or `ldb` is not a multiple of [`INT8_GEMM_ALIGNMENT`]
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_ex<A, C, S>(
+    handle: &BlasHandle,
+    trans_a: Operation,
+    trans_b: Operation,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: &S,
+    a: &MemoryPointer<A>,
+    a_type: DataType,
+    lda: i32,
+    b: &MemoryPointer<A>,
+    b_type: DataType,
+    ldb: i32,
+    beta: &S,
+    c: &mut MemoryPointer<C>,
+    c_type: DataType,
+    ldc: i32,
+    compute_type: ComputeType,
+    algo: GemmAlgo,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "b", b)?;
+    check_device(handle, "c", c)?;
+
+    let element_sizes_match = a_type.size() == std::mem::size_of::<A>()
+        && b_type.size() == std::mem::size_of::<A>()
+        && c_type.size() == std::mem::size_of::<C>();
+    if !element_sizes_match {
+        log::error!(
+            "gemm_ex: element types do not match the data types {:?}, {:?} and {:?}",
+            a_type,
+            b_type,
+            c_type
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+
+    // hipBLAS reads alpha and beta with the size of the compute type
+    if std::mem::size_of::<S>() != compute_type.size() {
+        log::error!(
+            "gemm_ex: alpha and beta are {} bytes, but {:?} scalars are {} bytes",
+            std::mem::size_of::<S>(),
+            compute_type,
+            compute_type.size()
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+
+    check_gemm_lens::<A, C>(
+        "gemm_ex",
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        a.size(),
+        lda,
+        b.size(),
+        ldb,
+        c.size(),
+        ldc,
+    )?;
+
+    let is_int8 = |t: DataType| matches!(t, DataType::R8I | DataType::R8U);
+    if is_int8(a_type) || is_int8(b_type) {
+        let aligned = [k, lda, ldb]
+            .iter()
+            .all(|&value| value % INT8_GEMM_ALIGNMENT == 0);
+        if !aligned {
+            log::error!(
+                "gemm_ex: int8 GEMM requires k ({}), lda ({}) and ldb ({}) to be multiples of {}",
+                k,
+                lda,
+                ldb,
+                INT8_GEMM_ALIGNMENT
+            );
+            return Err(BlasError::from_status(BlasStatus::InvalidValue));
+        }
+    }
+
+    check_leading_dimension_alignment::<A>("lda", lda);
+    check_leading_dimension_alignment::<A>("ldb", ldb);
+    check_leading_dimension_alignment::<C>("ldc", ldc);
+
+    unsafe {
+        let code = record_call!(sys::hipblasGemmEx(
+            handle.handle(),
+            trans_a.into(),
+            trans_b.into(),
+            m,
+            n,
+            k,
+            alpha as *const S as *const std::ffi::c_void,
+            a.as_pointer() as *const std::ffi::c_void,
+            a_type.into(),
+            lda,
+            b.as_pointer() as *const std::ffi::c_void,
+            b_type.into(),
+            ldb,
+            beta as *const S as *const std::ffi::c_void,
+            c.as_pointer() as *mut std::ffi::c_void,
+            c_type.into(),
+            ldc,
+            compute_type.into(),
+            algo.into(),
+        ));
+        ((), code).to_result()
+    }
+}
+
+/// Device-side arrays of matrix pointers for the batched BLAS functions.
+///
+/// The batched hipBLAS functions expect the pointers to the individual matrices in
+/// device memory. `BatchedPointers` uploads the host-side pointer arrays for A, B
+/// and C and owns the resulting device allocations.
+///
+/// # Drop
+/// Since batched calls execute asynchronously and read the pointer arrays on the
+/// device, dropping a `BatchedPointers` first synchronizes the stream of the handle
+/// it was created for and only then frees the arrays. This makes it safe to drop
+/// right after enqueueing a batched call on that handle, as long as the handle's
+/// stream is not changed in between.
+#[derive(Debug)]
+pub struct BatchedPointers<T> {
+    a: MemoryPointer<*const T>,
+    b: MemoryPointer<*const T>,
+    c: MemoryPointer<*mut T>,
+    stream: Stream,
+}
+
+impl<T> BatchedPointers<T> {
+    /// Uploads the pointer arrays for a batched call on `handle` to the device.
+    ///
+    /// # Arguments
+    /// * `handle` - The handle the batched call is made on, its current stream is
+    ///   synchronized on drop
+    /// * `a` - Device pointers to the matrices A[i]
+    /// * `b` - Device pointers to the matrices B[i]
+    /// * `c` - Device pointers to the matrices C[i]
+    ///
+    /// # Returns
+    /// * `Ok(BatchedPointers)` - The uploaded pointer arrays
+    /// * `Err(BlasError)` - If the arrays differ in length, the handle's stream
+    ///   cannot be queried, or allocation or upload fails
+    pub fn new(
+        handle: &BlasHandle,
+        a: &[*const T],
+        b: &[*const T],
+        c: &[*mut T],
+    ) -> BlasResult<Self> {
+        if a.len() != b.len() || a.len() != c.len() {
+            return Err(BlasError::from_status(BlasStatus::InvalidValue));
+        }
+
+        Ok(Self {
+            a: Self::upload(a)?,
+            b: Self::upload(b)?,
+            c: Self::upload(c)?,
+            stream: handle.stream()?,
+        })
+    }
+
+    /// Allocates a device array and copies the host pointers into it.
+    fn upload<P>(pointers: &[P]) -> HipResult<MemoryPointer<P>> {
+        let device = MemoryPointer::<P>::alloc(pointers.len())?;
+        if pointers.is_empty() {
+            return Ok(device);
+        }
+
+        unsafe {
+            let code = record_call!(sys::hipMemcpy(
+                device.as_pointer() as *mut std::ffi::c_void,
+                pointers.as_ptr() as *const std::ffi::c_void,
+                std::mem::size_of_val(pointers),
+                sys::hipMemcpyKind_hipMemcpyHostToDevice,
+            ));
+            (device, code).to_result()
+        }
+    }
+
+    /// Returns the number of matrices in the batch.
+    pub fn batch_count(&self) -> usize {
+        self.a.size()
+    }
+
+    /// Returns the device array of pointers to the matrices A[i].
+    pub fn a(&self) -> *const *const T {
+        self.a.as_pointer()
+    }
+
+    /// Returns the device array of pointers to the matrices B[i].
+    pub fn b(&self) -> *const *const T {
+        self.b.as_pointer()
+    }
+
+    /// Returns the device array of pointers to the matrices C[i].
+    pub fn c(&self) -> *mut *mut T {
+        self.c.as_pointer()
+    }
+}
+
+impl<T> Drop for BatchedPointers<T> {
+    fn drop(&mut self) {
+        // Pending batched calls may still read the arrays, wait for them before the
+        // fields are dropped and the arrays are freed
+        if let Err(error) = self.stream.synchronize() {
+            log::error!(
+                "BatchedPointers failed to synchronize before free: {:?}",
+                error
+            );
+        }
+    }
+}
+
 /// Performs batched matrix-matrix multiplication: C[i] = alpha * op(A[i]) * op(B[i]) + beta * C[i]
 /// for i = 0 to batch_count - 1
 ///
+/// The matrices are passed as raw device pointers, which the caller must keep
+/// alive until the call returns. Each pointer must hold at least as many
+/// elements as the shape requires, see [`gemm_operand_len`] and
+/// [`gemm_output_len`]. The pointers are wrapped with
+/// [`MemoryPointer::from_raw_borrowed`] and passed to [`gemm_batched_ptrs`],
+/// which borrows the [`MemoryPointer`]s instead and is preferred.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `trans_a` - How to transform matrices A[i]
+/// * `trans_b` - How to transform matrices B[i]
+/// * `m` - Number of rows in op(A[i]) and C[i]
+/// * `n` - Number of columns in op(B[i]) and C[i]
+/// * `k` - Number of columns in op(A[i]) and rows in op(B[i])
+/// * `alpha` - Scalar multiplier for A[i]B[i]
+/// * `a` - Array of input matrices A[i]
+/// * `lda` - Leading dimension of A[i]
+/// * `b` - Array of input matrices B[i]
+/// * `ldb` - Leading dimension of B[i]
+/// * `beta` - Scalar multiplier for C[i]
+/// * `c` - Array of input/output matrices C[i]
+/// * `ldc` - Leading dimension of C[i]
+/// * `batch_count` - Number of matrices in the batch, at most the length of `a`, `b` and `c`
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `batch_count` is not positive or exceeds the number of
+///   matrices, the shape is invalid, the pointer arrays could not be uploaded,
+///   or the operation failed
+pub fn gemm_batched<T: GemmDatatype>(
+    handle: &BlasHandle,
+    trans_a: Operation,
+    trans_b: Operation,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    a: &[*const T],
+    lda: i32,
+    b: &[*const T],
+    ldb: i32,
+    beta: &T,
+    c: &mut [*mut T],
+    ldc: i32,
+    batch_count: i32,
+) -> BlasResult<()> {
+    if batch_count <= 0 {
+        log::error!(
+            "gemm_batched: batch_count ({}) must be positive",
+            batch_count
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    let batch = batch_count as usize;
+    if a.len() < batch || b.len() < batch || c.len() < batch {
+        log::error!(
+            "gemm_batched: batch_count ({}) exceeds the number of matrices (a: {}, b: {}, c: {})",
+            batch_count,
+            a.len(),
+            b.len(),
+            c.len()
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+
+    let a_len = gemm_operand_len::<T>(trans_a, m, k, lda)?;
+    let b_len = gemm_operand_len::<T>(trans_b, k, n, ldb)?;
+    let c_len = gemm_output_len::<T>(m, n, ldc)?;
+
+    // The caller keeps the matrices alive for the call, as documented above
+    let borrow =
+        |pointer: *mut T, len: usize| unsafe { MemoryPointer::from_raw_borrowed(pointer, len) };
+    let a = a[..batch]
+        .iter()
+        .map(|&pointer| borrow(pointer as *mut T, a_len))
+        .collect::<HipResult<Vec<_>>>()?;
+    let b = b[..batch]
+        .iter()
+        .map(|&pointer| borrow(pointer as *mut T, b_len))
+        .collect::<HipResult<Vec<_>>>()?;
+    let mut c = c[..batch]
+        .iter()
+        .map(|&pointer| borrow(pointer, c_len))
+        .collect::<HipResult<Vec<_>>>()?;
+
+    gemm_batched_ptrs(
+        handle,
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        alpha,
+        &a.iter().collect::<Vec<_>>(),
+        lda,
+        &b.iter().collect::<Vec<_>>(),
+        ldb,
+        beta,
+        &mut c.iter_mut().collect::<Vec<_>>(),
+        ldc,
+    )
+}
+
+/// Performs batched matrix-matrix multiplication on [`MemoryPointer`]s:
+/// C[i] = alpha * op(A[i]) * op(B[i]) + beta * C[i]
+///
+/// This is the safe counterpart of [`gemm_batched`]. The raw pointer arrays are
+/// derived internally, and the matrices stay borrowed until the device has
+/// finished, so none of them can be freed while the call is running. The batch
+/// size is the number of matrices in each slice.
+///
+/// See [`gemm_batched`] for the remaining arguments.
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if the slices are empty or differ in length, a matrix is
+///   too short for the shape, the pointer arrays could not be uploaded, or the
+///   operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_batched_ptrs<T: GemmDatatype>(
+    handle: &BlasHandle,
+    trans_a: Operation,
+    trans_b: Operation,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: &T,
+    a: &[&MemoryPointer<T>],
+    lda: i32,
+    b: &[&MemoryPointer<T>],
+    ldb: i32,
+    beta: &T,
+    c: &mut [&mut MemoryPointer<T>],
+    ldc: i32,
+) -> BlasResult<()> {
+    if a.len() != b.len() || a.len() != c.len() {
+        log::error!(
+            "gemm_batched_ptrs: batches differ in length (a: {}, b: {}, c: {})",
+            a.len(),
+            b.len(),
+            c.len()
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    if a.is_empty() {
+        log::error!("gemm_batched_ptrs: the batch is empty");
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    let batch_count = i32::try_from(a.len()).map_err(|_| {
+        log::error!("gemm_batched_ptrs: {} matrices exceed i32::MAX", a.len());
+        BlasError::from_status(BlasStatus::InvalidValue)
+    })?;
+
+    for matrix in a.iter().chain(b) {
+        check_device(handle, "a/b", matrix)?;
+    }
+    for matrix in c.iter() {
+        check_device(handle, "c", matrix)?;
+    }
+
+    for ((a, b), c) in a.iter().zip(b).zip(c.iter()) {
+        check_gemm_lens::<T, T>(
+            "gemm_batched_ptrs",
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            a.size(),
+            lda,
+            b.size(),
+            ldb,
+            c.size(),
+            ldc,
+        )?;
+    }
+
+    let a_ptrs: Vec<*const T> = a.iter().map(|m| m.as_pointer() as *const T).collect();
+    let b_ptrs: Vec<*const T> = b.iter().map(|m| m.as_pointer() as *const T).collect();
+    let c_ptrs: Vec<*mut T> = c.iter().map(|m| m.as_pointer()).collect();
+
+    // Upload the pointer arrays, they are freed after a sync when `pointers` drops
+    let pointers = BatchedPointers::new(handle, &a_ptrs, &b_ptrs, &c_ptrs)?;
+
+    let result: BlasResult<()> = unsafe {
+        // Now call the batched GEMM with device pointer arrays
+        let code = T::hipblas_gemm_batched(
+            handle.handle(),
+            trans_a.into(),
+            trans_b.into(),
+            m,
+            n,
+            k,
+            alpha,
+            pointers.a(),
+            lda,
+            pointers.b(),
+            ldb,
+            beta,
+            pointers.c(),
+            ldc,
+            batch_count,
+        );
+        ((), code).to_result()
+    };
+    result?;
+
+    // The matrices are only borrowed for the call, so wait for the device to finish
+    pointers.stream.synchronize()?;
+    Ok(())
+}
+
+/// Performs strided batched matrix-matrix multiplication:
+/// C[i] = alpha * op(A[i]) * op(B[i]) + beta * C[i] for i = 0 to batch_count - 1
+///
+/// The matrices of each operand are laid out in a single allocation, with
+/// matrix `i` starting `i * stride` elements after the first. Unlike
+/// [`gemm_batched`] this needs no device arrays of pointers, so nothing is
+/// uploaded before the launch.
+///
 /// # Arguments
 /// * `handle` - HIPBLAS library handle
 /// * `trans_a` - How to transform matrices A[i]
@@ -416,19 +1586,25 @@ pub fn gemm<T: GemmDatatype>(
 /// * `n` - Number of columns in op(B[i]) and C[i]
 /// * `k` - Number of columns in op(A[i]) and rows in op(B[i])
 /// * `alpha` - Scalar multiplier for A[i]B[i]
-/// * `a` - Array of input matrices A[i]
+/// * `a` - Input matrices A[i]
 /// * `lda` - Leading dimension of A[i]
-/// * `b` - Array of input matrices B[i]
+/// * `stride_a` - Number of elements between the starts of A[i] and A[i + 1]
+/// * `b` - Input matrices B[i]
 /// * `ldb` - Leading dimension of B[i]
+/// * `stride_b` - Number of elements between the starts of B[i] and B[i + 1]
 /// * `beta` - Scalar multiplier for C[i]
-/// * `c` - Array of input/output matrices C[i]
+/// * `c` - Input/output matrices C[i]
 /// * `ldc` - Leading dimension of C[i]
+/// * `stride_c` - Number of elements between the starts of C[i] and C[i + 1]
 /// * `batch_count` - Number of matrices in the batch
 ///
 /// # Returns
 /// * `Ok(())` if successful
-/// * `Err(BlasError)` if operation failed
-pub fn gemm_batched<T: GemmDatatype>(
+/// * `Err(BlasError)` if a leading dimension is too small, a stride or
+///   `batch_count` is negative, an operand does not hold all of its matrices,
+///   or the operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_strided_batched<T: GemmDatatype>(
     handle: &BlasHandle,
     trans_a: Operation,
     trans_b: Operation,
@@ -436,45 +1612,41 @@ pub fn gemm_batched<T: GemmDatatype>(
     n: i32,
     k: i32,
     alpha: &T,
-    a: &[*const T],
+    a: &MemoryPointer<T>,
     lda: i32,
-    b: &[*const T],
+    stride_a: i64,
+    b: &MemoryPointer<T>,
     ldb: i32,
+    stride_b: i64,
     beta: &T,
-    c: &mut [*mut T],
+    c: &mut MemoryPointer<T>,
     ldc: i32,
+    stride_c: i64,
     batch_count: i32,
 ) -> BlasResult<()> {
-    // Allocate device memory for pointer arrays
-    let a_device = MemoryPointer::<*const T>::alloc(batch_count as usize).unwrap();
-    let b_device = MemoryPointer::<*const T>::alloc(batch_count as usize).unwrap();
-    let c_device = MemoryPointer::<*mut T>::alloc(batch_count as usize).unwrap();
+    check_device(handle, "a", a)?;
+    check_device(handle, "b", b)?;
+    check_device(handle, "c", c)?;
+    check_strided_gemm_lens(
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        a,
+        lda,
+        stride_a,
+        b,
+        ldb,
+        stride_b,
+        c,
+        ldc,
+        stride_c,
+        batch_count,
+    )?;
 
-    // Copy pointer arrays to device
     unsafe {
-        sys::hipMemcpy(
-            a_device.as_pointer() as *mut std::ffi::c_void,
-            a.as_ptr() as *const std::ffi::c_void,
-            batch_count as usize * std::mem::size_of::<*const T>(),
-            sys::hipMemcpyKind_hipMemcpyHostToDevice,
-        );
-
-        sys::hipMemcpy(
-            b_device.as_pointer() as *mut std::ffi::c_void,
-            b.as_ptr() as *const std::ffi::c_void,
-            batch_count as usize * std::mem::size_of::<*const T>(),
-            sys::hipMemcpyKind_hipMemcpyHostToDevice,
-        );
-
-        sys::hipMemcpy(
-            c_device.as_pointer() as *mut std::ffi::c_void,
-            c.as_ptr() as *const std::ffi::c_void,
-            batch_count as usize * std::mem::size_of::<*mut T>(),
-            sys::hipMemcpyKind_hipMemcpyHostToDevice,
-        );
-
-        // Now call the batched GEMM with device pointer arrays
-        let code = T::hipblas_gemm_batched(
+        let code = T::hipblas_gemm_strided_batched(
             handle.handle(),
             trans_a.into(),
             trans_b.into(),
@@ -482,19 +1654,18 @@ pub fn gemm_batched<T: GemmDatatype>(
             n,
             k,
             alpha,
-            a_device.as_pointer(),
+            a.as_pointer(),
             lda,
-            b_device.as_pointer(),
+            stride_a,
+            b.as_pointer(),
             ldb,
+            stride_b,
             beta,
-            c_device.as_pointer(),
+            c.as_pointer(),
             ldc,
+            stride_c,
             batch_count,
         );
-
-        // Synchronize to ensure operation is complete
-        sys::hipDeviceSynchronize();
-
         ((), code).to_result()
     }
 }
@@ -502,7 +1673,7 @@ pub fn gemm_batched<T: GemmDatatype>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Complex32;
+    use crate::{Complex32, Complex64};
     //use crate::HipResult;
 
     #[test]
@@ -516,8 +1687,13 @@ mod tests {
         let b = MemoryPointer::<sys::hipblasHalf>::alloc(k as usize * n as usize).unwrap();
         let mut c = MemoryPointer::<sys::hipblasHalf>::alloc(m as usize * n as usize).unwrap();
 
-        let alpha = 1.0 as u16; // 1.0 in half precision
-        let beta = 0.0 as u16; // 0.0 in half precision
+        // IEEE 754 half precision bit patterns of 1.0 to 4.0 and 5.0 to 8.0
+        a.copy_from_host(&[0x3c00, 0x4000, 0x4200, 0x4400]).unwrap();
+        b.copy_from_host(&[0x4500, 0x4600, 0x4700, 0x4800]).unwrap();
+
+        // A plain `1.0 as u16` would be the integer 1, a tiny subnormal
+        let alpha = sys::hipblasHalf::ONE;
+        let beta: sys::hipblasHalf = 0x0000;
 
         let result = gemm(
             &handle,
@@ -536,6 +1712,51 @@ mod tests {
             m,
         );
         assert!(result.is_ok());
+
+        // 23, 34, 31 and 46 in half precision
+        let mut c_result = [0u16; 4];
+        c.copy_to_host(&mut c_result).unwrap();
+        assert_eq!(c_result, [0x4dc0, 0x5040, 0x4fc0, 0x51c0]);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_hgemm_f16() {
+        use crate::{f16_to_hipblas, hipblas_to_f16};
+        use half::f16;
+
+        let handle = BlasHandle::new().unwrap();
+        let to_f16 = |values: &[f32]| values.iter().map(|&v| f16::from_f32(v)).collect::<Vec<_>>();
+
+        let a = MemoryPointer::<f16>::alloc(4).unwrap();
+        let b = MemoryPointer::<f16>::alloc(4).unwrap();
+        let mut c = MemoryPointer::<f16>::alloc(4).unwrap();
+        a.copy_from_host(&to_f16(&[1.0, 2.0, 3.0, 4.0])).unwrap();
+        b.copy_from_host(&to_f16(&[0.5, 1.5, 2.5, 3.5])).unwrap();
+
+        gemm(
+            &handle,
+            Operation::None,
+            Operation::None,
+            2,
+            2,
+            2,
+            &f16::ONE,
+            &a,
+            2,
+            &b,
+            2,
+            &f16::ZERO,
+            &mut c,
+            2,
+        )
+        .unwrap();
+
+        let mut result = [f16::ZERO; 4];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result.to_vec(), to_f16(&[5.0, 7.0, 13.0, 19.0]));
+        assert_eq!(hipblas_to_f16(f16_to_hipblas(result[0])), result[0]);
+        assert_eq!(f16_to_hipblas(f16::ONE), sys::hipblasHalf::ONE);
     }
 
     #[test]
@@ -649,8 +1870,8 @@ mod tests {
         let mut c =
             MemoryPointer::<sys::hipblasDoubleComplex>::alloc(m as usize * n as usize).unwrap();
 
-        let alpha = sys::hipblasDoubleComplex { x: 1.0, y: 0.0 };
-        let beta = sys::hipblasDoubleComplex { x: 0.0, y: 0.0 };
+        let alpha: sys::hipblasDoubleComplex = Complex64::new(1.0, 0.0).into();
+        let beta: sys::hipblasDoubleComplex = Complex64::default().into();
 
         let result = gemm(
             &handle,
@@ -671,6 +1892,45 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_gemm_timed() {
+        let handle = BlasHandle::new().unwrap();
+        let stream = Stream::create().unwrap();
+
+        // Column-major A = [1 3], B = [5 7]
+        //                  [2 4]      [6 8]
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let b = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+        a.copy_from_host(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        b.copy_from_host(&[5.0, 6.0, 7.0, 8.0]).unwrap();
+        c.copy_from_host(&[0.0; 4]).unwrap();
+
+        let milliseconds = gemm_timed(
+            &handle,
+            &stream,
+            Operation::None,
+            Operation::None,
+            2,
+            2,
+            2,
+            &1.0,
+            &a,
+            2,
+            &b,
+            2,
+            &0.0,
+            &mut c,
+            2,
+        )
+        .unwrap();
+        assert!(milliseconds.is_finite() && milliseconds >= 0.0);
+
+        let mut result = [0.0f32; 4];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [23.0, 34.0, 31.0, 46.0]);
+    }
+
     #[test]
     fn test_gemm_error() {
         let handle = BlasHandle::new().unwrap();
@@ -1004,4 +2264,571 @@ mod tests {
         }
         println!("=== test_gemm_batched_simple completed successfully ===");
     }
+
+    #[test]
+    fn test_gemm_ex_int8() {
+        let handle = BlasHandle::new().unwrap();
+        let m = 4;
+        let n = 4;
+        let k = 4;
+
+        // Column-major inputs with small values so the products stay well within i32
+        let a_data: Vec<i8> = (0..m * k).map(|i| (i % 7) as i8 - 3).collect();
+        let b_data: Vec<i8> = (0..k * n).map(|i| (i % 5) as i8 - 2).collect();
+
+        let mut expected = vec![0i32; m * n];
+        for col in 0..n {
+            for row in 0..m {
+                expected[col * m + row] = (0..k)
+                    .map(|p| a_data[p * m + row] as i32 * b_data[col * k + p] as i32)
+                    .sum();
+            }
+        }
+
+        let a = MemoryPointer::<i8>::alloc(m * k).unwrap();
+        let b = MemoryPointer::<i8>::alloc(k * n).unwrap();
+        let mut c = MemoryPointer::<i32>::alloc(m * n).unwrap();
+        unsafe {
+            sys::hipMemcpy(
+                a.as_pointer() as *mut std::ffi::c_void,
+                a_data.as_ptr() as *const std::ffi::c_void,
+                m * k,
+                sys::hipMemcpyKind_hipMemcpyHostToDevice,
+            );
+            sys::hipMemcpy(
+                b.as_pointer() as *mut std::ffi::c_void,
+                b_data.as_ptr() as *const std::ffi::c_void,
+                k * n,
+                sys::hipMemcpyKind_hipMemcpyHostToDevice,
+            );
+        }
+
+        let alpha: i32 = 1;
+        let beta: i32 = 0;
+        let result = gemm_ex(
+            &handle,
+            Operation::None,
+            Operation::None,
+            m as i32,
+            n as i32,
+            k as i32,
+            &alpha,
+            &a,
+            DataType::R8I,
+            m as i32,
+            &b,
+            DataType::R8I,
+            k as i32,
+            &beta,
+            &mut c,
+            DataType::R32I,
+            m as i32,
+            ComputeType::I32,
+            GemmAlgo::Default,
+        );
+        assert!(result.is_ok(), "int8 gemm_ex failed: {:?}", result);
+
+        let mut c_result = vec![0i32; m * n];
+        unsafe {
+            sys::hipMemcpy(
+                c_result.as_mut_ptr() as *mut std::ffi::c_void,
+                c.as_pointer() as *const std::ffi::c_void,
+                m * n * std::mem::size_of::<i32>(),
+                sys::hipMemcpyKind_hipMemcpyDeviceToHost,
+            );
+        }
+        assert_eq!(c_result, expected);
+    }
+
+    #[test]
+    fn test_gemm_ex_fp16_to_fp32() {
+        let handle = BlasHandle::new().unwrap();
+
+        // fp16 bit patterns of 0.0 to 4.0
+        const ZERO: u16 = 0x0000;
+        const ONE: u16 = 0x3c00;
+        const TWO: u16 = 0x4000;
+        const THREE: u16 = 0x4200;
+        const FOUR: u16 = 0x4400;
+
+        // A = 2 * I, so C = 2 * B accumulated in fp32
+        let a = MemoryPointer::<u16>::alloc(4).unwrap();
+        let b = MemoryPointer::<u16>::alloc(4).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+        a.copy_from_host(&[TWO, ZERO, ZERO, TWO]).unwrap();
+        b.copy_from_host(&[ONE, TWO, THREE, FOUR]).unwrap();
+        c.copy_from_host(&[0.0; 4]).unwrap();
+
+        gemm_ex(
+            &handle,
+            Operation::None,
+            Operation::None,
+            2,
+            2,
+            2,
+            &1.0f32,
+            &a,
+            DataType::R16F,
+            2,
+            &b,
+            DataType::R16F,
+            2,
+            &0.0f32,
+            &mut c,
+            DataType::R32F,
+            2,
+            ComputeType::F32,
+            GemmAlgo::Default,
+        )
+        .unwrap();
+
+        let mut result = [0.0f32; 4];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [2.0, 4.0, 6.0, 8.0]);
+    }
+
+    #[test]
+    fn test_gemm_ex_int8_unaligned() {
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<i8>::alloc(9).unwrap();
+        let b = MemoryPointer::<i8>::alloc(9).unwrap();
+        let mut c = MemoryPointer::<i32>::alloc(9).unwrap();
+        let alpha: i32 = 1;
+        let beta: i32 = 0;
+
+        // k = 3 is not a multiple of INT8_GEMM_ALIGNMENT
+        let result = gemm_ex(
+            &handle,
+            Operation::None,
+            Operation::None,
+            3,
+            3,
+            3,
+            &alpha,
+            &a,
+            DataType::R8I,
+            3,
+            &b,
+            DataType::R8I,
+            3,
+            &beta,
+            &mut c,
+            DataType::R32I,
+            3,
+            ComputeType::I32,
+            GemmAlgo::Default,
+        );
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+
+    #[test]
+    fn test_gemm_ex_rejects_mismatched_scalars_and_short_buffers() {
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let b = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut short_c = MemoryPointer::<f32>::alloc(3).unwrap();
+
+        let gemm_f32 = |c: &mut MemoryPointer<f32>, compute_type: ComputeType| {
+            gemm_ex(
+                &handle,
+                Operation::None,
+                Operation::None,
+                2,
+                2,
+                2,
+                &1.0f32,
+                &a,
+                DataType::R32F,
+                2,
+                &b,
+                DataType::R32F,
+                2,
+                &0.0f32,
+                c,
+                DataType::R32F,
+                2,
+                compute_type,
+                GemmAlgo::Default,
+            )
+        };
+
+        // f32 scalars would be read as 8 byte doubles
+        let result = gemm_f32(&mut c, ComputeType::F64);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        // A 2 x 2 C with ldc = 2 needs 4 elements
+        let result = gemm_f32(&mut short_c, ComputeType::F32);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        gemm_f32(&mut c, ComputeType::F32).unwrap();
+    }
+
+    #[test]
+    fn test_sgemm_strided_batched() {
+        let handle = BlasHandle::new().unwrap();
+
+        // The matrices of test_gemm_batched_simple, each batch stored back to back
+        let a = MemoryPointer::<f32>::alloc(8).unwrap();
+        let b = MemoryPointer::<f32>::alloc(8).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(8).unwrap();
+        a.copy_from_host(&[1.0, 2.0, 3.0, 4.0, 0.5, 1.0, 1.5, 2.0])
+            .unwrap();
+        b.copy_from_host(&[5.0, 6.0, 7.0, 8.0, 2.5, 3.0, 3.5, 4.0])
+            .unwrap();
+        c.copy_from_host(&[0.0; 8]).unwrap();
+
+        gemm_strided_batched(
+            &handle,
+            Operation::None,
+            Operation::None,
+            2,
+            2,
+            2,
+            &1.0,
+            &a,
+            2,
+            4,
+            &b,
+            2,
+            4,
+            &0.0,
+            &mut c,
+            2,
+            4,
+            2,
+        )
+        .unwrap();
+
+        let mut result = [0.0f32; 8];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [23.0, 34.0, 31.0, 46.0, 5.75, 8.5, 7.75, 11.5]);
+    }
+
+    #[test]
+    fn test_gemm_strided_batched_invalid() {
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<f32>::alloc(8).unwrap();
+        let b = MemoryPointer::<f32>::alloc(8).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(8).unwrap();
+        let mut short_c = MemoryPointer::<f32>::alloc(7).unwrap();
+
+        let run = |c: &mut MemoryPointer<f32>, ldc: i32, stride_b: i64| {
+            gemm_strided_batched(
+                &handle,
+                Operation::None,
+                Operation::None,
+                2,
+                2,
+                2,
+                &1.0,
+                &a,
+                2,
+                4,
+                &b,
+                2,
+                stride_b,
+                &0.0,
+                c,
+                ldc,
+                4,
+                2,
+            )
+        };
+
+        // The second C matrix ends past the buffer
+        let result = run(&mut short_c, 2, 4);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        let result = run(&mut c, 2, -4);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        // ldc smaller than m
+        let result = run(&mut c, 1, 4);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        // A stride of zero reuses the same B for every matrix
+        run(&mut c, 2, 0).unwrap();
+    }
+
+    #[test]
+    fn test_gemm_batched_ptrs() {
+        let handle = BlasHandle::new().unwrap();
+
+        // The matrices of test_gemm_batched_simple
+        let upload = |data: &[f32]| {
+            let ptr = MemoryPointer::<f32>::alloc(4).unwrap();
+            ptr.copy_from_host(data).unwrap();
+            ptr
+        };
+        let a1 = upload(&[1.0, 2.0, 3.0, 4.0]);
+        let a2 = upload(&[0.5, 1.0, 1.5, 2.0]);
+        let b1 = upload(&[5.0, 6.0, 7.0, 8.0]);
+        let b2 = upload(&[2.5, 3.0, 3.5, 4.0]);
+        let mut c1 = upload(&[0.0; 4]);
+        let mut c2 = upload(&[0.0; 4]);
+
+        gemm_batched_ptrs(
+            &handle,
+            Operation::None,
+            Operation::None,
+            2,
+            2,
+            2,
+            &1.0,
+            &[&a1, &a2],
+            2,
+            &[&b1, &b2],
+            2,
+            &0.0,
+            &mut [&mut c1, &mut c2],
+            2,
+        )
+        .unwrap();
+
+        let mut result = [0.0f32; 4];
+        c1.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [23.0, 34.0, 31.0, 46.0]);
+        c2.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [5.75, 8.5, 7.75, 11.5]);
+    }
+
+    #[test]
+    fn test_gemm_batched_ptrs_invalid() {
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let short = MemoryPointer::<f32>::alloc(3).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+
+        let run = |a: &[&MemoryPointer<f32>], b: &[&MemoryPointer<f32>], c| {
+            gemm_batched_ptrs(
+                &handle,
+                Operation::None,
+                Operation::None,
+                2,
+                2,
+                2,
+                &1.0,
+                a,
+                2,
+                b,
+                2,
+                &0.0,
+                c,
+                2,
+            )
+        };
+
+        let result = run(&[], &[], &mut []);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        let result = run(&[&a], &[&short], &mut [&mut c]);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+
+    #[test]
+    fn test_gemm_batched_zero_batch_count() {
+        let handle = BlasHandle::new().unwrap();
+        let a: Vec<*const f32> = Vec::new();
+        let mut c: Vec<*mut f32> = Vec::new();
+
+        let result = gemm_batched(
+            &handle,
+            Operation::None,
+            Operation::None,
+            2,
+            2,
+            2,
+            &1.0,
+            &a,
+            2,
+            &a,
+            2,
+            &0.0,
+            &mut c,
+            2,
+            0,
+        );
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+
+    #[test]
+    fn test_batched_pointers_no_leak() {
+        use crate::stats::thread_call_count;
+
+        let handle = BlasHandle::new().unwrap();
+        let matrices: Vec<MemoryPointer<f32>> = (0..8)
+            .map(|_| MemoryPointer::<f32>::alloc(16).unwrap())
+            .collect();
+        let a: Vec<*const f32> = matrices
+            .iter()
+            .map(|m| m.as_pointer() as *const f32)
+            .collect();
+        let c: Vec<*mut f32> = matrices.iter().map(|m| m.as_pointer()).collect();
+
+        // Counted per thread, so allocations of concurrent tests don't interfere
+        let mallocs = thread_call_count("hipMalloc");
+        let frees = thread_call_count("hipFree");
+
+        for _ in 0..100 {
+            let pointers = BatchedPointers::new(&handle, &a, &a, &c).unwrap();
+            assert_eq!(pointers.batch_count(), 8);
+            drop(pointers);
+        }
+
+        // Each BatchedPointers allocates and frees its three pointer arrays
+        assert_eq!(thread_call_count("hipMalloc") - mallocs, 300);
+        assert_eq!(thread_call_count("hipFree") - frees, 300);
+    }
+
+    #[test]
+    fn test_batched_pointers_length_mismatch() {
+        let handle = BlasHandle::new().unwrap();
+        let a: Vec<*const f32> = vec![std::ptr::null(); 2];
+        let c: Vec<*mut f32> = vec![std::ptr::null_mut(); 3];
+        assert!(BatchedPointers::new(&handle, &a, &a, &c).is_err());
+    }
+
+    #[test]
+    fn test_gemm_output_len() {
+        assert_eq!(gemm_output_len::<f32>(2, 2, 2).unwrap(), 4);
+        assert_eq!(gemm_output_len::<f32>(3, 4, 8).unwrap(), 32);
+        assert_eq!(gemm_output_len::<f32>(0, 4, 1).unwrap(), 4);
+        assert_eq!(gemm_output_len::<f32>(3, 0, 3).unwrap(), 0);
+
+        // ldc smaller than m
+        assert!(gemm_output_len::<f32>(4, 2, 3).is_err());
+        // Negative dimensions
+        assert!(gemm_output_len::<f32>(-1, 2, 2).is_err());
+        assert!(gemm_output_len::<f32>(2, -1, 2).is_err());
+    }
+
+    #[test]
+    fn test_gemm_operand_len() {
+        assert_eq!(
+            gemm_operand_len::<f32>(Operation::None, 3, 2, 3).unwrap(),
+            6
+        );
+        assert_eq!(
+            gemm_operand_len::<f32>(Operation::None, 3, 2, 4).unwrap(),
+            8
+        );
+        assert_eq!(
+            gemm_operand_len::<f32>(Operation::Transpose, 3, 2, 2).unwrap(),
+            6
+        );
+        assert_eq!(
+            gemm_operand_len::<f32>(Operation::Conjugate, 3, 2, 4).unwrap(),
+            12
+        );
+
+        // A transposed 3 x 2 operand is stored as 2 x 3, so ld = 2 is fine but 1 is not
+        assert!(gemm_operand_len::<f32>(Operation::Transpose, 3, 2, 1).is_err());
+        assert!(gemm_operand_len::<f32>(Operation::None, 3, 2, 2).is_err());
+    }
+
+    #[test]
+    fn test_gemm_len_overflow() {
+        // i32::MAX * i32::MAX elements of f32 exceed isize::MAX bytes on every target
+        let result = gemm_output_len::<f32>(i32::MAX, i32::MAX, i32::MAX);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        let result = gemm_operand_len::<f64>(Operation::Transpose, i32::MAX, i32::MAX, i32::MAX);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        // The limit is in bytes, so the same shape of single bytes still fits
+        #[cfg(target_pointer_width = "64")]
+        assert!(gemm_output_len::<u8>(i32::MAX, i32::MAX, i32::MAX).is_ok());
+    }
+
+    mod test_logger {
+        use std::cell::RefCell;
+
+        thread_local! {
+            static RECORDS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+        }
+
+        /// Captures log messages emitted on the current thread.
+        struct TestLogger;
+
+        impl log::Log for TestLogger {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                RECORDS.with(|records| records.borrow_mut().push(record.args().to_string()));
+            }
+
+            fn flush(&self) {}
+        }
+
+        static LOGGER: TestLogger = TestLogger;
+
+        pub fn init() {
+            // Another test may already have installed the logger
+            let _ = log::set_logger(&LOGGER);
+            log::set_max_level(log::LevelFilter::Debug);
+            RECORDS.with(|records| records.borrow_mut().clear());
+        }
+
+        pub fn records() -> Vec<String> {
+            RECORDS.with(|records| records.borrow().clone())
+        }
+    }
+
+    #[test]
+    fn test_gemm_leading_dimension_alignment_warning() {
+        test_logger::init();
+
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<f32>::alloc(16).unwrap();
+        let b = MemoryPointer::<f32>::alloc(16).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(16).unwrap();
+        let alpha: f32 = 1.0;
+        let beta: f32 = 0.0;
+
+        // lda = 3 f32 elements = 12 bytes, not 16 byte aligned
+        let result = gemm(
+            &handle,
+            Operation::None,
+            Operation::None,
+            3,
+            4,
+            4,
+            &alpha,
+            &a,
+            3,
+            &b,
+            4,
+            &beta,
+            &mut c,
+            4,
+        );
+        assert!(result.is_ok());
+        let records = test_logger::records();
+        assert_eq!(records.len(), 1);
+        assert!(records[0].contains("lda = 3"));
+
+        // All leading dimensions 4 f32 elements = 16 bytes
+        test_logger::init();
+        let result = gemm(
+            &handle,
+            Operation::None,
+            Operation::None,
+            4,
+            4,
+            4,
+            &alpha,
+            &a,
+            4,
+            &b,
+            4,
+            &beta,
+            &mut c,
+            4,
+        );
+        assert!(result.is_ok());
+        assert!(test_logger::records().is_empty());
+    }
 }
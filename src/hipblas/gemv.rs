@@ -0,0 +1,300 @@
+use super::{check_device, check_matrix_len, check_vector_len, BlasHandle, BlasResult, Operation};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by GEMV operations
+pub trait GemvDatatype {
+    /// Calls the appropriate HIPBLAS GEMV function for this datatype
+    ///
+    /// # Safety
+    /// `a` must be a device pointer to an `lda x n` matrix, and `x` and `y` must be
+    /// device pointers to vectors of the lengths implied by `trans`, `m` and `n`.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_gemv(
+        handle: sys::hipblasHandle_t,
+        trans: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl GemvDatatype for f32 {
+    unsafe fn hipblas_gemv(
+        handle: sys::hipblasHandle_t,
+        trans: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSgemv(
+            handle, trans, m, n, alpha, a, lda, x, incx, beta, y, incy
+        ))
+    }
+}
+
+impl GemvDatatype for f64 {
+    unsafe fn hipblas_gemv(
+        handle: sys::hipblasHandle_t,
+        trans: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDgemv(
+            handle, trans, m, n, alpha, a, lda, x, incx, beta, y, incy
+        ))
+    }
+}
+
+impl GemvDatatype for Complex32 {
+    unsafe fn hipblas_gemv(
+        handle: sys::hipblasHandle_t,
+        trans: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCgemv(
+            handle,
+            trans,
+            m,
+            n,
+            alpha as *const sys::hipblasComplex,
+            a as *const sys::hipblasComplex,
+            lda,
+            x as *const sys::hipblasComplex,
+            incx,
+            beta as *const sys::hipblasComplex,
+            y as *mut sys::hipblasComplex,
+            incy,
+        ))
+    }
+}
+
+impl GemvDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_gemv(
+        handle: sys::hipblasHandle_t,
+        trans: sys::hipblasOperation_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZgemv(
+            handle, trans, m, n, alpha, a, lda, x, incx, beta, y, incy
+        ))
+    }
+}
+
+/// Performs matrix-vector multiplication: y = alpha * op(A) * x + beta * y
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `trans` - How to transform matrix A
+/// * `m` - Number of rows in A
+/// * `n` - Number of columns in A
+/// * `alpha` - Scalar multiplier for op(A) * x
+/// * `a` - Input matrix A, `m x n` in column-major order
+/// * `lda` - Leading dimension of A
+/// * `x` - Input vector x, of `n` elements, or `m` if A is transposed
+/// * `incx` - Stride between consecutive elements of x
+/// * `beta` - Scalar multiplier for y
+/// * `y` - Input/output vector y, of `m` elements, or `n` if A is transposed
+/// * `incy` - Stride between consecutive elements of y
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `a`, `x` or `y` is too short for the shape or operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn gemv<T: GemvDatatype>(
+    handle: &BlasHandle,
+    trans: Operation,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    beta: &T,
+    y: &mut MemoryPointer<T>,
+    incy: i32,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+
+    let (x_len, y_len) = match trans {
+        Operation::None => (n, m),
+        Operation::Transpose | Operation::Conjugate => (m, n),
+    };
+    check_matrix_len("a", m, n, a, lda)?;
+    check_vector_len("x", x_len, x, incx)?;
+    check_vector_len("y", y_len, y, incy)?;
+
+    unsafe {
+        let code = T::hipblas_gemv(
+            handle.handle(),
+            trans.into(),
+            m,
+            n,
+            alpha,
+            a.as_pointer(),
+            lda,
+            x.as_pointer(),
+            incx,
+            beta,
+            y.as_pointer(),
+            incy,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlasStatus;
+
+    #[test]
+    fn test_sgemv() {
+        let handle = BlasHandle::new().unwrap();
+
+        // This vector represents the matrix in row-major layout for readability
+        // but HIPBLAS will interpret it as column-major
+        //
+        // Row-major interpretation (as written):
+        // A = [1 2]
+        //     [3 4]
+        //
+        // Column-major interpretation (as used by HIPBLAS):
+        // A = [1 3]
+        //     [2 4]
+        let a_data = [1.0f32, 2.0, 3.0, 4.0];
+        let x_data = [1.0f32, 1.0];
+
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let x = MemoryPointer::<f32>::alloc(2).unwrap();
+        let mut y = MemoryPointer::<f32>::alloc(2).unwrap();
+        a.copy_from_host(&a_data).unwrap();
+        x.copy_from_host(&x_data).unwrap();
+
+        gemv(
+            &handle,
+            Operation::None,
+            2,
+            2,
+            &1.0,
+            &a,
+            2,
+            &x,
+            1,
+            &0.0,
+            &mut y,
+            1,
+        )
+        .unwrap();
+
+        // A * x = [1 + 3, 2 + 4]
+        let mut result = [0.0f32; 2];
+        y.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [4.0, 6.0]);
+
+        // A^T * x = [1 + 2, 3 + 4]
+        gemv(
+            &handle,
+            Operation::Transpose,
+            2,
+            2,
+            &1.0,
+            &a,
+            2,
+            &x,
+            1,
+            &0.0,
+            &mut y,
+            1,
+        )
+        .unwrap();
+        y.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [3.0, 7.0]);
+    }
+
+    #[test]
+    fn test_gemv_short_buffers() {
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<f32>::alloc(6).unwrap();
+        let two = MemoryPointer::<f32>::alloc(2).unwrap();
+        let mut three = MemoryPointer::<f32>::alloc(3).unwrap();
+
+        // A is 3x2, so op(A) = A^T takes three elements and yields two
+        let result = gemv(
+            &handle,
+            Operation::Transpose,
+            3,
+            2,
+            &1.0,
+            &a,
+            3,
+            &two,
+            1,
+            &0.0,
+            &mut three,
+            1,
+        );
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        // A 3x3 matrix does not fit in six elements
+        let result = gemv(
+            &handle,
+            Operation::None,
+            3,
+            3,
+            &1.0,
+            &a,
+            3,
+            &three,
+            1,
+            &0.0,
+            &mut MemoryPointer::alloc(3).unwrap(),
+            1,
+        );
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
@@ -0,0 +1,390 @@
+use super::{check_device, check_matrix_len, check_vector_len, BlasHandle, BlasResult};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for real types supported by GER operations
+pub trait GerDatatype {
+    /// Calls the appropriate HIPBLAS GER function for this datatype
+    ///
+    /// # Safety
+    /// `x` and `y` must be device pointers to `m` and `n` strided elements, and `a`
+    /// must be a device pointer to at least `lda * n` elements.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_ger(
+        handle: sys::hipblasHandle_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        a: *mut Self,
+        lda: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl GerDatatype for f32 {
+    unsafe fn hipblas_ger(
+        handle: sys::hipblasHandle_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        a: *mut Self,
+        lda: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSger(
+            handle, m, n, alpha, x, incx, y, incy, a, lda
+        ))
+    }
+}
+
+impl GerDatatype for f64 {
+    unsafe fn hipblas_ger(
+        handle: sys::hipblasHandle_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        a: *mut Self,
+        lda: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDger(
+            handle, m, n, alpha, x, incx, y, incy, a, lda
+        ))
+    }
+}
+
+/// Trait for complex types supported by GERU and GERC operations
+pub trait GercDatatype {
+    /// Calls the appropriate HIPBLAS GERU function for this datatype
+    ///
+    /// # Safety
+    /// Same requirements as [`GerDatatype::hipblas_ger`].
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_geru(
+        handle: sys::hipblasHandle_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        a: *mut Self,
+        lda: i32,
+    ) -> sys::hipblasStatus_t;
+
+    /// Calls the appropriate HIPBLAS GERC function for this datatype
+    ///
+    /// # Safety
+    /// Same requirements as [`GerDatatype::hipblas_ger`].
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_gerc(
+        handle: sys::hipblasHandle_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        a: *mut Self,
+        lda: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl GercDatatype for Complex32 {
+    unsafe fn hipblas_geru(
+        handle: sys::hipblasHandle_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        a: *mut Self,
+        lda: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCgeru(
+            handle,
+            m,
+            n,
+            alpha as *const sys::hipblasComplex,
+            x as *const sys::hipblasComplex,
+            incx,
+            y as *const sys::hipblasComplex,
+            incy,
+            a as *mut sys::hipblasComplex,
+            lda,
+        ))
+    }
+
+    unsafe fn hipblas_gerc(
+        handle: sys::hipblasHandle_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        a: *mut Self,
+        lda: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCgerc(
+            handle,
+            m,
+            n,
+            alpha as *const sys::hipblasComplex,
+            x as *const sys::hipblasComplex,
+            incx,
+            y as *const sys::hipblasComplex,
+            incy,
+            a as *mut sys::hipblasComplex,
+            lda,
+        ))
+    }
+}
+
+impl GercDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_geru(
+        handle: sys::hipblasHandle_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        a: *mut Self,
+        lda: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZgeru(
+            handle, m, n, alpha, x, incx, y, incy, a, lda
+        ))
+    }
+
+    unsafe fn hipblas_gerc(
+        handle: sys::hipblasHandle_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        x: *const Self,
+        incx: i32,
+        y: *const Self,
+        incy: i32,
+        a: *mut Self,
+        lda: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZgerc(
+            handle, m, n, alpha, x, incx, y, incy, a, lda
+        ))
+    }
+}
+
+/// Performs a rank-1 update: A = alpha * x * y^T + A
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `m` - Number of rows in A and elements in x
+/// * `n` - Number of columns in A and elements in y
+/// * `alpha` - Scalar multiplier for x * y^T
+/// * `x` - Input vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Input vector y
+/// * `incy` - Stride between consecutive elements of y
+/// * `a` - Input/output matrix A, in column-major order
+/// * `lda` - Leading dimension of A
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `x` or `y` is too short, `a` holds fewer than `lda * n`
+///   elements or the operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn ger<T: GerDatatype>(
+    handle: &BlasHandle,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    y: &MemoryPointer<T>,
+    incy: i32,
+    a: &mut MemoryPointer<T>,
+    lda: i32,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+    check_device(handle, "a", a)?;
+
+    check_vector_len("x", m, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+    check_matrix_len("a", m, n, a, lda)?;
+
+    unsafe {
+        let code = T::hipblas_ger(
+            handle.handle(),
+            m,
+            n,
+            alpha,
+            x.as_pointer(),
+            incx,
+            y.as_pointer(),
+            incy,
+            a.as_pointer(),
+            lda,
+        );
+        ((), code).to_result()
+    }
+}
+
+/// Performs an unconjugated complex rank-1 update: A = alpha * x * y^T + A
+///
+/// See [`ger`] for the arguments.
+#[allow(clippy::too_many_arguments)]
+pub fn geru<T: GercDatatype>(
+    handle: &BlasHandle,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    y: &MemoryPointer<T>,
+    incy: i32,
+    a: &mut MemoryPointer<T>,
+    lda: i32,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+    check_device(handle, "a", a)?;
+
+    check_vector_len("x", m, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+    check_matrix_len("a", m, n, a, lda)?;
+
+    unsafe {
+        let code = T::hipblas_geru(
+            handle.handle(),
+            m,
+            n,
+            alpha,
+            x.as_pointer(),
+            incx,
+            y.as_pointer(),
+            incy,
+            a.as_pointer(),
+            lda,
+        );
+        ((), code).to_result()
+    }
+}
+
+/// Performs a conjugated complex rank-1 update: A = alpha * x * y^H + A
+///
+/// See [`ger`] for the arguments. Each element of `y` is conjugated.
+#[allow(clippy::too_many_arguments)]
+pub fn gerc<T: GercDatatype>(
+    handle: &BlasHandle,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    y: &MemoryPointer<T>,
+    incy: i32,
+    a: &mut MemoryPointer<T>,
+    lda: i32,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+    check_device(handle, "a", a)?;
+
+    check_vector_len("x", m, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+    check_matrix_len("a", m, n, a, lda)?;
+
+    unsafe {
+        let code = T::hipblas_gerc(
+            handle.handle(),
+            m,
+            n,
+            alpha,
+            x.as_pointer(),
+            incx,
+            y.as_pointer(),
+            incy,
+            a.as_pointer(),
+            lda,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlasStatus;
+
+    #[test]
+    fn test_sger_outer_product() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(2).unwrap();
+        let y = MemoryPointer::<f32>::alloc(2).unwrap();
+        let mut a = MemoryPointer::<f32>::alloc(4).unwrap();
+        x.copy_from_host(&[1.0, 2.0]).unwrap();
+        y.copy_from_host(&[3.0, 4.0]).unwrap();
+        a.copy_from_host(&[0.0; 4]).unwrap();
+
+        ger(&handle, 2, 2, &1.0, &x, 1, &y, 1, &mut a, 2).unwrap();
+
+        // x * y^T = [3 4]
+        //           [6 8]
+        // which is [3, 6, 4, 8] in column-major order
+        let mut result = [0.0f32; 4];
+        a.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [3.0, 6.0, 4.0, 8.0]);
+    }
+
+    #[test]
+    fn test_cgeru_cgerc() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<Complex32>::alloc(1).unwrap();
+        let mut a = MemoryPointer::<Complex32>::alloc(1).unwrap();
+        x.copy_from_host(&[Complex32::new(1.0, 1.0)]).unwrap();
+        let one = Complex32::new(1.0, 0.0);
+        let mut result = [Complex32::default()];
+
+        // (1 + i)(1 + i) = 2i
+        a.copy_from_host(&[Complex32::default()]).unwrap();
+        geru(&handle, 1, 1, &one, &x, 1, &x, 1, &mut a, 1).unwrap();
+        a.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [Complex32::new(0.0, 2.0)]);
+
+        // (1 + i)(1 - i) = 2
+        a.copy_from_host(&[Complex32::default()]).unwrap();
+        gerc(&handle, 1, 1, &one, &x, 1, &x, 1, &mut a, 1).unwrap();
+        a.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [Complex32::new(2.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_ger_matrix_too_small() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(2).unwrap();
+        let y = MemoryPointer::<f32>::alloc(2).unwrap();
+        let mut a = MemoryPointer::<f32>::alloc(3).unwrap();
+
+        let result = ger(&handle, 2, 2, &1.0, &x, 1, &y, 1, &mut a, 2);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
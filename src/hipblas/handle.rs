@@ -1,6 +1,6 @@
-use super::BlasResult;
+use super::{AtomicsMode, BlasError, BlasResult, BlasStatus, PointerMode};
 use crate::result::ResultExt;
-use crate::sys;
+use crate::{sys, Device, HipResult, MemoryPointer, Stream};
 use std::fmt;
 
 /// A handle to a hipBLAS library context.
@@ -25,10 +25,13 @@ use std::fmt;
 #[derive(Debug)]
 pub struct BlasHandle {
     handle: sys::hipblasHandle_t,
+    device: Device,
+    owned: bool,
+    sync_on_drop: bool,
 }
 
 impl BlasHandle {
-    /// Creates a new hipBLAS library context.
+    /// Creates a new hipBLAS library context on the current device.
     ///
     /// # Returns
     ///
@@ -43,13 +46,86 @@ impl BlasHandle {
     /// let handle = BlasHandle::new().unwrap();
     /// ```
     pub fn new() -> BlasResult<Self> {
+        let device = crate::get_device()?;
         let mut handle = std::ptr::null_mut();
         unsafe {
-            let status = sys::hipblasCreate(&mut handle);
-            (Self { handle }, status).to_result()
+            let status = record_call!(sys::hipblasCreate(&mut handle));
+            (
+                Self {
+                    handle,
+                    device,
+                    owned: true,
+                    sync_on_drop: false,
+                },
+                status,
+            )
+                .to_result()
         }
     }
 
+    /// Wraps an existing raw hipBLAS handle without taking ownership of it.
+    ///
+    /// This is intended for FFI interop, where the handle was created and is
+    /// managed by other code. The returned `BlasHandle` does not destroy the
+    /// handle when dropped.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `handle` is a valid hipBLAS handle that was
+    /// created on the current device, and that it outlives the returned
+    /// `BlasHandle`.
+    ///
+    /// # Returns
+    /// * `Ok(BlasHandle)` - The borrowed handle, recording the current device
+    /// * `Err(BlasError)` - If the current device could not be queried
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hip_rs::BlasHandle;
+    ///
+    /// let owner = BlasHandle::new().unwrap();
+    /// let borrowed = unsafe { BlasHandle::from_raw_borrowed(owner.handle()) }.unwrap();
+    /// assert!(!borrowed.is_owned());
+    /// ```
+    pub unsafe fn from_raw_borrowed(handle: sys::hipblasHandle_t) -> BlasResult<Self> {
+        Ok(Self {
+            handle,
+            device: crate::get_device()?,
+            owned: false,
+            sync_on_drop: false,
+        })
+    }
+
+    /// Returns `true` if the handle is destroyed when this `BlasHandle` is dropped.
+    pub fn is_owned(&self) -> bool {
+        self.owned
+    }
+
+    /// Sets whether dropping the handle first waits for work on its stream.
+    ///
+    /// Destroying a handle while operations are still running on its stream can
+    /// race with the teardown. With this enabled, `Drop` synchronizes the
+    /// handle's stream before destroying it. Disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hip_rs::BlasHandle;
+    ///
+    /// let handle = BlasHandle::new().unwrap().with_sync_on_drop(true);
+    /// assert!(handle.syncs_on_drop());
+    /// ```
+    pub fn with_sync_on_drop(mut self, sync_on_drop: bool) -> Self {
+        self.sync_on_drop = sync_on_drop;
+        self
+    }
+
+    /// Returns `true` if dropping the handle synchronizes its stream first.
+    pub fn syncs_on_drop(&self) -> bool {
+        self.sync_on_drop
+    }
+
     /// Returns the raw hipBLAS handle.
     ///
     /// # Safety
@@ -59,14 +135,185 @@ impl BlasHandle {
     pub fn handle(&self) -> sys::hipblasHandle_t {
         self.handle
     }
+
+    /// Sets the stream that subsequent hipBLAS calls on this handle are enqueued on.
+    ///
+    /// # Arguments
+    /// * `stream` - The stream to use, it must outlive its use by this handle
+    pub fn set_stream(&self, stream: &Stream) -> BlasResult<()> {
+        unsafe {
+            let status = record_call!(sys::hipblasSetStream(self.handle, stream.handle()));
+            ((), status).to_result()
+        }
+    }
+
+    /// Gets the stream hipBLAS calls on this handle are currently enqueued on.
+    ///
+    /// The returned [`Stream`] is borrowed and does not destroy the stream when dropped.
+    pub fn stream(&self) -> BlasResult<Stream> {
+        let mut stream: sys::hipStream_t = std::ptr::null_mut();
+        unsafe {
+            let status = record_call!(sys::hipblasGetStream(self.handle, &mut stream));
+            (Stream::from_raw_borrowed(stream), status).to_result()
+        }
+    }
+
+    /// Sets where subsequent hipBLAS calls on this handle read and write scalars.
+    ///
+    /// # Arguments
+    /// * `mode` - Whether scalar arguments are host or device pointers
+    ///
+    /// # Safety
+    /// The safe wrappers of this crate pass scalars such as `alpha`, `beta` and
+    /// reduction results as host references. In [`PointerMode::Device`] hipBLAS
+    /// dereferences those as device pointers. While the handle is in device mode,
+    /// the caller must only use it for raw hipBLAS calls through
+    /// [`BlasHandle::handle`] with device scalars. The exceptions are [`crate::rot`]
+    /// and [`crate::rotg`], which switch to host mode for the duration of the call.
+    pub unsafe fn set_pointer_mode(&self, mode: PointerMode) -> BlasResult<()> {
+        unsafe {
+            let status = record_call!(sys::hipblasSetPointerMode(self.handle, mode.into()));
+            ((), status).to_result()
+        }
+    }
+
+    /// Gets where hipBLAS calls on this handle currently read and write scalars.
+    ///
+    /// Handles start out in [`PointerMode::Host`].
+    pub fn pointer_mode(&self) -> BlasResult<PointerMode> {
+        let mut mode: sys::hipblasPointerMode_t = 0;
+        unsafe {
+            let status = record_call!(sys::hipblasGetPointerMode(self.handle, &mut mode));
+            (PointerMode::from(mode), status).to_result()
+        }
+    }
+
+    /// Sets whether hipBLAS calls on this handle may use atomic operations.
+    ///
+    /// # Arguments
+    /// * `mode` - Whether functions that support atomics may use them
+    pub fn set_atomics_mode(&self, mode: AtomicsMode) -> BlasResult<()> {
+        unsafe {
+            let status = record_call!(sys::hipblasSetAtomicsMode(self.handle, mode.into()));
+            ((), status).to_result()
+        }
+    }
+
+    /// Gets whether hipBLAS calls on this handle may currently use atomic operations.
+    pub fn atomics_mode(&self) -> BlasResult<AtomicsMode> {
+        let mut mode: sys::hipblasAtomicsMode_t = 0;
+        unsafe {
+            let status = record_call!(sys::hipblasGetAtomicsMode(self.handle, &mut mode));
+            (AtomicsMode::from(mode), status).to_result()
+        }
+    }
+
+    /// Makes hipBLAS calls on this handle reproducible by disallowing atomics.
+    ///
+    /// With atomics allowed, functions such as GEMM may split a reduction across
+    /// workgroups and accumulate the partial results in whatever order they
+    /// finish, so repeated calls can differ in the last bits. After this call,
+    /// repeating a call with the same inputs on this handle gives bitwise
+    /// identical results.
+    ///
+    /// The guarantee only holds for the same device, hipBLAS/rocBLAS version and
+    /// problem sizes; a different GPU or library release may pick another kernel
+    /// and round differently. Disallowing atomics can also make some functions
+    /// slower. Restore the default with [`BlasHandle::set_atomics_mode`].
+    pub fn set_deterministic(&self) -> BlasResult<()> {
+        self.set_atomics_mode(AtomicsMode::NotAllowed)
+    }
+
+    /// Blocks until all work enqueued on this handle's stream has completed.
+    ///
+    /// Unlike [`crate::synchronize`], this only waits for the handle's stream, so
+    /// work queued on other streams or by other handles keeps running.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Once the stream is idle
+    /// * `Err(BlasError)` - If the stream could not be queried or synchronized
+    pub fn flush(&self) -> BlasResult<()> {
+        self.stream()?.synchronize()?;
+        Ok(())
+    }
+
+    /// Returns the device that operations on this handle run on.
+    ///
+    /// This is the device that was current when the handle was created or
+    /// wrapped, regardless of the calling thread's current device.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// Checks that `buffer` was allocated on the device of this handle.
+    ///
+    /// Passing memory from another device to a hipBLAS call does not fail
+    /// cleanly, it faults or silently computes garbage.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the buffer, used in the error message
+    /// * `buffer` - The device memory to check
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the buffer is on the same device as the handle
+    /// * `Err(BlasError)` - With [`BlasStatus::InvalidValue`] if the devices differ,
+    ///   or if the buffer's device could not be queried
+    pub fn check_same_device<T>(&self, name: &str, buffer: &MemoryPointer<T>) -> BlasResult<()> {
+        // Empty buffers have no allocation to query, and are never accessed
+        if buffer.size() == 0 {
+            return Ok(());
+        }
+
+        let handle_device = self.device();
+        let buffer_device = buffer.device()?;
+        if handle_device != buffer_device {
+            log::error!(
+                "{} is allocated on device {}, but the hipBLAS handle runs on device {}",
+                name,
+                buffer_device.id(),
+                handle_device.id()
+            );
+            return Err(BlasError::from_status(BlasStatus::InvalidValue));
+        }
+        Ok(())
+    }
+}
+
+/// Checks that `buffer` is on the device of `handle` when the `checked` feature is enabled.
+///
+/// Without the feature this compiles to nothing, so the BLAS wrappers can call
+/// it unconditionally.
+#[inline]
+pub(crate) fn check_device<T>(
+    handle: &BlasHandle,
+    name: &str,
+    buffer: &MemoryPointer<T>,
+) -> BlasResult<()> {
+    #[cfg(feature = "checked")]
+    handle.check_same_device(name, buffer)?;
+    #[cfg(not(feature = "checked"))]
+    let _ = (handle, name, buffer);
+    Ok(())
 }
 
 // Implement Drop to clean up the handle
 impl Drop for BlasHandle {
     fn drop(&mut self) {
-        if !self.handle.is_null() {
+        // Borrowed handles are destroyed by their owner
+        if self.owned && !self.handle.is_null() {
             unsafe {
-                let status = sys::hipblasDestroy(self.handle);
+                if self.sync_on_drop {
+                    let mut stream: sys::hipStream_t = std::ptr::null_mut();
+                    let mut code = record_call!(sys::hipblasGetStream(self.handle, &mut stream));
+                    if code == 0 {
+                        code = record_call!(sys::hipStreamSynchronize(stream));
+                    }
+                    if code != 0 {
+                        log::error!("Failed to synchronize hipBLAS handle before drop: {}", code);
+                    }
+                }
+
+                let status = record_call!(sys::hipblasDestroy(self.handle));
                 if status != 0 {
                     log::error!("Failed to destroy hipBLAS handle: {}", status);
                 }
@@ -75,6 +322,41 @@ impl Drop for BlasHandle {
     }
 }
 
+/// Runs `f` in a scoped hipBLAS session.
+///
+/// Creates a [`BlasHandle`], passes it to `f`, waits for the work submitted to the
+/// handle's stream to finish and destroys the handle again. This is convenient for one-off
+/// computations where managing the handle lifecycle would be noise.
+///
+/// # Arguments
+/// * `f` - The computation to run with the session's handle
+///
+/// # Returns
+/// * `Ok(R)` - The value returned by `f`, once the handle's stream is idle
+/// * `Err(HipError)` - If the handle could not be created or synchronization
+///   failed, hipBLAS errors are converted to the closest [`crate::HipStatus`]
+///
+/// # Examples
+/// ```
+/// use hip_rs::blas_compute;
+///
+/// let valid = blas_compute(|handle| !handle.handle().is_null()).unwrap();
+/// assert!(valid);
+/// ```
+pub fn blas_compute<F, R>(f: F) -> HipResult<R>
+where
+    F: FnOnce(&BlasHandle) -> R,
+{
+    let handle = BlasHandle::new().map_err(BlasError::into_hip_error)?;
+
+    let result = f(&handle);
+    handle
+        .stream()
+        .map_err(BlasError::into_hip_error)?
+        .synchronize()?;
+    Ok(result)
+}
+
 // Implement Display for better error messages
 impl fmt::Display for BlasHandle {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -98,6 +380,193 @@ mod tests {
         assert!(!handle.handle().is_null(), "Handle is null after creation");
     }
 
+    #[test]
+    fn test_blas_compute_matrix_vector() {
+        use crate::{gemv, MemoryPointer, Operation};
+
+        // Column-major A = [1 3], x = [1]
+        //                  [2 4]      [1]
+        let y = blas_compute(|handle| {
+            let a = MemoryPointer::<f32>::alloc(4).unwrap();
+            let x = MemoryPointer::<f32>::alloc(2).unwrap();
+            let mut y = MemoryPointer::<f32>::alloc(2).unwrap();
+            a.copy_from_host(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+            x.copy_from_host(&[1.0, 1.0]).unwrap();
+
+            gemv(
+                handle,
+                Operation::None,
+                2,
+                2,
+                &1.0,
+                &a,
+                2,
+                &x,
+                1,
+                &0.0,
+                &mut y,
+                1,
+            )
+            .unwrap();
+
+            let mut result = vec![0.0f32; 2];
+            y.copy_to_host(&mut result).unwrap();
+            result
+        })
+        .unwrap();
+
+        assert_eq!(y, vec![4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_set_stream() {
+        let handle = BlasHandle::new().unwrap();
+        let stream = Stream::create().unwrap();
+
+        let default = handle.stream().unwrap();
+        assert!(!default.is_owned());
+
+        handle.set_stream(&stream).unwrap();
+        assert_eq!(handle.stream().unwrap().handle(), stream.handle());
+
+        handle.set_stream(&default).unwrap();
+    }
+
+    #[test]
+    fn test_pointer_mode() {
+        let handle = BlasHandle::new().unwrap();
+        assert_eq!(handle.pointer_mode().unwrap(), PointerMode::Host);
+
+        // SAFETY: the handle is not passed to any wrapper while in device mode
+        unsafe { handle.set_pointer_mode(PointerMode::Device) }.unwrap();
+        assert_eq!(handle.pointer_mode().unwrap(), PointerMode::Device);
+    }
+
+    #[test]
+    fn test_atomics_mode() {
+        let handle = BlasHandle::new().unwrap();
+
+        handle.set_atomics_mode(AtomicsMode::Allowed).unwrap();
+        assert_eq!(handle.atomics_mode().unwrap(), AtomicsMode::Allowed);
+
+        handle.set_deterministic().unwrap();
+        assert_eq!(handle.atomics_mode().unwrap(), AtomicsMode::NotAllowed);
+    }
+
+    #[test]
+    fn test_set_deterministic_gemm() {
+        use crate::{gemm, MemoryPointer, Operation};
+
+        let handle = BlasHandle::new().unwrap();
+        handle.set_deterministic().unwrap();
+
+        let n = 64;
+        let len = (n * n) as usize;
+        let a_host: Vec<f32> = (0..len).map(|i| ((i * 7919) % 101) as f32 / 7.0).collect();
+        let b_host: Vec<f32> = (0..len).map(|i| ((i * 104729) % 97) as f32 / 3.0).collect();
+        let a = MemoryPointer::<f32>::alloc(len).unwrap();
+        let b = MemoryPointer::<f32>::alloc(len).unwrap();
+        a.copy_from_host(&a_host).unwrap();
+        b.copy_from_host(&b_host).unwrap();
+
+        let mut results = Vec::new();
+        for _ in 0..2 {
+            let mut c = MemoryPointer::<f32>::alloc(len).unwrap();
+            gemm(
+                &handle,
+                Operation::None,
+                Operation::None,
+                n,
+                n,
+                n,
+                &1.0,
+                &a,
+                n,
+                &b,
+                n,
+                &0.0,
+                &mut c,
+                n,
+            )
+            .unwrap();
+            let mut host = vec![0.0f32; len];
+            c.copy_to_host(&mut host).unwrap();
+            results.push(host.iter().map(|x| x.to_bits()).collect::<Vec<_>>());
+        }
+        assert_eq!(results[0], results[1]);
+    }
+
+    #[test]
+    fn test_sync_on_drop() {
+        let handle = BlasHandle::new().unwrap().with_sync_on_drop(true);
+        assert!(handle.syncs_on_drop());
+        let stream = Stream::create().unwrap();
+        handle.set_stream(&stream).unwrap();
+
+        let a = crate::MemoryPointer::<f32>::alloc(64 * 64).unwrap();
+        let b = crate::MemoryPointer::<f32>::alloc(64 * 64).unwrap();
+        let mut c = crate::MemoryPointer::<f32>::alloc(64 * 64).unwrap();
+        crate::gemm(
+            &handle,
+            crate::Operation::None,
+            crate::Operation::None,
+            64,
+            64,
+            64,
+            &1.0,
+            &a,
+            64,
+            &b,
+            64,
+            &0.0,
+            &mut c,
+            64,
+        )
+        .unwrap();
+
+        // Dropped with the gemm possibly still in flight
+        drop(handle);
+        stream.synchronize().unwrap();
+    }
+
+    #[test]
+    fn test_flush() {
+        use crate::{gemm, MemoryPointer, Operation};
+
+        let handle = BlasHandle::new().unwrap();
+        let stream = Stream::create().unwrap();
+        handle.set_stream(&stream).unwrap();
+
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let b = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+        a.copy_from_host(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        b.copy_from_host(&[1.0, 0.0, 0.0, 1.0]).unwrap();
+
+        gemm(
+            &handle,
+            Operation::None,
+            Operation::None,
+            2,
+            2,
+            2,
+            &2.0,
+            &a,
+            2,
+            &b,
+            2,
+            &0.0,
+            &mut c,
+            2,
+        )
+        .unwrap();
+        handle.flush().unwrap();
+
+        let mut result = [0.0f32; 4];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [2.0, 4.0, 6.0, 8.0]);
+    }
+
     #[test]
     fn test_handle_drop() {
         let handle = BlasHandle::new().unwrap();
@@ -189,4 +658,90 @@ mod tests {
         let handle = BlasHandle::new().unwrap();
         assert!(!handle.handle().is_null(), "Handle should not be null");
     }
+
+    #[test]
+    fn test_handle_from_raw_borrowed() {
+        use crate::{blas_call, BlasResult, MemoryPointer, ResultExt};
+
+        let owner = BlasHandle::new().unwrap();
+        assert!(owner.is_owned());
+
+        let borrowed = unsafe { BlasHandle::from_raw_borrowed(owner.handle()) }.unwrap();
+        assert!(!borrowed.is_owned());
+        assert_eq!(borrowed.handle(), owner.handle());
+        assert_eq!(borrowed.device(), owner.device());
+
+        // The borrowed handle is usable for hipBLAS calls
+        let x = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut result: f32 = 0.0;
+        let blas_result = blas_call!(sys::hipblasSasum(
+            borrowed.handle(),
+            0,
+            x.as_pointer(),
+            1,
+            &mut result,
+        ));
+        assert!(blas_result.is_ok());
+
+        // Dropping the borrowed handle must leave the original intact
+        drop(borrowed);
+        let blas_result = blas_call!(sys::hipblasSasum(
+            owner.handle(),
+            0,
+            x.as_pointer(),
+            1,
+            &mut result,
+        ));
+        assert!(blas_result.is_ok());
+    }
+
+    #[test]
+    fn test_check_same_device() {
+        let handle = BlasHandle::new().unwrap();
+        let buffer = MemoryPointer::<f32>::alloc(4).unwrap();
+
+        assert_eq!(handle.device(), buffer.device().unwrap());
+        handle.check_same_device("buffer", &buffer).unwrap();
+    }
+
+    #[test]
+    fn test_check_same_device_mismatch() {
+        if crate::get_device_count().unwrap() < 2 {
+            println!("Fewer than two devices available, skipping test");
+            return;
+        }
+
+        crate::set_device(Device::new(0)).unwrap();
+        let handle = BlasHandle::new().unwrap();
+        crate::set_device(Device::new(1)).unwrap();
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let b = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+
+        // Device 1 is still current, the handle must keep reporting device 0
+        assert_eq!(handle.device(), Device::new(0));
+        let result = handle.check_same_device("a", &a);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+
+        // With the `checked` feature the wrappers reject the buffers before launching
+        if cfg!(feature = "checked") {
+            let result = crate::gemm(
+                &handle,
+                crate::Operation::None,
+                crate::Operation::None,
+                2,
+                2,
+                2,
+                &1.0,
+                &a,
+                2,
+                &b,
+                2,
+                &0.0,
+                &mut c,
+                2,
+            );
+            assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+        }
+    }
 }
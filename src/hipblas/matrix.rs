@@ -0,0 +1,547 @@
+use super::{
+    geam, gemm, nrm2, scal, BlasError, BlasHandle, BlasResult, BlasStatus, GeamDatatype,
+    GemmDatatype, Nrm2Datatype, Operation, ScalDatatype,
+};
+use crate::result::ResultExt;
+use crate::{sys, HipError, HipResult, HipStatus, MemoryPointer};
+
+/// A dense matrix stored in device memory.
+///
+/// Elements are laid out in column-major order, as hipBLAS expects, with
+/// `leading_dim` elements between the starts of consecutive columns.
+#[derive(Debug)]
+pub struct Matrix<T> {
+    data: MemoryPointer<T>,
+    rows: i32,
+    cols: i32,
+    leading_dim: i32,
+}
+
+impl<T> Matrix<T> {
+    /// Allocates an uninitialized `rows x cols` matrix on the current device.
+    ///
+    /// # Arguments
+    /// * `rows` - Number of rows
+    /// * `cols` - Number of columns
+    ///
+    /// # Returns
+    /// * `Ok(Matrix<T>)` - The allocated matrix, with a leading dimension of `rows`
+    /// * `Err(HipError)` - If a dimension is not positive or the allocation failed
+    pub fn new(rows: i32, cols: i32) -> HipResult<Self> {
+        if rows <= 0 || cols <= 0 {
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+
+        Ok(Self {
+            data: MemoryPointer::alloc(rows as usize * cols as usize)?,
+            rows,
+            cols,
+            leading_dim: rows,
+        })
+    }
+
+    /// Uploads a column-major host buffer into a new `rows x cols` matrix.
+    ///
+    /// # Arguments
+    /// * `rows` - Number of rows
+    /// * `cols` - Number of columns
+    /// * `data` - `rows * cols` elements in column-major order
+    ///
+    /// # Returns
+    /// * `Ok(Matrix<T>)` - The uploaded matrix
+    /// * `Err(HipError)` - If `data` has the wrong length or the upload failed
+    pub fn from_host(rows: i32, cols: i32, data: &[T]) -> HipResult<Self> {
+        let matrix = Self::new(rows, cols)?;
+        if data.len() != matrix.data.size() {
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+
+        matrix.data.copy_from_host(data)?;
+        Ok(matrix)
+    }
+
+    /// Downloads the matrix into a column-major host buffer of `rows * cols` elements.
+    pub fn to_host(&self) -> HipResult<Vec<T>>
+    where
+        T: Clone + Default,
+    {
+        let mut host = vec![T::default(); self.data.size()];
+        self.data.copy_to_host(&mut host)?;
+        Ok(host)
+    }
+
+    /// Downloads the `rows x cols` tile starting at `(row_offset, col_offset)`.
+    ///
+    /// The tile is fetched with a single `hipMemcpy2D`, one column at a time as
+    /// the data is stored, and returned in row-major order.
+    ///
+    /// # Arguments
+    /// * `row_offset` - Row of the top-left element of the tile
+    /// * `col_offset` - Column of the top-left element of the tile
+    /// * `rows` - Number of rows in the tile
+    /// * `cols` - Number of columns in the tile
+    ///
+    /// # Returns
+    /// * `Ok(Vec<T>)` - The `rows * cols` elements of the tile, row by row
+    /// * `Err(HipError)` - If the tile does not fit in the matrix or the copy failed
+    pub fn download_tile(
+        &self,
+        row_offset: i32,
+        col_offset: i32,
+        rows: i32,
+        cols: i32,
+    ) -> HipResult<Vec<T>>
+    where
+        T: Clone + Default,
+    {
+        let fits = |offset: i32, len: i32, total: i32| {
+            offset >= 0 && len >= 0 && offset.checked_add(len).is_some_and(|end| end <= total)
+        };
+        if !fits(row_offset, rows, self.rows) || !fits(col_offset, cols, self.cols) {
+            log::error!(
+                "download_tile: {}x{} tile at ({}, {}) does not fit in a {}x{} matrix",
+                rows,
+                cols,
+                row_offset,
+                col_offset,
+                self.rows,
+                self.cols
+            );
+            return Err(HipError::from_status(HipStatus::InvalidValue));
+        }
+        if rows == 0 || cols == 0 {
+            return Ok(Vec::new());
+        }
+
+        let (rows, cols) = (rows as usize, cols as usize);
+        let element = std::mem::size_of::<T>();
+        let offset = row_offset as usize + col_offset as usize * self.leading_dim as usize;
+        let mut column_major = vec![T::default(); rows * cols];
+        unsafe {
+            let code = record_call!(sys::hipMemcpy2D(
+                column_major.as_mut_ptr() as *mut std::ffi::c_void,
+                rows * element,
+                self.data.as_pointer().add(offset) as *const std::ffi::c_void,
+                self.leading_dim as usize * element,
+                rows * element,
+                cols,
+                sys::hipMemcpyKind_hipMemcpyDeviceToHost,
+            ));
+            let result: HipResult<()> = ((), code).to_result();
+            result?;
+        }
+
+        Ok((0..rows * cols)
+            .map(|i| column_major[(i % cols) * rows + i / cols].clone())
+            .collect())
+    }
+
+    /// Returns the number of rows
+    pub fn rows(&self) -> i32 {
+        self.rows
+    }
+
+    /// Returns the number of columns
+    pub fn cols(&self) -> i32 {
+        self.cols
+    }
+
+    /// Returns the leading dimension
+    pub fn leading_dim(&self) -> i32 {
+        self.leading_dim
+    }
+
+    /// Returns the underlying device memory
+    pub fn data(&self) -> &MemoryPointer<T> {
+        &self.data
+    }
+
+    /// Returns a view of the matrix as it is stored.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: &self.data,
+            rows: self.rows,
+            cols: self.cols,
+            leading_dim: self.leading_dim,
+            operation: Operation::None,
+        }
+    }
+
+    /// Returns a view of the transpose of the matrix.
+    ///
+    /// The data is not moved. The view swaps the logical dimensions and passes
+    /// [`Operation::Transpose`] to hipBLAS, which reads the stored matrix
+    /// transposed. This is cheaper than transposing the data in memory.
+    pub fn transpose_view(&self) -> MatrixView<'_, T> {
+        self.as_view().transpose_view()
+    }
+}
+
+/// A borrowed view of a [`Matrix`], optionally transposed.
+///
+/// Views are created with [`Matrix::as_view`] and [`Matrix::transpose_view`].
+/// `rows` and `cols` are the dimensions after the operation is applied, while
+/// the leading dimension still describes the stored data.
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    data: &'a MemoryPointer<T>,
+    rows: i32,
+    cols: i32,
+    leading_dim: i32,
+    operation: Operation,
+}
+
+// Implemented by hand, deriving would require `T: Clone` for a view that only
+// holds a reference
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Returns the number of rows of the viewed matrix
+    pub fn rows(&self) -> i32 {
+        self.rows
+    }
+
+    /// Returns the number of columns of the viewed matrix
+    pub fn cols(&self) -> i32 {
+        self.cols
+    }
+
+    /// Returns the leading dimension of the stored data
+    pub fn leading_dim(&self) -> i32 {
+        self.leading_dim
+    }
+
+    /// Returns the operation hipBLAS applies to the stored data
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// Returns the transpose of this view, undoing a previous transpose.
+    pub fn transpose_view(&self) -> MatrixView<'a, T> {
+        let operation = match self.operation {
+            Operation::None => Operation::Transpose,
+            Operation::Transpose | Operation::Conjugate => Operation::None,
+        };
+        MatrixView {
+            rows: self.cols,
+            cols: self.rows,
+            operation,
+            ..*self
+        }
+    }
+}
+
+impl<T: GemmDatatype> MatrixView<'_, T> {
+    /// Computes `c = op(self) * op(other) + beta * c`, writing into an existing matrix.
+    ///
+    /// See [`Matrix::gemm_into`]. Transposed views are multiplied without
+    /// transposing their data.
+    ///
+    /// # Errors
+    /// Returns `BlasError` with `BlasStatus::InvalidValue` if the shapes do not
+    /// line up.
+    pub fn gemm_into(
+        &self,
+        other: &MatrixView<T>,
+        beta: T,
+        c: &mut Matrix<T>,
+        handle: &BlasHandle,
+    ) -> BlasResult<()> {
+        if self.cols != other.rows || c.rows != self.rows || c.cols != other.cols {
+            log::error!(
+                "gemm_into shape mismatch: {}x{} * {}x{} into {}x{}",
+                self.rows,
+                self.cols,
+                other.rows,
+                other.cols,
+                c.rows,
+                c.cols
+            );
+            return Err(BlasError::from_status(BlasStatus::InvalidValue));
+        }
+
+        gemm(
+            handle,
+            self.operation,
+            other.operation,
+            self.rows,
+            other.cols,
+            self.cols,
+            &T::ONE,
+            self.data,
+            self.leading_dim,
+            other.data,
+            other.leading_dim,
+            &beta,
+            &mut c.data,
+            c.leading_dim,
+        )
+    }
+}
+
+impl<T: GemmDatatype> Matrix<T> {
+    /// Computes `c = self * other + beta * c`, writing into an existing matrix.
+    ///
+    /// Reusing `c` avoids an allocation per product and, with a non-zero
+    /// `beta`, accumulates several products into the same output.
+    ///
+    /// # Arguments
+    /// * `other` - The right-hand operand
+    /// * `beta` - Scalar applied to the current contents of `c`
+    /// * `c` - The output matrix, of shape `self.rows() x other.cols()`
+    /// * `handle` - The hipBLAS handle to run the product on
+    ///
+    /// # Errors
+    /// Returns `BlasError` with `BlasStatus::InvalidValue` if the shapes do not
+    /// line up.
+    pub fn gemm_into(
+        &self,
+        other: &Matrix<T>,
+        beta: T,
+        c: &mut Matrix<T>,
+        handle: &BlasHandle,
+    ) -> BlasResult<()> {
+        self.as_view().gemm_into(&other.as_view(), beta, c, handle)
+    }
+}
+
+impl<T: GeamDatatype> Matrix<T> {
+    /// Transposes a square matrix in place.
+    ///
+    /// The transpose is written into a temporary allocation with [`geam`], which
+    /// then replaces the matrix data. Only square matrices can be transposed in
+    /// place, use [`Matrix::transpose_view`] to read a non-square matrix transposed.
+    ///
+    /// # Arguments
+    /// * `handle` - The hipBLAS handle to run the transpose on
+    ///
+    /// # Errors
+    /// Returns `BlasError` with `BlasStatus::InvalidValue` if the matrix is not
+    /// square.
+    pub fn transpose_in_place(&mut self, handle: &BlasHandle) -> BlasResult<()> {
+        if self.rows != self.cols {
+            log::error!(
+                "transpose_in_place needs a square matrix, got {}x{}",
+                self.rows,
+                self.cols
+            );
+            return Err(BlasError::from_status(BlasStatus::InvalidValue));
+        }
+
+        // B is not read with a zero beta, A is passed again to keep it valid
+        let mut transposed = Matrix::<T>::new(self.rows, self.cols)?;
+        geam(
+            handle,
+            Operation::Transpose,
+            Operation::None,
+            self.rows,
+            self.cols,
+            &T::ONE,
+            &self.data,
+            self.leading_dim,
+            &T::ZERO,
+            &self.data,
+            self.leading_dim,
+            &mut transposed.data,
+            transposed.leading_dim,
+        )?;
+
+        std::mem::swap(self, &mut transposed);
+        Ok(())
+    }
+}
+
+impl<T: Nrm2Datatype> Matrix<T> {
+    /// Computes the Frobenius norm, the square root of the sum of the squared
+    /// magnitudes of all elements.
+    ///
+    /// This is the 2-norm of the matrix data viewed as one long vector, so it is
+    /// computed with a single [`nrm2`] over the whole buffer.
+    ///
+    /// # Arguments
+    /// * `handle` - The hipBLAS handle to compute the norm on
+    pub fn frobenius_norm(&self, handle: &BlasHandle) -> BlasResult<T::Real> {
+        nrm2(handle, self.data.size() as i32, &self.data, 1)
+    }
+}
+
+impl<T: ScalDatatype> Matrix<T> {
+    /// Scales every element in place: A = alpha * A
+    ///
+    /// The data is scaled with a single [`scal`] over the whole buffer, so this
+    /// only applies to densely packed matrices, whose leading dimension equals
+    /// the number of rows. Padding between columns would be scaled as well.
+    ///
+    /// # Arguments
+    /// * `alpha` - Scalar multiplier
+    /// * `handle` - The hipBLAS handle to run the scaling on
+    ///
+    /// # Errors
+    /// Returns `BlasError` with `BlasStatus::InvalidValue` if the matrix is not
+    /// densely packed.
+    pub fn scale(&mut self, alpha: T, handle: &BlasHandle) -> BlasResult<()> {
+        if self.leading_dim != self.rows {
+            log::error!(
+                "scale needs a densely packed matrix, got {} rows with a leading dimension of {}",
+                self.rows,
+                self.leading_dim
+            );
+            return Err(BlasError::from_status(BlasStatus::InvalidValue));
+        }
+
+        scal(handle, self.data.size() as i32, &alpha, &mut self.data, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_host_round_trip() {
+        let data = vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let matrix = Matrix::from_host(2, 3, &data).unwrap();
+        assert_eq!(matrix.rows(), 2);
+        assert_eq!(matrix.cols(), 3);
+        assert_eq!(matrix.leading_dim(), 2);
+        assert_eq!(matrix.to_host().unwrap(), data);
+
+        // Wrong number of elements
+        assert!(Matrix::from_host(2, 2, &data).is_err());
+    }
+
+    #[test]
+    fn test_download_tile() {
+        // Column-major 4x4 matrix with element (r, c) = 10 * r + c
+        let data: Vec<f32> = (0..16).map(|i| (10 * (i % 4) + i / 4) as f32).collect();
+        let matrix = Matrix::from_host(4, 4, &data).unwrap();
+
+        let tile = matrix.download_tile(2, 2, 2, 2).unwrap();
+        assert_eq!(tile, [22.0, 23.0, 32.0, 33.0]);
+
+        // A non-square tile comes back row by row as well
+        let tile = matrix.download_tile(1, 0, 2, 3).unwrap();
+        assert_eq!(tile, [10.0, 11.0, 12.0, 20.0, 21.0, 22.0]);
+
+        let result = matrix.download_tile(3, 3, 2, 2);
+        assert_eq!(result.unwrap_err().status, HipStatus::InvalidValue);
+    }
+
+    #[test]
+    fn test_gemm_into_accumulates() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major 2x2 operands
+        // A1 = [1 3]  B1 = [1 0]  A2 = [2 0]  B2 = [1 1]
+        //      [2 4]       [0 1]       [0 2]       [1 1]
+        let a1 = Matrix::from_host(2, 2, &[1.0f32, 2.0, 3.0, 4.0]).unwrap();
+        let b1 = Matrix::from_host(2, 2, &[1.0f32, 0.0, 0.0, 1.0]).unwrap();
+        let a2 = Matrix::from_host(2, 2, &[2.0f32, 0.0, 0.0, 2.0]).unwrap();
+        let b2 = Matrix::from_host(2, 2, &[1.0f32, 1.0, 1.0, 1.0]).unwrap();
+        let mut c = Matrix::<f32>::new(2, 2).unwrap();
+
+        // C = A1 * B1, then C = A2 * B2 + C
+        a1.gemm_into(&b1, 0.0, &mut c, &handle).unwrap();
+        a2.gemm_into(&b2, 1.0, &mut c, &handle).unwrap();
+
+        // A1 * B1 = A1, A2 * B2 = all 2s
+        assert_eq!(c.to_host().unwrap(), vec![3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_transpose_view_gemm() {
+        let handle = BlasHandle::new().unwrap();
+
+        // A is 3x2, so A^T * B is 2x3 * 3x2
+        let a_data = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let b_data = [1.0f32, 0.0, 2.0, -1.0, 1.0, 0.5];
+        let a = Matrix::from_host(3, 2, &a_data).unwrap();
+        let b = Matrix::from_host(3, 2, &b_data).unwrap();
+
+        let a_t = a.transpose_view();
+        assert_eq!((a_t.rows(), a_t.cols()), (2, 3));
+        assert_eq!(a_t.operation(), Operation::Transpose);
+        assert_eq!(a_t.transpose_view().operation(), Operation::None);
+
+        let mut c = Matrix::<f32>::new(2, 2).unwrap();
+        a_t.gemm_into(&b.as_view(), 0.0, &mut c, &handle).unwrap();
+
+        // Reference: physically transpose A, then multiply without operations
+        let mut transposed = [0.0f32; 6];
+        for row in 0..3 {
+            for col in 0..2 {
+                transposed[col + row * 2] = a_data[row + col * 3];
+            }
+        }
+        let a_transposed = Matrix::from_host(2, 3, &transposed).unwrap();
+        let mut expected = Matrix::<f32>::new(2, 2).unwrap();
+        a_transposed
+            .gemm_into(&b, 0.0, &mut expected, &handle)
+            .unwrap();
+
+        assert_eq!(c.to_host().unwrap(), expected.to_host().unwrap());
+    }
+
+    #[test]
+    fn test_transpose_in_place() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major A = [1 4 7]
+        //                  [2 5 8]
+        //                  [3 6 9]
+        let data: Vec<f32> = (1..=9).map(|x| x as f32).collect();
+        let mut a = Matrix::from_host(3, 3, &data).unwrap();
+
+        a.transpose_in_place(&handle).unwrap();
+
+        // A^T = [1 2 3]
+        //       [4 5 6]
+        //       [7 8 9]
+        assert_eq!(
+            a.to_host().unwrap(),
+            vec![1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]
+        );
+
+        let mut b = Matrix::<f32>::new(2, 3).unwrap();
+        let result = b.transpose_in_place(&handle);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+
+    #[test]
+    fn test_frobenius_norm() {
+        let handle = BlasHandle::new().unwrap();
+
+        // [1 2]
+        // [2 1]
+        let a = Matrix::from_host(2, 2, &[1.0f32, 2.0, 2.0, 1.0]).unwrap();
+
+        let norm = a.frobenius_norm(&handle).unwrap();
+        assert!((norm - 10.0f32.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_scale() {
+        let handle = BlasHandle::new().unwrap();
+        let mut a = Matrix::from_host(2, 2, &[1.0f32, -2.0, 3.0, 5.0]).unwrap();
+
+        a.scale(0.5, &handle).unwrap();
+        assert_eq!(a.to_host().unwrap(), [0.5, -1.0, 1.5, 2.5]);
+    }
+
+    #[test]
+    fn test_gemm_into_shape_mismatch() {
+        let handle = BlasHandle::new().unwrap();
+        let a = Matrix::<f32>::new(2, 3).unwrap();
+        let b = Matrix::<f32>::new(2, 2).unwrap();
+        let mut c = Matrix::<f32>::new(2, 2).unwrap();
+
+        let result = a.gemm_into(&b, 0.0, &mut c, &handle);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
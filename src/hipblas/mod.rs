@@ -1,12 +1,48 @@
+mod axpy;
 mod blas_call;
+mod checks;
+mod copy;
+mod dgmm;
+mod dot;
+mod geam;
 mod gemm;
+mod gemv;
+mod ger;
 mod handle;
+mod matrix;
+mod pipeline;
+mod reduction;
 mod result;
+mod rot;
+mod scal;
+mod symm;
+mod symv;
+mod trmm;
+mod trsm;
+mod trsv;
 mod types;
 
+pub use axpy::*;
 #[allow(unused_imports)]
 pub use blas_call::*;
+pub(crate) use checks::{check_matrix_len, check_vector_len};
+pub use copy::*;
+pub use dgmm::*;
+pub use dot::*;
+pub use geam::*;
 pub use gemm::*;
+pub use gemv::*;
+pub use ger::*;
 pub use handle::*;
+pub use matrix::*;
+pub use pipeline::*;
+pub use reduction::*;
 pub use result::*;
+pub use rot::*;
+pub use scal::*;
+pub use symm::*;
+pub use symv::*;
+pub use trmm::*;
+pub use trsm::*;
+pub use trsv::*;
 pub use types::*;
@@ -0,0 +1,219 @@
+use super::{gemm, BlasError, BlasHandle, BlasResult, BlasStatus, GemmDatatype, Operation};
+use crate::{HostMemory, MemoryPointer, Stream};
+
+/// Number of slots in flight in [`gemm_pipeline`].
+const PIPELINE_DEPTH: usize = 3;
+
+/// Pinned staging buffers, device buffers and the stream of one pipeline slot.
+struct Slot<T> {
+    stream: Stream,
+    host_a: HostMemory<T>,
+    host_b: HostMemory<T>,
+    host_c: HostMemory<T>,
+    a: MemoryPointer<T>,
+    b: MemoryPointer<T>,
+    c: MemoryPointer<T>,
+}
+
+impl<T: Copy + Default> Slot<T> {
+    fn new(a_len: usize, b_len: usize, c_len: usize) -> BlasResult<Self> {
+        Ok(Self {
+            stream: Stream::create()?,
+            host_a: HostMemory::alloc(a_len)?,
+            host_b: HostMemory::alloc(b_len)?,
+            host_c: HostMemory::alloc(c_len)?,
+            a: MemoryPointer::alloc(a_len)?,
+            b: MemoryPointer::alloc(b_len)?,
+            c: MemoryPointer::alloc(c_len)?,
+        })
+    }
+
+    /// Waits for the slot's previous product and returns it.
+    fn finish(&self) -> BlasResult<Vec<T>> {
+        self.stream.synchronize()?;
+        Ok(self.host_c.as_slice().to_vec())
+    }
+}
+
+impl<T> Drop for Slot<T> {
+    fn drop(&mut self) {
+        // Copies may still be in flight if the pipeline stopped on an error
+        if let Err(e) = self.stream.synchronize() {
+            log::error!("Failed to synchronize pipeline stream: {:?}", e);
+        }
+    }
+}
+
+/// Computes `C[i] = A[i] * B[i]` for a sequence of column-major host matrices,
+/// overlapping transfers with computation.
+///
+/// Each product is uploaded, computed and downloaded on one of three streams
+/// through pinned staging buffers, so that while product `i` is being computed
+/// the result of product `i - 1` is downloaded and the inputs of product `i + 1`
+/// are uploaded. Only three sets of buffers are allocated on the device, so the
+/// total input may be larger than device memory.
+///
+/// The handle's stream is restored before returning.
+///
+/// # Arguments
+/// * `handle` - The hipBLAS handle to run the products on
+/// * `m` - Rows of each `A` and `C`
+/// * `n` - Columns of each `B` and `C`
+/// * `k` - Columns of each `A` and rows of each `B`
+/// * `inputs` - Pairs of `A` (`m * k` elements) and `B` (`k * n` elements)
+///
+/// # Returns
+/// * `Ok(Vec<Vec<T>>)` - The `m * n` column-major products, in input order
+/// * `Err(BlasError)` - If an input has the wrong length or an operation failed
+pub fn gemm_pipeline<T>(
+    handle: &BlasHandle,
+    m: i32,
+    n: i32,
+    k: i32,
+    inputs: &[(&[T], &[T])],
+) -> BlasResult<Vec<Vec<T>>>
+where
+    T: GemmDatatype + Copy + Default,
+{
+    if m <= 0 || n <= 0 || k <= 0 {
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    let (a_len, b_len, c_len) = (
+        m as usize * k as usize,
+        k as usize * n as usize,
+        m as usize * n as usize,
+    );
+    if let Some(i) = inputs
+        .iter()
+        .position(|(a, b)| a.len() != a_len || b.len() != b_len)
+    {
+        log::error!(
+            "gemm_pipeline: input {} does not match {}x{}x{}",
+            i,
+            m,
+            n,
+            k
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+
+    let previous_stream = handle.stream()?;
+    let result = run_pipeline(handle, m, n, k, inputs, (a_len, b_len, c_len));
+    handle.set_stream(&previous_stream)?;
+    result
+}
+
+fn run_pipeline<T>(
+    handle: &BlasHandle,
+    m: i32,
+    n: i32,
+    k: i32,
+    inputs: &[(&[T], &[T])],
+    (a_len, b_len, c_len): (usize, usize, usize),
+) -> BlasResult<Vec<Vec<T>>>
+where
+    T: GemmDatatype + Copy + Default,
+{
+    let depth = PIPELINE_DEPTH.min(inputs.len());
+    let mut slots = (0..depth)
+        .map(|_| Slot::new(a_len, b_len, c_len))
+        .collect::<BlasResult<Vec<_>>>()?;
+
+    let mut results = Vec::with_capacity(inputs.len());
+    for (i, (a, b)) in inputs.iter().enumerate() {
+        let slot = &mut slots[i % depth];
+
+        // The slot's staging buffers are reused, so collect its previous product first
+        if i >= depth {
+            results.push(slot.finish()?);
+        }
+
+        slot.host_a.as_mut_slice().copy_from_slice(a);
+        slot.host_b.as_mut_slice().copy_from_slice(b);
+        // The staging buffers are only touched again after `finish` synchronized the slot
+        unsafe {
+            slot.a.copy_from_host_async(&slot.host_a, &slot.stream)?;
+            slot.b.copy_from_host_async(&slot.host_b, &slot.stream)?;
+        }
+
+        handle.set_stream(&slot.stream)?;
+        gemm(
+            handle,
+            Operation::None,
+            Operation::None,
+            m,
+            n,
+            k,
+            &T::ONE,
+            &slot.a,
+            m,
+            &slot.b,
+            k,
+            &T::default(),
+            &mut slot.c,
+            m,
+        )?;
+
+        unsafe {
+            slot.c.copy_to_host_async(&mut slot.host_c, &slot.stream)?;
+        }
+    }
+
+    // Drain the products still in flight, oldest first
+    for i in inputs.len().saturating_sub(depth)..inputs.len() {
+        results.push(slots[i % depth].finish()?);
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Column-major reference product on the host.
+    fn reference_gemm(m: usize, n: usize, k: usize, a: &[f32], b: &[f32]) -> Vec<f32> {
+        let mut c = vec![0.0; m * n];
+        for j in 0..n {
+            for i in 0..m {
+                c[i + j * m] = (0..k).map(|p| a[i + p * m] * b[p + j * k]).sum();
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn test_gemm_pipeline() {
+        let handle = BlasHandle::new().unwrap();
+        let (m, n, k) = (3, 2, 4);
+
+        let matrices: Vec<(Vec<f32>, Vec<f32>)> = (0..8)
+            .map(|i| {
+                let a = (0..m * k).map(|x| (x + i) as f32).collect();
+                let b = (0..k * n).map(|x| (x * i) as f32 - 1.0).collect();
+                (a, b)
+            })
+            .collect();
+        let inputs: Vec<(&[f32], &[f32])> = matrices
+            .iter()
+            .map(|(a, b)| (a.as_slice(), b.as_slice()))
+            .collect();
+
+        let results = gemm_pipeline(&handle, m as i32, n as i32, k as i32, &inputs).unwrap();
+
+        assert_eq!(results.len(), 8);
+        for ((a, b), c) in matrices.iter().zip(&results) {
+            assert_eq!(c, &reference_gemm(m, n, k, a, b));
+        }
+    }
+
+    #[test]
+    fn test_gemm_pipeline_wrong_input_len() {
+        let handle = BlasHandle::new().unwrap();
+        let a = vec![0.0f32; 4];
+        let b = vec![0.0f32; 3];
+
+        let result = gemm_pipeline(&handle, 2, 2, 2, &[(&a, &b)]);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
@@ -0,0 +1,940 @@
+use super::{check_device, check_vector_len, BlasError, BlasHandle, BlasResult, BlasStatus};
+use crate::result::ResultExt;
+use crate::{sys, Complex32, HipResult, MemoryPointer};
+
+/// Trait for real types supported by strided batched level-1 reductions
+pub trait ReductionDatatype: Copy + Default {
+    /// Calls the appropriate HIPBLAS ASUM strided batched function for this datatype
+    ///
+    /// # Safety
+    /// `x` must be a device pointer to `batch_count` strided vectors and `result`
+    /// must be a host pointer to `batch_count` elements.
+    unsafe fn hipblas_asum_strided_batched(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        batch_count: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t;
+
+    /// Calls the appropriate HIPBLAS NRM2 strided batched function for this datatype
+    ///
+    /// # Safety
+    /// Same requirements as [`ReductionDatatype::hipblas_asum_strided_batched`].
+    unsafe fn hipblas_nrm2_strided_batched(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        batch_count: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t;
+
+    /// Calls the appropriate HIPBLAS IAMAX strided batched function for this datatype
+    ///
+    /// # Safety
+    /// Same requirements as [`ReductionDatatype::hipblas_asum_strided_batched`].
+    unsafe fn hipblas_iamax_strided_batched(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        batch_count: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl ReductionDatatype for f32 {
+    unsafe fn hipblas_asum_strided_batched(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        batch_count: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSasumStridedBatched(
+            handle,
+            n,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+            result
+        ))
+    }
+
+    unsafe fn hipblas_nrm2_strided_batched(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        batch_count: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSnrm2StridedBatched(
+            handle,
+            n,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+            result
+        ))
+    }
+
+    unsafe fn hipblas_iamax_strided_batched(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        batch_count: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIsamaxStridedBatched(
+            handle,
+            n,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+            result
+        ))
+    }
+}
+
+impl ReductionDatatype for f64 {
+    unsafe fn hipblas_asum_strided_batched(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        batch_count: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDasumStridedBatched(
+            handle,
+            n,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+            result
+        ))
+    }
+
+    unsafe fn hipblas_nrm2_strided_batched(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        batch_count: i32,
+        result: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDnrm2StridedBatched(
+            handle,
+            n,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+            result
+        ))
+    }
+
+    unsafe fn hipblas_iamax_strided_batched(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        stride_x: i64,
+        batch_count: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIdamaxStridedBatched(
+            handle,
+            n,
+            x,
+            incx,
+            stride_x,
+            batch_count,
+            result
+        ))
+    }
+}
+
+/// Trait for types supported by ASUM operations
+pub trait AsumDatatype {
+    /// The real scalar type of the sum, `f32` or `f64`
+    type Real: Copy + Default;
+
+    /// Calls the appropriate HIPBLAS ASUM function for this datatype
+    ///
+    /// # Safety
+    /// `x` must be a device pointer to at least `1 + (n - 1) * incx` elements and
+    /// `result` must be a host pointer valid for a write.
+    unsafe fn hipblas_asum(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl AsumDatatype for f32 {
+    type Real = f32;
+
+    unsafe fn hipblas_asum(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSasum(handle, n, x, incx, result))
+    }
+}
+
+impl AsumDatatype for f64 {
+    type Real = f64;
+
+    unsafe fn hipblas_asum(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDasum(handle, n, x, incx, result))
+    }
+}
+
+impl AsumDatatype for Complex32 {
+    type Real = f32;
+
+    unsafe fn hipblas_asum(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasScasum(
+            handle,
+            n,
+            x as *const sys::hipblasComplex,
+            incx,
+            result
+        ))
+    }
+}
+
+impl AsumDatatype for sys::hipblasDoubleComplex {
+    type Real = f64;
+
+    unsafe fn hipblas_asum(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDzasum(handle, n, x, incx, result))
+    }
+}
+
+/// Computes the sum of absolute values of a vector.
+///
+/// For complex vectors this is the sum of `|re| + |im|` over the elements, as
+/// defined by BLAS, and is returned as the matching real type.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x
+/// * `x` - Input vector x
+/// * `incx` - Stride between consecutive elements of x
+///
+/// # Returns
+/// * `Ok(T::Real)` - The sum, zero if `n` is zero
+/// * `Err(BlasError)` - If `x` is too short for `n` elements or the operation failed
+pub fn asum<T: AsumDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+) -> BlasResult<T::Real> {
+    check_device(handle, "x", x)?;
+
+    check_vector_len("x", n, x, incx)?;
+
+    if n == 0 {
+        return Ok(T::Real::default());
+    }
+
+    // Initialized so that a failed call never exposes uninitialized memory
+    let mut result = T::Real::default();
+    unsafe {
+        let code = T::hipblas_asum(handle.handle(), n, x.as_pointer(), incx, &mut result);
+        (result, code).to_result()
+    }
+}
+
+/// Trait for types supported by IAMAX and IAMIN operations
+pub trait IamaxDatatype {
+    /// Calls the appropriate HIPBLAS IAMAX function for this datatype
+    ///
+    /// # Safety
+    /// `x` must be a device pointer to at least `1 + (n - 1) * incx` elements and
+    /// `result` must be a host pointer valid for a write.
+    unsafe fn hipblas_iamax(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t;
+
+    /// Calls the appropriate HIPBLAS IAMIN function for this datatype
+    ///
+    /// # Safety
+    /// Same requirements as [`IamaxDatatype::hipblas_iamax`].
+    unsafe fn hipblas_iamin(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl IamaxDatatype for f32 {
+    unsafe fn hipblas_iamax(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIsamax(handle, n, x, incx, result))
+    }
+
+    unsafe fn hipblas_iamin(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIsamin(handle, n, x, incx, result))
+    }
+}
+
+impl IamaxDatatype for f64 {
+    unsafe fn hipblas_iamax(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIdamax(handle, n, x, incx, result))
+    }
+
+    unsafe fn hipblas_iamin(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIdamin(handle, n, x, incx, result))
+    }
+}
+
+impl IamaxDatatype for Complex32 {
+    unsafe fn hipblas_iamax(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIcamax(
+            handle,
+            n,
+            x as *const sys::hipblasComplex,
+            incx,
+            result
+        ))
+    }
+
+    unsafe fn hipblas_iamin(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIcamin(
+            handle,
+            n,
+            x as *const sys::hipblasComplex,
+            incx,
+            result
+        ))
+    }
+}
+
+impl IamaxDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_iamax(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIzamax(handle, n, x, incx, result))
+    }
+
+    unsafe fn hipblas_iamin(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasIzamin(handle, n, x, incx, result))
+    }
+}
+
+/// Converts a 1-based hipBLAS index to a 0-based one.
+///
+/// hipBLAS returns 0 when there is no element to pick, e.g. for `n == 0`.
+fn to_zero_based(index: i32) -> BlasResult<usize> {
+    if index <= 0 {
+        log::error!("hipBLAS returned no index, the vector is empty");
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    Ok(index as usize - 1)
+}
+
+/// Finds the first element with the largest magnitude in a vector.
+///
+/// hipBLAS returns a 1-based index, which is converted to a **0-based** index
+/// here, so the result can be used to index Rust slices directly. For complex
+/// vectors the magnitude is `|re| + |im|`, as defined by BLAS.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x
+/// * `x` - Input vector x
+/// * `incx` - Stride between consecutive elements of x
+///
+/// # Returns
+/// * `Ok(usize)` - The 0-based position of the element within the strided vector
+/// * `Err(BlasError)` - If the vector is empty, `x` is too short for `n` elements,
+///   or the operation failed
+pub fn iamax<T: IamaxDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+) -> BlasResult<usize> {
+    check_device(handle, "x", x)?;
+
+    check_vector_len("x", n, x, incx)?;
+
+    let mut index = 0;
+    let index: BlasResult<i32> = unsafe {
+        let code = T::hipblas_iamax(handle.handle(), n, x.as_pointer(), incx, &mut index);
+        (index, code).to_result()
+    };
+    to_zero_based(index?)
+}
+
+/// Finds the first element with the smallest magnitude in a vector.
+///
+/// Like [`iamax`], the 1-based hipBLAS result is converted to a **0-based** index.
+///
+/// # Returns
+/// * `Ok(usize)` - The 0-based position of the element within the strided vector
+/// * `Err(BlasError)` - If the vector is empty, `x` is too short for `n` elements,
+///   or the operation failed
+pub fn iamin<T: IamaxDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+) -> BlasResult<usize> {
+    check_device(handle, "x", x)?;
+
+    check_vector_len("x", n, x, incx)?;
+
+    let mut index = 0;
+    let index: BlasResult<i32> = unsafe {
+        let code = T::hipblas_iamin(handle.handle(), n, x.as_pointer(), incx, &mut index);
+        (index, code).to_result()
+    };
+    to_zero_based(index?)
+}
+
+/// Downloads the element at `index` of a device vector.
+fn download_element(x: &MemoryPointer<f32>, index: usize) -> BlasResult<f32> {
+    let mut value = 0.0f32;
+    let value: HipResult<f32> = unsafe {
+        let code = record_call!(sys::hipMemcpy(
+            &mut value as *mut f32 as *mut std::ffi::c_void,
+            x.as_pointer().add(index) as *const std::ffi::c_void,
+            std::mem::size_of::<f32>(),
+            sys::hipMemcpyKind_hipMemcpyDeviceToHost,
+        ));
+        (value, code).to_result()
+    };
+    Ok(value?)
+}
+
+/// Returns the length of `x` as a hipBLAS element count.
+fn element_count(name: &str, x: &MemoryPointer<f32>) -> BlasResult<i32> {
+    i32::try_from(x.size()).map_err(|_| {
+        log::error!("{}: {} elements do not fit in an i32", name, x.size());
+        BlasError::from_status(BlasStatus::InvalidValue)
+    })
+}
+
+/// Returns the largest element of a vector.
+///
+/// The element with the largest magnitude is found with [`iamax`] and only that
+/// element is downloaded. If it is non-negative it is the maximum. Otherwise the
+/// vector is downloaded and the maximum is found on the host, so for `[1, -5, 3]`
+/// the result is `3`.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `x` - Input vector x, all elements are compared
+///
+/// # Returns
+/// * `Ok(f32)` - The largest element
+/// * `Err(BlasError)` - If the vector is empty or longer than `i32::MAX`
+///   (`BlasStatus::InvalidValue`), or the operation failed
+pub fn reduce_max(handle: &BlasHandle, x: &MemoryPointer<f32>) -> BlasResult<f32> {
+    let n = element_count("reduce_max", x)?;
+    let index = iamax(handle, n, x, 1)?;
+    let value = download_element(x, index)?;
+    if value >= 0.0 {
+        return Ok(value);
+    }
+
+    // Every element is at least `value`, but one closer to zero may exist
+    let mut host = vec![0.0f32; x.size()];
+    x.copy_to_host(&mut host)?;
+    Ok(host.into_iter().fold(value, f32::max))
+}
+
+/// Returns the element with the smallest magnitude in a vector.
+///
+/// Unlike [`reduce_max`], elements are compared by absolute value as defined by
+/// [`iamin`]: for `[1, -5, 3]` the result is `1`, and for `[-2, 3]` it is `-2`.
+/// The element is returned with its sign. Only the selected element is
+/// downloaded from the device.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `x` - Input vector x, all elements are compared
+///
+/// # Returns
+/// * `Ok(f32)` - The first element with the smallest absolute value
+/// * `Err(BlasError)` - If the vector is empty or longer than `i32::MAX`
+///   (`BlasStatus::InvalidValue`), or the operation failed
+pub fn reduce_min(handle: &BlasHandle, x: &MemoryPointer<f32>) -> BlasResult<f32> {
+    let n = element_count("reduce_min", x)?;
+    let index = iamin(handle, n, x, 1)?;
+    download_element(x, index)
+}
+
+/// Trait for types supported by NRM2 operations
+pub trait Nrm2Datatype {
+    /// The real scalar type of the norm, `f32` or `f64`
+    type Real: Copy + Default;
+
+    /// Calls the appropriate HIPBLAS NRM2 function for this datatype
+    ///
+    /// # Safety
+    /// `x` must be a device pointer to at least `1 + (n - 1) * incx` elements and
+    /// `result` must be a host pointer valid for a write.
+    unsafe fn hipblas_nrm2(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl Nrm2Datatype for f32 {
+    type Real = f32;
+
+    unsafe fn hipblas_nrm2(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSnrm2(handle, n, x, incx, result))
+    }
+}
+
+impl Nrm2Datatype for f64 {
+    type Real = f64;
+
+    unsafe fn hipblas_nrm2(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDnrm2(handle, n, x, incx, result))
+    }
+}
+
+impl Nrm2Datatype for Complex32 {
+    type Real = f32;
+
+    unsafe fn hipblas_nrm2(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasScnrm2(
+            handle,
+            n,
+            x as *const sys::hipblasComplex,
+            incx,
+            result
+        ))
+    }
+}
+
+impl Nrm2Datatype for sys::hipblasDoubleComplex {
+    type Real = f64;
+
+    unsafe fn hipblas_nrm2(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *const Self,
+        incx: i32,
+        result: *mut Self::Real,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDznrm2(handle, n, x, incx, result))
+    }
+}
+
+/// Computes the Euclidean norm of a vector: ||x||
+///
+/// For complex vectors the norm is returned as the matching real type.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x
+/// * `x` - Input vector x
+/// * `incx` - Stride between consecutive elements of x
+///
+/// # Returns
+/// * `Ok(T::Real)` - The norm, zero if `n` is zero
+/// * `Err(BlasError)` - If `x` is too short for `n` elements or the operation failed
+pub fn nrm2<T: Nrm2Datatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+) -> BlasResult<T::Real> {
+    check_device(handle, "x", x)?;
+
+    check_vector_len("x", n, x, incx)?;
+
+    if n == 0 {
+        return Ok(T::Real::default());
+    }
+
+    // Initialized so that a failed call never exposes uninitialized memory
+    let mut result = T::Real::default();
+    unsafe {
+        let code = T::hipblas_nrm2(handle.handle(), n, x.as_pointer(), incx, &mut result);
+        (result, code).to_result()
+    }
+}
+
+/// Checks that `x` holds `batch_count` vectors of `n` elements with stride
+/// `incx`, spaced `stride_x` elements apart.
+fn check_strided_batch<T>(
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> BlasResult<()> {
+    if n < 0 || incx <= 0 || stride_x < 0 || batch_count < 0 {
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    if n == 0 || batch_count == 0 {
+        return Ok(());
+    }
+
+    let last_vector = (batch_count as u64 - 1) * stride_x as u64;
+    let vector_len = (n as u64 - 1) * incx as u64 + 1;
+    let required = last_vector + vector_len;
+    if required > x.size() as u64 {
+        log::error!(
+            "strided batch of {} vectors needs {} elements, but x holds {}",
+            batch_count,
+            required,
+            x.size()
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    Ok(())
+}
+
+/// Computes the sum of absolute values of each vector in a strided batch.
+///
+/// Vector `i` starts at element `i * stride_x` of `x`, so a contiguous batch of
+/// vectors of length `n` uses `stride_x = n`.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in each vector
+/// * `x` - Input vectors
+/// * `incx` - Stride between consecutive elements of a vector, must be positive
+/// * `stride_x` - Stride between the first elements of consecutive vectors
+/// * `batch_count` - Number of vectors
+///
+/// # Returns
+/// * `Ok(Vec<T>)` - One sum per vector
+/// * `Err(BlasError)` - If the batch does not fit in `x` or the operation failed
+pub fn asum_strided_batched<T: ReductionDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> BlasResult<Vec<T>> {
+    check_device(handle, "x", x)?;
+
+    check_strided_batch(n, x, incx, stride_x, batch_count)?;
+
+    let mut result = vec![T::default(); batch_count as usize];
+    unsafe {
+        let code = T::hipblas_asum_strided_batched(
+            handle.handle(),
+            n,
+            x.as_pointer(),
+            incx,
+            stride_x,
+            batch_count,
+            result.as_mut_ptr(),
+        );
+        (result, code).to_result()
+    }
+}
+
+/// Computes the Euclidean norm of each vector in a strided batch.
+///
+/// See [`asum_strided_batched`] for the layout of the batch.
+///
+/// # Returns
+/// * `Ok(Vec<T>)` - One norm per vector
+/// * `Err(BlasError)` - If the batch does not fit in `x` or the operation failed
+pub fn nrm2_strided_batched<T: ReductionDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> BlasResult<Vec<T>> {
+    check_device(handle, "x", x)?;
+
+    check_strided_batch(n, x, incx, stride_x, batch_count)?;
+
+    let mut result = vec![T::default(); batch_count as usize];
+    unsafe {
+        let code = T::hipblas_nrm2_strided_batched(
+            handle.handle(),
+            n,
+            x.as_pointer(),
+            incx,
+            stride_x,
+            batch_count,
+            result.as_mut_ptr(),
+        );
+        (result, code).to_result()
+    }
+}
+
+/// Finds the element with the largest absolute value in each vector of a strided batch.
+///
+/// See [`asum_strided_batched`] for the layout of the batch.
+///
+/// # Returns
+/// * `Ok(Vec<i32>)` - One 1-based index per vector, as returned by hipBLAS
+/// * `Err(BlasError)` - If the batch does not fit in `x` or the operation failed
+pub fn iamax_strided_batched<T: ReductionDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    stride_x: i64,
+    batch_count: i32,
+) -> BlasResult<Vec<i32>> {
+    check_device(handle, "x", x)?;
+
+    check_strided_batch(n, x, incx, stride_x, batch_count)?;
+
+    let mut result = vec![0; batch_count as usize];
+    unsafe {
+        let code = T::hipblas_iamax_strided_batched(
+            handle.handle(),
+            n,
+            x.as_pointer(),
+            incx,
+            stride_x,
+            batch_count,
+            result.as_mut_ptr(),
+        );
+        (result, code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sasum() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(5).unwrap();
+        x.copy_from_host(&[1.0, -2.0, 3.0, -4.0, 5.0]).unwrap();
+
+        assert_eq!(asum(&handle, 5, &x, 1).unwrap(), 15.0);
+        assert_eq!(asum(&handle, 0, &x, 1).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_scasum() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<Complex32>::alloc(2).unwrap();
+        x.copy_from_host(&[Complex32::new(1.0, -2.0), Complex32::new(-3.0, 4.0)])
+            .unwrap();
+
+        let sum: f32 = asum(&handle, 2, &x, 1).unwrap();
+        assert_eq!(sum, 10.0);
+    }
+
+    #[test]
+    fn test_iamax_iamin() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(5).unwrap();
+        x.copy_from_host(&[1.0, -2.0, 3.0, -4.0, 5.0]).unwrap();
+
+        // 0-based, hipBLAS itself returns 5 and 1
+        assert_eq!(iamax(&handle, 5, &x, 1).unwrap(), 4);
+        assert_eq!(iamin(&handle, 5, &x, 1).unwrap(), 0);
+
+        // Only the first four elements, -4.0 has the largest magnitude
+        assert_eq!(iamax(&handle, 4, &x, 1).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_reduce_max_min() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(3).unwrap();
+        x.copy_from_host(&[1.0, -5.0, 3.0]).unwrap();
+
+        // The signed maximum, and the minimum by magnitude
+        assert_eq!(reduce_max(&handle, &x).unwrap(), 3.0);
+        assert_eq!(reduce_min(&handle, &x).unwrap(), 1.0);
+
+        x.copy_from_host(&[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(reduce_max(&handle, &x).unwrap(), 3.0);
+
+        x.copy_from_host(&[-4.0, -2.0, -3.0]).unwrap();
+        assert_eq!(reduce_max(&handle, &x).unwrap(), -2.0);
+        assert_eq!(reduce_min(&handle, &x).unwrap(), -2.0);
+    }
+
+    #[test]
+    fn test_iamax_empty() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(5).unwrap();
+
+        let result = iamax(&handle, 0, &x, 1);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+
+    #[test]
+    fn test_snrm2() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(2).unwrap();
+        x.copy_from_host(&[3.0, 4.0]).unwrap();
+
+        assert_eq!(nrm2(&handle, 2, &x, 1).unwrap(), 5.0);
+        assert_eq!(nrm2(&handle, 0, &x, 1).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_scnrm2() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<Complex32>::alloc(2).unwrap();
+        x.copy_from_host(&[Complex32::new(3.0, 4.0), Complex32::new(0.0, 0.0)])
+            .unwrap();
+
+        let norm: f32 = nrm2(&handle, 2, &x, 1).unwrap();
+        assert_eq!(norm, 5.0);
+    }
+
+    #[test]
+    fn test_asum_strided_batched() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(6).unwrap();
+        x.copy_from_host(&[1.0, -2.0, 3.0, -4.0, 5.0, 6.0]).unwrap();
+
+        let sums = asum_strided_batched(&handle, 3, &x, 1, 3, 2).unwrap();
+        assert_eq!(sums, vec![6.0, 15.0]);
+    }
+
+    #[test]
+    fn test_nrm2_and_iamax_strided_batched() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f64>::alloc(4).unwrap();
+        x.copy_from_host(&[3.0, -4.0, 0.0, 2.0]).unwrap();
+
+        let norms = nrm2_strided_batched(&handle, 2, &x, 1, 2, 2).unwrap();
+        assert_eq!(norms, vec![5.0, 2.0]);
+
+        let indices = iamax_strided_batched(&handle, 2, &x, 1, 2, 2).unwrap();
+        assert_eq!(indices, vec![2, 2]);
+    }
+
+    #[test]
+    fn test_strided_batched_out_of_bounds() {
+        let handle = BlasHandle::new().unwrap();
+        let x = MemoryPointer::<f32>::alloc(5).unwrap();
+
+        let result = asum_strided_batched(&handle, 3, &x, 1, 3, 2);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
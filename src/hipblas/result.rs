@@ -1,4 +1,5 @@
 use crate::result::{ResultExt, StatusCode};
+use crate::{HipError, HipStatus};
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -56,6 +57,22 @@ impl BlasError {
             code: status as u32,
         }
     }
+
+    /// Converts the error into the closest HIP runtime status, for functions
+    /// such as [`crate::blas_compute`] that report errors as [`HipError`].
+    pub(crate) fn into_hip_error(self) -> HipError {
+        log::debug!("Converting {:?} into a HIP error", self);
+        let status = match self.status {
+            BlasStatus::AllocationFailed => HipStatus::MemoryAllocation,
+            BlasStatus::InvalidValue
+            | BlasStatus::InvalidEnum
+            | BlasStatus::HandleIsNullPointer => HipStatus::InvalidValue,
+            BlasStatus::NotInitialized => HipStatus::NotInitialized,
+            BlasStatus::NotSupported | BlasStatus::ArchMismatch => HipStatus::NotSupported,
+            _ => HipStatus::Unknown,
+        };
+        HipError::from_status(status)
+    }
 }
 
 impl StatusCode for BlasError {
@@ -89,6 +106,22 @@ impl StatusCode for BlasError {
     }
 }
 
+/// Converts a HIP runtime error raised inside a hipBLAS workflow, e.g. by an
+/// allocation or copy, into the closest hipBLAS status.
+impl From<HipError> for BlasError {
+    fn from(error: HipError) -> Self {
+        log::debug!("Converting {:?} into a hipBLAS error", error);
+        let status = match error.status {
+            HipStatus::MemoryAllocation => BlasStatus::AllocationFailed,
+            HipStatus::InvalidValue => BlasStatus::InvalidValue,
+            HipStatus::NotInitialized | HipStatus::Deinitialized => BlasStatus::NotInitialized,
+            HipStatus::NotSupported => BlasStatus::NotSupported,
+            _ => BlasStatus::ExecutionFailed,
+        };
+        BlasError::from_status(status)
+    }
+}
+
 pub type BlasResult<T> = std::result::Result<T, BlasError>;
 
 impl<T> ResultExt<T, BlasError> for (T, u32) {
@@ -120,6 +153,27 @@ mod tests {
         assert_eq!(BlasStatus::from(999), BlasStatus::Unknown);
     }
 
+    #[test]
+    fn test_blas_error_from_hip_error() {
+        let error = BlasError::from(HipError::from_status(HipStatus::MemoryAllocation));
+        assert_eq!(error.status, BlasStatus::AllocationFailed);
+
+        let error = BlasError::from(HipError::from_status(HipStatus::InvalidDevice));
+        assert_eq!(error.status, BlasStatus::ExecutionFailed);
+    }
+
+    #[test]
+    fn test_blas_error_into_hip_error() {
+        let error = BlasError::from_status(BlasStatus::AllocationFailed).into_hip_error();
+        assert_eq!(error.status, HipStatus::MemoryAllocation);
+
+        let error = BlasError::from_status(BlasStatus::NotInitialized).into_hip_error();
+        assert_eq!(error.status, HipStatus::NotInitialized);
+
+        let error = BlasError::from_status(BlasStatus::ExecutionFailed).into_hip_error();
+        assert_eq!(error.status, HipStatus::Unknown);
+    }
+
     #[test]
     fn test_blas_error_new() {
         let error = BlasError::new(3);
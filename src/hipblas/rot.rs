@@ -0,0 +1,312 @@
+use super::{check_device, check_vector_len, BlasHandle, BlasResult, PointerMode};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by Givens rotation operations
+pub trait RotDatatype {
+    /// The real type of the cosine parameter `c`
+    type Real: Copy + Default;
+
+    /// Calls the appropriate HIPBLAS ROT function for this datatype
+    ///
+    /// # Safety
+    /// `x` and `y` must be device pointers to at least `1 + (n - 1) * |inc|` elements,
+    /// and `c` and `s` must be valid for reads in the handle's pointer mode.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_rot(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+        c: *const Self::Real,
+        s: *const Self,
+    ) -> sys::hipblasStatus_t;
+
+    /// Calls the appropriate HIPBLAS ROTG function for this datatype
+    ///
+    /// # Safety
+    /// All pointers must be valid for reads and writes in the handle's pointer mode.
+    unsafe fn hipblas_rotg(
+        handle: sys::hipblasHandle_t,
+        a: *mut Self,
+        b: *mut Self,
+        c: *mut Self::Real,
+        s: *mut Self,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl RotDatatype for f32 {
+    type Real = f32;
+
+    unsafe fn hipblas_rot(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+        c: *const Self::Real,
+        s: *const Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSrot(handle, n, x, incx, y, incy, c, s))
+    }
+
+    unsafe fn hipblas_rotg(
+        handle: sys::hipblasHandle_t,
+        a: *mut Self,
+        b: *mut Self,
+        c: *mut Self::Real,
+        s: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSrotg(handle, a, b, c, s))
+    }
+}
+
+impl RotDatatype for f64 {
+    type Real = f64;
+
+    unsafe fn hipblas_rot(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+        c: *const Self::Real,
+        s: *const Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDrot(handle, n, x, incx, y, incy, c, s))
+    }
+
+    unsafe fn hipblas_rotg(
+        handle: sys::hipblasHandle_t,
+        a: *mut Self,
+        b: *mut Self,
+        c: *mut Self::Real,
+        s: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDrotg(handle, a, b, c, s))
+    }
+}
+
+impl RotDatatype for Complex32 {
+    type Real = f32;
+
+    unsafe fn hipblas_rot(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+        c: *const Self::Real,
+        s: *const Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCrot(
+            handle,
+            n,
+            x as *mut sys::hipblasComplex,
+            incx,
+            y as *mut sys::hipblasComplex,
+            incy,
+            c,
+            s as *const sys::hipblasComplex,
+        ))
+    }
+
+    unsafe fn hipblas_rotg(
+        handle: sys::hipblasHandle_t,
+        a: *mut Self,
+        b: *mut Self,
+        c: *mut Self::Real,
+        s: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCrotg(
+            handle,
+            a as *mut sys::hipblasComplex,
+            b as *mut sys::hipblasComplex,
+            c,
+            s as *mut sys::hipblasComplex,
+        ))
+    }
+}
+
+impl RotDatatype for sys::hipblasDoubleComplex {
+    type Real = f64;
+
+    unsafe fn hipblas_rot(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        x: *mut Self,
+        incx: i32,
+        y: *mut Self,
+        incy: i32,
+        c: *const Self::Real,
+        s: *const Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZrot(handle, n, x, incx, y, incy, c, s))
+    }
+
+    unsafe fn hipblas_rotg(
+        handle: sys::hipblasHandle_t,
+        a: *mut Self,
+        b: *mut Self,
+        c: *mut Self::Real,
+        s: *mut Self,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZrotg(handle, a, b, c, s))
+    }
+}
+
+/// Runs `f` with the handle in host pointer mode, restoring the previous mode afterwards.
+///
+/// The scalars of [`rot`] and [`rotg`] are host references, which hipBLAS would
+/// misread as device pointers on a handle in [`PointerMode::Device`].
+fn with_host_pointer_mode<R>(
+    handle: &BlasHandle,
+    f: impl FnOnce() -> BlasResult<R>,
+) -> BlasResult<R> {
+    let mode = handle.pointer_mode()?;
+    if mode == PointerMode::Host {
+        return f();
+    }
+
+    // SAFETY: host mode matches the host references `f` passes, and the
+    // caller's mode is restored unchanged afterwards
+    unsafe { handle.set_pointer_mode(PointerMode::Host)? };
+    let result = f();
+    unsafe { handle.set_pointer_mode(mode)? };
+    result
+}
+
+/// Applies a Givens rotation to the point pairs `(x[i], y[i])`
+///
+/// Each pair is replaced by `x = c * x + s * y` and `y = c * y - conj(s) * x`.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x and y
+/// * `x` - Input/output vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `y` - Input/output vector y
+/// * `incy` - Stride between consecutive elements of y
+/// * `c` - Cosine of the rotation
+/// * `s` - Sine of the rotation
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `x` or `y` is too short for `n` elements or the operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn rot<T: RotDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    x: &mut MemoryPointer<T>,
+    incx: i32,
+    y: &mut MemoryPointer<T>,
+    incy: i32,
+    c: &T::Real,
+    s: &T,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+
+    check_vector_len("x", n, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+
+    with_host_pointer_mode(handle, || unsafe {
+        let code = T::hipblas_rot(
+            handle.handle(),
+            n,
+            x.as_pointer(),
+            incx,
+            y.as_pointer(),
+            incy,
+            c,
+            s,
+        );
+        ((), code).to_result()
+    })
+}
+
+/// Generates the Givens rotation that zeroes `b` in the pair `(a, b)`
+///
+/// On return `a` holds the rotated value `r`, `b` holds the reconstruction
+/// parameter `z`, and `c` and `s` hold the rotation to pass to [`rot`].
+///
+/// The scalars are host references, so the call always runs in
+/// [`PointerMode::Host`], even on a handle set to [`PointerMode::Device`]. The
+/// handle's mode is restored afterwards. To generate the rotation from scalars in
+/// device memory, call `hipblas*rotg` through [`BlasHandle::handle`] instead.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `a` - Input first element, output `r`
+/// * `b` - Input second element, output `z`
+/// * `c` - Output cosine of the rotation
+/// * `s` - Output sine of the rotation
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if the operation failed
+pub fn rotg<T: RotDatatype>(
+    handle: &BlasHandle,
+    a: &mut T,
+    b: &mut T,
+    c: &mut T::Real,
+    s: &mut T,
+) -> BlasResult<()> {
+    with_host_pointer_mode(handle, || unsafe {
+        let code = T::hipblas_rotg(handle.handle(), a, b, c, s);
+        ((), code).to_result()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srot_quarter_turn() {
+        let handle = BlasHandle::new().unwrap();
+        let mut x = MemoryPointer::<f32>::alloc(1).unwrap();
+        let mut y = MemoryPointer::<f32>::alloc(1).unwrap();
+        x.copy_from_host(&[1.0]).unwrap();
+        y.copy_from_host(&[0.0]).unwrap();
+
+        // c = cos(90°), s = -sin(90°) turns (1, 0) counterclockwise onto (0, 1)
+        rot(&handle, 1, &mut x, 1, &mut y, 1, &0.0, &-1.0).unwrap();
+
+        let mut point = [0.0f32; 2];
+        x.copy_to_host(&mut point[..1]).unwrap();
+        y.copy_to_host(&mut point[1..]).unwrap();
+        assert_eq!(point, [0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_srotg() {
+        let handle = BlasHandle::new().unwrap();
+        let (mut a, mut b, mut c, mut s) = (3.0f32, 4.0f32, 0.0f32, 0.0f32);
+
+        rotg(&handle, &mut a, &mut b, &mut c, &mut s).unwrap();
+
+        assert!((a - 5.0).abs() < 1e-6);
+        assert!((c - 0.6).abs() < 1e-6);
+        assert!((s - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rotg_restores_device_pointer_mode() {
+        let handle = BlasHandle::new().unwrap();
+        // SAFETY: rotg switches to host mode for its host scalars
+        unsafe { handle.set_pointer_mode(PointerMode::Device) }.unwrap();
+        let (mut a, mut b, mut c, mut s) = (3.0f64, 4.0f64, 0.0f64, 0.0f64);
+
+        rotg(&handle, &mut a, &mut b, &mut c, &mut s).unwrap();
+
+        assert!((a - 5.0).abs() < 1e-12);
+        assert_eq!(handle.pointer_mode().unwrap(), PointerMode::Device);
+    }
+}
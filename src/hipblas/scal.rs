@@ -0,0 +1,242 @@
+use super::{check_device, check_vector_len, BlasError, BlasHandle, BlasResult, BlasStatus};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by SCAL operations
+pub trait ScalDatatype {
+    /// Calls the appropriate HIPBLAS SCAL function for this datatype
+    ///
+    /// # Safety
+    /// `x` must be a device pointer to at least `1 + (n - 1) * incx` elements.
+    unsafe fn hipblas_scal(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl ScalDatatype for f32 {
+    unsafe fn hipblas_scal(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSscal(handle, n, alpha, x, incx))
+    }
+}
+
+impl ScalDatatype for f64 {
+    unsafe fn hipblas_scal(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDscal(handle, n, alpha, x, incx))
+    }
+}
+
+impl ScalDatatype for Complex32 {
+    unsafe fn hipblas_scal(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCscal(
+            handle,
+            n,
+            alpha as *const sys::hipblasComplex,
+            x as *mut sys::hipblasComplex,
+            incx,
+        ))
+    }
+}
+
+impl ScalDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_scal(
+        handle: sys::hipblasHandle_t,
+        n: i32,
+        alpha: *const Self,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZscal(handle, n, alpha, x, incx))
+    }
+}
+
+/// Rejects a zero increment, which hipBLAS does not support for scaling.
+///
+/// # Arguments
+/// * `name` - Name of the calling function, used in the log message
+/// * `incx` - Stride between consecutive elements of x
+fn check_increment(name: &str, incx: i32) -> BlasResult<()> {
+    if incx == 0 {
+        log::error!("{}: incx must be non-zero", name);
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    Ok(())
+}
+
+/// Scales a vector in place: x = alpha * x
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x
+/// * `alpha` - Scalar multiplier
+/// * `x` - Input/output vector x
+/// * `incx` - Stride between consecutive elements of x, must be non-zero
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `incx` is zero, `x` is too short for `n` elements or the
+///   operation failed
+pub fn scal<T: ScalDatatype>(
+    handle: &BlasHandle,
+    n: i32,
+    alpha: &T,
+    x: &mut MemoryPointer<T>,
+    incx: i32,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+
+    check_vector_len("x", n, x, incx)?;
+
+    check_increment("scal", incx)?;
+
+    unsafe {
+        let code = T::hipblas_scal(handle.handle(), n, alpha, x.as_pointer(), incx);
+        ((), code).to_result()
+    }
+}
+
+/// Scales a single precision complex vector in place by a real scalar: x = alpha * x
+///
+/// Cheaper than [`scal`] with a complex `alpha` that has no imaginary part.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x
+/// * `alpha` - Real scalar multiplier
+/// * `x` - Input/output vector x
+/// * `incx` - Stride between consecutive elements of x, must be non-zero
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `incx` is zero, `x` is too short for `n` elements or the
+///   operation failed
+pub fn csscal(
+    handle: &BlasHandle,
+    n: i32,
+    alpha: &f32,
+    x: &mut MemoryPointer<Complex32>,
+    incx: i32,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+
+    check_vector_len("x", n, x, incx)?;
+
+    check_increment("csscal", incx)?;
+
+    unsafe {
+        let code = record_call!(sys::hipblasCsscal(
+            handle.handle(),
+            n,
+            alpha,
+            x.as_pointer() as *mut sys::hipblasComplex,
+            incx,
+        ));
+        ((), code).to_result()
+    }
+}
+
+/// Scales a double precision complex vector in place by a real scalar: x = alpha * x
+///
+/// Cheaper than [`scal`] with a complex `alpha` that has no imaginary part.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `n` - Number of elements in x
+/// * `alpha` - Real scalar multiplier
+/// * `x` - Input/output vector x
+/// * `incx` - Stride between consecutive elements of x, must be non-zero
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `incx` is zero, `x` is too short for `n` elements or the
+///   operation failed
+pub fn zdscal(
+    handle: &BlasHandle,
+    n: i32,
+    alpha: &f64,
+    x: &mut MemoryPointer<sys::hipblasDoubleComplex>,
+    incx: i32,
+) -> BlasResult<()> {
+    check_device(handle, "x", x)?;
+
+    check_vector_len("x", n, x, incx)?;
+
+    check_increment("zdscal", incx)?;
+
+    unsafe {
+        let code = record_call!(sys::hipblasZdscal(
+            handle.handle(),
+            n,
+            alpha,
+            x.as_pointer(),
+            incx
+        ));
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sscal() {
+        let handle = BlasHandle::new().unwrap();
+        let mut x = MemoryPointer::<f32>::alloc(3).unwrap();
+        x.copy_from_host(&[1.0, 2.0, 3.0]).unwrap();
+
+        scal(&handle, 3, &2.0, &mut x, 1).unwrap();
+
+        let mut result = [0.0f32; 3];
+        x.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_csscal() {
+        let handle = BlasHandle::new().unwrap();
+        let mut x = MemoryPointer::<Complex32>::alloc(2).unwrap();
+        x.copy_from_host(&[Complex32::new(1.0, 2.0), Complex32::new(-3.0, 0.5)])
+            .unwrap();
+
+        csscal(&handle, 2, &2.0, &mut x, 1).unwrap();
+
+        let mut result = [Complex32::default(); 2];
+        x.copy_to_host(&mut result).unwrap();
+        assert_eq!(
+            result,
+            [Complex32::new(2.0, 4.0), Complex32::new(-6.0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_scal_zero_increment() {
+        let handle = BlasHandle::new().unwrap();
+        let mut x = MemoryPointer::<f32>::alloc(3).unwrap();
+
+        let result = scal(&handle, 3, &2.0, &mut x, 0);
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
@@ -0,0 +1,297 @@
+use super::{
+    check_device, check_matrix_len, BlasError, BlasHandle, BlasResult, BlasStatus, FillMode,
+    SideMode,
+};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by SYMM operations
+pub trait SymmDatatype {
+    /// Calls the appropriate HIPBLAS SYMM function for this datatype
+    ///
+    /// # Safety
+    /// `a` must be a device pointer to a symmetric `lda x k` matrix, where `k` is
+    /// `m` for [`SideMode::Left`] and `n` for [`SideMode::Right`], and `b` and `c`
+    /// device pointers to `ldb x n` and `ldc x n` matrices.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_symm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl SymmDatatype for f32 {
+    unsafe fn hipblas_symm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSsymm(
+            handle, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+        ))
+    }
+}
+
+impl SymmDatatype for f64 {
+    unsafe fn hipblas_symm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDsymm(
+            handle, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+        ))
+    }
+}
+
+impl SymmDatatype for Complex32 {
+    unsafe fn hipblas_symm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCsymm(
+            handle,
+            side,
+            uplo,
+            m,
+            n,
+            alpha as *const sys::hipblasComplex,
+            a as *const sys::hipblasComplex,
+            lda,
+            b as *const sys::hipblasComplex,
+            ldb,
+            beta as *const sys::hipblasComplex,
+            c as *mut sys::hipblasComplex,
+            ldc,
+        ))
+    }
+}
+
+impl SymmDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_symm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        beta: *const Self,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZsymm(
+            handle, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+        ))
+    }
+}
+
+fn check_leading_dimension(name: &str, ld: i32, rows: i32) -> BlasResult<()> {
+    if ld < rows.max(1) {
+        log::error!(
+            "{} ({}) must be at least the number of rows ({})",
+            name,
+            ld,
+            rows
+        );
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    Ok(())
+}
+
+/// Performs symmetric matrix-matrix multiplication:
+/// C = alpha * A * B + beta * C or C = alpha * B * A + beta * C
+///
+/// Only the triangle of A selected by `uplo` is read, the other triangle is
+/// taken to mirror it.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `side` - Whether A is on the left or on the right of B
+/// * `uplo` - Which triangle of A to read
+/// * `m` - Number of rows in B and C
+/// * `n` - Number of columns in B and C
+/// * `alpha` - Scalar multiplier for the product
+/// * `a` - Input symmetric matrix A, `m x m` for the left side and `n x n` for the right
+/// * `lda` - Leading dimension of A, at least the order of A
+/// * `b` - Input matrix B
+/// * `ldb` - Leading dimension of B, at least `m`
+/// * `beta` - Scalar multiplier for C
+/// * `c` - Input/output matrix C
+/// * `ldc` - Leading dimension of C, at least `m`
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if a leading dimension is too small, a matrix is too short for
+///   the shape, or the operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn symm<T: SymmDatatype>(
+    handle: &BlasHandle,
+    side: SideMode,
+    uplo: FillMode,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    b: &MemoryPointer<T>,
+    ldb: i32,
+    beta: &T,
+    c: &mut MemoryPointer<T>,
+    ldc: i32,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "b", b)?;
+    check_device(handle, "c", c)?;
+
+    let k = match side {
+        SideMode::Left => m,
+        SideMode::Right => n,
+    };
+    check_matrix_len("a", k, k, a, lda)?;
+    check_matrix_len("b", m, n, b, ldb)?;
+    check_matrix_len("c", m, n, c, ldc)?;
+
+    let order = match side {
+        SideMode::Left => m,
+        SideMode::Right => n,
+    };
+    check_leading_dimension("lda", lda, order)?;
+    check_leading_dimension("ldb", ldb, m)?;
+    check_leading_dimension("ldc", ldc, m)?;
+
+    unsafe {
+        let code = T::hipblas_symm(
+            handle.handle(),
+            side.into(),
+            uplo.into(),
+            m,
+            n,
+            alpha,
+            a.as_pointer(),
+            lda,
+            b.as_pointer(),
+            ldb,
+            beta,
+            c.as_pointer(),
+            ldc,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssymm_left_upper() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major A = [1 2], only the upper triangle is stored, the
+        //                  [2 3]  lower element is never read
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        a.copy_from_host(&[1.0, 99.0, 2.0, 3.0]).unwrap();
+        // Column-major B = [1 2]
+        //                  [3 4]
+        let b = MemoryPointer::<f32>::alloc(4).unwrap();
+        b.copy_from_host(&[1.0, 3.0, 2.0, 4.0]).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+        c.copy_from_host(&[0.0; 4]).unwrap();
+
+        symm(
+            &handle,
+            SideMode::Left,
+            FillMode::Upper,
+            2,
+            2,
+            &1.0,
+            &a,
+            2,
+            &b,
+            2,
+            &0.0,
+            &mut c,
+            2,
+        )
+        .unwrap();
+
+        // A * B = [ 7 10]
+        //         [11 16]
+        let mut result = [0.0f32; 4];
+        c.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [7.0, 11.0, 10.0, 16.0]);
+    }
+
+    #[test]
+    fn test_symm_right_lda_too_small() {
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<f32>::alloc(9).unwrap();
+        let b = MemoryPointer::<f32>::alloc(6).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(6).unwrap();
+
+        // On the right A is n x n, so lda = m = 2 is too small for n = 3
+        let result = symm(
+            &handle,
+            SideMode::Right,
+            FillMode::Lower,
+            2,
+            3,
+            &1.0,
+            &a,
+            2,
+            &b,
+            2,
+            &0.0,
+            &mut c,
+            2,
+        );
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
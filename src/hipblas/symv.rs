@@ -0,0 +1,365 @@
+use super::{
+    check_device, check_matrix_len, check_vector_len, BlasError, BlasHandle, BlasResult,
+    BlasStatus, FillMode,
+};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for real types supported by SYMV operations
+pub trait SymvDatatype {
+    /// Calls the appropriate HIPBLAS SYMV function for this datatype
+    ///
+    /// # Safety
+    /// `a` must be a device pointer to an `lda x n` matrix, and `x` and `y` device
+    /// pointers to at least `1 + (n - 1) * |inc|` elements.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_symv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl SymvDatatype for f32 {
+    unsafe fn hipblas_symv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasSsymv(
+            handle, uplo, n, alpha, a, lda, x, incx, beta, y, incy
+        ))
+    }
+}
+
+impl SymvDatatype for f64 {
+    unsafe fn hipblas_symv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDsymv(
+            handle, uplo, n, alpha, a, lda, x, incx, beta, y, incy
+        ))
+    }
+}
+
+/// Trait for complex types supported by HEMV operations
+pub trait HemvDatatype {
+    /// Calls the appropriate HIPBLAS HEMV function for this datatype
+    ///
+    /// # Safety
+    /// Same requirements as [`SymvDatatype::hipblas_symv`].
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_hemv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl HemvDatatype for Complex32 {
+    unsafe fn hipblas_hemv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasChemv(
+            handle,
+            uplo,
+            n,
+            alpha as *const sys::hipblasComplex,
+            a as *const sys::hipblasComplex,
+            lda,
+            x as *const sys::hipblasComplex,
+            incx,
+            beta as *const sys::hipblasComplex,
+            y as *mut sys::hipblasComplex,
+            incy,
+        ))
+    }
+}
+
+impl HemvDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_hemv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: *const Self,
+        y: *mut Self,
+        incy: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZhemv(
+            handle, uplo, n, alpha, a, lda, x, incx, beta, y, incy
+        ))
+    }
+}
+
+fn check_leading_dimension(n: i32, lda: i32) -> BlasResult<()> {
+    if lda < n.max(1) {
+        log::error!("lda ({}) must be at least n ({})", lda, n);
+        return Err(BlasError::from_status(BlasStatus::InvalidValue));
+    }
+    Ok(())
+}
+
+/// Performs symmetric matrix-vector multiplication: y = alpha * A * x + beta * y
+///
+/// Only the triangle of A selected by `uplo` is read, the other triangle is
+/// taken to mirror it.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `uplo` - Which triangle of A to read
+/// * `n` - Number of rows and columns in A
+/// * `alpha` - Scalar multiplier for A * x
+/// * `a` - Input symmetric matrix A, in column-major order
+/// * `lda` - Leading dimension of A, at least `n`
+/// * `x` - Input vector x
+/// * `incx` - Stride between consecutive elements of x
+/// * `beta` - Scalar multiplier for y
+/// * `y` - Input/output vector y
+/// * `incy` - Stride between consecutive elements of y
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `lda` is smaller than `n`, `a`, `x` or `y` is too short
+///   for the shape, or the operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn symv<T: SymvDatatype>(
+    handle: &BlasHandle,
+    uplo: FillMode,
+    n: i32,
+    alpha: &T,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    beta: &T,
+    y: &mut MemoryPointer<T>,
+    incy: i32,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+
+    check_matrix_len("a", n, n, a, lda)?;
+    check_vector_len("x", n, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+
+    check_leading_dimension(n, lda)?;
+
+    unsafe {
+        let code = T::hipblas_symv(
+            handle.handle(),
+            uplo.into(),
+            n,
+            alpha,
+            a.as_pointer(),
+            lda,
+            x.as_pointer(),
+            incx,
+            beta,
+            y.as_pointer(),
+            incy,
+        );
+        ((), code).to_result()
+    }
+}
+
+/// Performs Hermitian matrix-vector multiplication: y = alpha * A * x + beta * y
+///
+/// Only the triangle of A selected by `uplo` is read, the other triangle is
+/// taken to be its conjugate transpose. The imaginary parts of the diagonal are
+/// assumed to be zero.
+///
+/// See [`symv`] for the arguments.
+#[allow(clippy::too_many_arguments)]
+pub fn hemv<T: HemvDatatype>(
+    handle: &BlasHandle,
+    uplo: FillMode,
+    n: i32,
+    alpha: &T,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    x: &MemoryPointer<T>,
+    incx: i32,
+    beta: &T,
+    y: &mut MemoryPointer<T>,
+    incy: i32,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "x", x)?;
+    check_device(handle, "y", y)?;
+
+    check_matrix_len("a", n, n, a, lda)?;
+    check_vector_len("x", n, x, incx)?;
+    check_vector_len("y", n, y, incy)?;
+
+    check_leading_dimension(n, lda)?;
+
+    unsafe {
+        let code = T::hipblas_hemv(
+            handle.handle(),
+            uplo.into(),
+            n,
+            alpha,
+            a.as_pointer(),
+            lda,
+            x.as_pointer(),
+            incx,
+            beta,
+            y.as_pointer(),
+            incy,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssymv_upper() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major symmetric A = [2 1]
+        //                            [1 3]
+        // stored in the upper triangle, the entry below the diagonal is never read
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let x = MemoryPointer::<f32>::alloc(2).unwrap();
+        let mut y = MemoryPointer::<f32>::alloc(2).unwrap();
+        a.copy_from_host(&[2.0, 99.0, 1.0, 3.0]).unwrap();
+        x.copy_from_host(&[1.0, 2.0]).unwrap();
+
+        symv(
+            &handle,
+            FillMode::Upper,
+            2,
+            &1.0,
+            &a,
+            2,
+            &x,
+            1,
+            &0.0,
+            &mut y,
+            1,
+        )
+        .unwrap();
+
+        // A * x = [2 + 2, 1 + 6]
+        let mut result = [0.0f32; 2];
+        y.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, [4.0, 7.0]);
+    }
+
+    #[test]
+    fn test_chemv_lower() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major Hermitian A = [1   -i]
+        //                            [i    1]
+        // stored in the lower triangle
+        let zero = Complex32::default();
+        let one = Complex32::new(1.0, 0.0);
+        let a = MemoryPointer::<Complex32>::alloc(4).unwrap();
+        let x = MemoryPointer::<Complex32>::alloc(2).unwrap();
+        let mut y = MemoryPointer::<Complex32>::alloc(2).unwrap();
+        a.copy_from_host(&[one, Complex32::new(0.0, 1.0), Complex32::new(9.0, 9.0), one])
+            .unwrap();
+        x.copy_from_host(&[one, one]).unwrap();
+
+        hemv(
+            &handle,
+            FillMode::Lower,
+            2,
+            &one,
+            &a,
+            2,
+            &x,
+            1,
+            &zero,
+            &mut y,
+            1,
+        )
+        .unwrap();
+
+        // A * x = [1 - i, 1 + i]
+        let mut result = [zero; 2];
+        y.copy_to_host(&mut result).unwrap();
+        assert_eq!(
+            result,
+            [Complex32::new(1.0, -1.0), Complex32::new(1.0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn test_symv_invalid_leading_dimension() {
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let x = MemoryPointer::<f32>::alloc(2).unwrap();
+        let mut y = MemoryPointer::<f32>::alloc(2).unwrap();
+
+        let result = symv(
+            &handle,
+            FillMode::Upper,
+            2,
+            &1.0,
+            &a,
+            1,
+            &x,
+            1,
+            &0.0,
+            &mut y,
+            1,
+        );
+        assert_eq!(result.unwrap_err().status, BlasStatus::InvalidValue);
+    }
+}
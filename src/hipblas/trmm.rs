@@ -0,0 +1,269 @@
+use super::{
+    check_device, check_matrix_len, BlasHandle, BlasResult, DiagType, FillMode, Operation, SideMode,
+};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by TRMM operations
+pub trait TrmmDatatype {
+    /// Calls the appropriate HIPBLAS TRMM function for this datatype
+    ///
+    /// # Safety
+    /// `a` must be a device pointer to a triangular `lda x k` matrix, where `k` is
+    /// `m` for [`SideMode::Left`] and `n` for [`SideMode::Right`], and `b` and `c`
+    /// device pointers to `m x n` matrices.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_trmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl TrmmDatatype for f32 {
+    unsafe fn hipblas_trmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasStrmm(
+            handle, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb, c, ldc,
+        ))
+    }
+}
+
+impl TrmmDatatype for f64 {
+    unsafe fn hipblas_trmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDtrmm(
+            handle, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb, c, ldc,
+        ))
+    }
+}
+
+impl TrmmDatatype for Complex32 {
+    unsafe fn hipblas_trmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCtrmm(
+            handle,
+            side,
+            uplo,
+            trans,
+            diag,
+            m,
+            n,
+            alpha as *const sys::hipblasComplex,
+            a as *const sys::hipblasComplex,
+            lda,
+            b as *const sys::hipblasComplex,
+            ldb,
+            c as *mut sys::hipblasComplex,
+            ldc,
+        ))
+    }
+}
+
+impl TrmmDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_trmm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZtrmm(
+            handle, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb, c, ldc,
+        ))
+    }
+}
+
+/// Performs triangular matrix-matrix multiplication with a separate output:
+/// C = alpha * op(A) * B or C = alpha * B * op(A)
+///
+/// Only the triangle of A selected by `uplo` is read.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `side` - Whether op(A) multiplies B from the left or from the right
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `trans` - How to transform matrix A
+/// * `diag` - Whether A has an implicit unit diagonal
+/// * `m` - Number of rows in B and C
+/// * `n` - Number of columns in B and C
+/// * `alpha` - Scalar multiplier for the product
+/// * `a` - Input triangular matrix A, `m x m` for the left side and `n x n` for the right
+/// * `lda` - Leading dimension of A
+/// * `b` - Input matrix B
+/// * `ldb` - Leading dimension of B
+/// * `c` - Output matrix C
+/// * `ldc` - Leading dimension of C
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if a buffer is too short for the shape or operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn trmm<T: TrmmDatatype>(
+    handle: &BlasHandle,
+    side: SideMode,
+    uplo: FillMode,
+    trans: Operation,
+    diag: DiagType,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    b: &MemoryPointer<T>,
+    ldb: i32,
+    c: &mut MemoryPointer<T>,
+    ldc: i32,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "b", b)?;
+    check_device(handle, "c", c)?;
+
+    let k = match side {
+        SideMode::Left => m,
+        SideMode::Right => n,
+    };
+    check_matrix_len("a", k, k, a, lda)?;
+    check_matrix_len("b", m, n, b, ldb)?;
+    check_matrix_len("c", m, n, c, ldc)?;
+
+    unsafe {
+        let code = T::hipblas_trmm(
+            handle.handle(),
+            side.into(),
+            uplo.into(),
+            trans.into(),
+            diag.into(),
+            m,
+            n,
+            alpha,
+            a.as_pointer(),
+            lda,
+            b.as_pointer(),
+            ldb,
+            c.as_pointer(),
+            ldc,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trmm_unit_upper(side: SideMode) -> [f32; 4] {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major unit upper-triangular A = [1 2]
+        //                                        [0 1]
+        // The diagonal and the entry below it are never read
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        a.copy_from_host(&[9.0, 99.0, 2.0, 9.0]).unwrap();
+
+        // Column-major B = [1 2]
+        //                  [3 4]
+        let b = MemoryPointer::<f32>::alloc(4).unwrap();
+        b.copy_from_host(&[1.0, 3.0, 2.0, 4.0]).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+
+        trmm(
+            &handle,
+            side,
+            FillMode::Upper,
+            Operation::None,
+            DiagType::Unit,
+            2,
+            2,
+            &1.0,
+            &a,
+            2,
+            &b,
+            2,
+            &mut c,
+            2,
+        )
+        .unwrap();
+
+        let mut result = [0.0f32; 4];
+        c.copy_to_host(&mut result).unwrap();
+        result
+    }
+
+    #[test]
+    fn test_strmm_left() {
+        // A * B = [7 10]
+        //         [3  4]
+        assert_eq!(trmm_unit_upper(SideMode::Left), [7.0, 3.0, 10.0, 4.0]);
+    }
+
+    #[test]
+    fn test_strmm_right() {
+        // B * A = [1  4]
+        //         [3 10]
+        assert_eq!(trmm_unit_upper(SideMode::Right), [1.0, 3.0, 4.0, 10.0]);
+    }
+}
@@ -0,0 +1,258 @@
+use super::{
+    check_device, check_matrix_len, BlasHandle, BlasResult, DiagType, FillMode, Operation, SideMode,
+};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by TRSM operations
+pub trait TrsmDatatype {
+    /// Calls the appropriate HIPBLAS TRSM function for this datatype
+    ///
+    /// # Safety
+    /// `a` must be a device pointer to a triangular `lda x k` matrix, where `k` is
+    /// `m` for [`SideMode::Left`] and `n` for [`SideMode::Right`], and `b` a device
+    /// pointer to an `ldb x n` matrix.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_trsm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl TrsmDatatype for f32 {
+    unsafe fn hipblas_trsm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasStrsm(
+            handle, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb
+        ))
+    }
+}
+
+impl TrsmDatatype for f64 {
+    unsafe fn hipblas_trsm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDtrsm(
+            handle, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb
+        ))
+    }
+}
+
+impl TrsmDatatype for Complex32 {
+    unsafe fn hipblas_trsm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCtrsm(
+            handle,
+            side,
+            uplo,
+            trans,
+            diag,
+            m,
+            n,
+            alpha as *const sys::hipblasComplex,
+            a as *const sys::hipblasComplex,
+            lda,
+            b as *mut sys::hipblasComplex,
+            ldb,
+        ))
+    }
+}
+
+impl TrsmDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_trsm(
+        handle: sys::hipblasHandle_t,
+        side: sys::hipblasSideMode_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        m: i32,
+        n: i32,
+        alpha: *const Self,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZtrsm(
+            handle, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb
+        ))
+    }
+}
+
+/// Solves a triangular system with multiple right-hand sides in place:
+/// op(A) * X = alpha * B or X * op(A) = alpha * B
+///
+/// `B` is overwritten with the solution `X`. Only the triangle of A selected
+/// by `uplo` is read. No test for singularity is performed.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `side` - Whether op(A) appears on the left or on the right of X
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `trans` - How to transform matrix A
+/// * `diag` - Whether A has an implicit unit diagonal
+/// * `m` - Number of rows in B
+/// * `n` - Number of columns in B
+/// * `alpha` - Scalar multiplier for B
+/// * `a` - Input triangular matrix A, `m x m` for the left side and `n x n` for the right
+/// * `lda` - Leading dimension of A
+/// * `b` - Input right-hand sides B, output solution X
+/// * `ldb` - Leading dimension of B
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if a buffer is too short for the shape or operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn trsm<T: TrsmDatatype>(
+    handle: &BlasHandle,
+    side: SideMode,
+    uplo: FillMode,
+    trans: Operation,
+    diag: DiagType,
+    m: i32,
+    n: i32,
+    alpha: &T,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    b: &mut MemoryPointer<T>,
+    ldb: i32,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "b", b)?;
+
+    let k = match side {
+        SideMode::Left => m,
+        SideMode::Right => n,
+    };
+    check_matrix_len("a", k, k, a, lda)?;
+    check_matrix_len("b", m, n, b, ldb)?;
+
+    unsafe {
+        let code = T::hipblas_trsm(
+            handle.handle(),
+            side.into(),
+            uplo.into(),
+            trans.into(),
+            diag.into(),
+            m,
+            n,
+            alpha,
+            a.as_pointer(),
+            lda,
+            b.as_pointer(),
+            ldb,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Solves `L X = B` column by column with forward substitution.
+    fn forward_substitution(l: &[f64], b: &[f64], m: usize, n: usize) -> Vec<f64> {
+        let mut x = b.to_vec();
+        for j in 0..n {
+            for i in 0..m {
+                let mut sum = x[i + j * m];
+                for p in 0..i {
+                    sum -= l[i + p * m] * x[p + j * m];
+                }
+                x[i + j * m] = sum / l[i + i * m];
+            }
+        }
+        x
+    }
+
+    #[test]
+    fn test_dtrsm_lower_left() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major lower-triangular L = [2 0 0]
+        //                                   [1 4 0]
+        //                                   [3 2 5]
+        let l = [2.0, 1.0, 3.0, 0.0, 4.0, 2.0, 0.0, 0.0, 5.0];
+        // Two right-hand sides, column-major 3x2
+        let b = [2.0, 13.0, 30.0, 4.0, 18.0, 22.0];
+
+        let a = MemoryPointer::<f64>::alloc(9).unwrap();
+        let mut x = MemoryPointer::<f64>::alloc(6).unwrap();
+        a.copy_from_host(&l).unwrap();
+        x.copy_from_host(&b).unwrap();
+
+        trsm(
+            &handle,
+            SideMode::Left,
+            FillMode::Lower,
+            Operation::None,
+            DiagType::NonUnit,
+            3,
+            2,
+            &1.0,
+            &a,
+            3,
+            &mut x,
+            3,
+        )
+        .unwrap();
+
+        let mut solution = [0.0f64; 6];
+        x.copy_to_host(&mut solution).unwrap();
+        let expected = forward_substitution(&l, &b, 3, 2);
+        for (actual, expected) in solution.iter().zip(&expected) {
+            assert!(
+                (actual - expected).abs() < 1e-12,
+                "{} != {}",
+                actual,
+                expected
+            );
+        }
+    }
+}
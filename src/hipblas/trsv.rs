@@ -0,0 +1,198 @@
+use super::{
+    check_device, check_matrix_len, check_vector_len, BlasHandle, BlasResult, DiagType, FillMode,
+    Operation,
+};
+use crate::result::ResultExt;
+use crate::Complex32;
+use crate::{sys, MemoryPointer};
+
+/// Trait for types supported by TRSV operations
+pub trait TrsvDatatype {
+    /// Calls the appropriate HIPBLAS TRSV function for this datatype
+    ///
+    /// # Safety
+    /// `a` must be a device pointer to an `lda x n` matrix and `x` a device pointer
+    /// to at least `1 + (n - 1) * |incx|` elements.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn hipblas_trsv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t;
+}
+
+impl TrsvDatatype for f32 {
+    unsafe fn hipblas_trsv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasStrsv(
+            handle, uplo, trans, diag, n, a, lda, x, incx
+        ))
+    }
+}
+
+impl TrsvDatatype for f64 {
+    unsafe fn hipblas_trsv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasDtrsv(
+            handle, uplo, trans, diag, n, a, lda, x, incx
+        ))
+    }
+}
+
+impl TrsvDatatype for Complex32 {
+    unsafe fn hipblas_trsv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasCtrsv(
+            handle,
+            uplo,
+            trans,
+            diag,
+            n,
+            a as *const sys::hipblasComplex,
+            lda,
+            x as *mut sys::hipblasComplex,
+            incx,
+        ))
+    }
+}
+
+impl TrsvDatatype for sys::hipblasDoubleComplex {
+    unsafe fn hipblas_trsv(
+        handle: sys::hipblasHandle_t,
+        uplo: sys::hipblasFillMode_t,
+        trans: sys::hipblasOperation_t,
+        diag: sys::hipblasDiagType_t,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *mut Self,
+        incx: i32,
+    ) -> sys::hipblasStatus_t {
+        record_call!(sys::hipblasZtrsv(
+            handle, uplo, trans, diag, n, a, lda, x, incx
+        ))
+    }
+}
+
+/// Solves a triangular system of equations in place: op(A) * x = b
+///
+/// Only the triangle of A selected by `uplo` is read. No test for singularity
+/// is performed, a zero on the diagonal produces infinities or NaNs.
+///
+/// # Arguments
+/// * `handle` - HIPBLAS library handle
+/// * `uplo` - Whether A is upper or lower triangular
+/// * `trans` - How to transform matrix A
+/// * `diag` - Whether A has an implicit unit diagonal
+/// * `n` - Number of rows and columns in A
+/// * `a` - Input triangular matrix A, in column-major order
+/// * `lda` - Leading dimension of A
+/// * `x` - Input right-hand side b, output solution x
+/// * `incx` - Stride between consecutive elements of x
+///
+/// # Returns
+/// * `Ok(())` if successful
+/// * `Err(BlasError)` if `a` or `x` is too short for the shape or operation failed
+#[allow(clippy::too_many_arguments)]
+pub fn trsv<T: TrsvDatatype>(
+    handle: &BlasHandle,
+    uplo: FillMode,
+    trans: Operation,
+    diag: DiagType,
+    n: i32,
+    a: &MemoryPointer<T>,
+    lda: i32,
+    x: &mut MemoryPointer<T>,
+    incx: i32,
+) -> BlasResult<()> {
+    check_device(handle, "a", a)?;
+    check_device(handle, "x", x)?;
+
+    check_matrix_len("a", n, n, a, lda)?;
+    check_vector_len("x", n, x, incx)?;
+
+    unsafe {
+        let code = T::hipblas_trsv(
+            handle.handle(),
+            uplo.into(),
+            trans.into(),
+            diag.into(),
+            n,
+            a.as_pointer(),
+            lda,
+            x.as_pointer(),
+            incx,
+        );
+        ((), code).to_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strsv_upper() {
+        let handle = BlasHandle::new().unwrap();
+
+        // Column-major upper-triangular A = [2 1]
+        //                                   [0 4]
+        // The entry below the diagonal is never read
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        a.copy_from_host(&[2.0, 99.0, 1.0, 4.0]).unwrap();
+
+        // A * [1, 2] = [4, 8]
+        let mut x = MemoryPointer::<f32>::alloc(2).unwrap();
+        x.copy_from_host(&[4.0, 8.0]).unwrap();
+
+        trsv(
+            &handle,
+            FillMode::Upper,
+            Operation::None,
+            DiagType::NonUnit,
+            2,
+            &a,
+            2,
+            &mut x,
+            1,
+        )
+        .unwrap();
+
+        let mut solution = [0.0f32; 2];
+        x.copy_to_host(&mut solution).unwrap();
+        assert_eq!(solution, [1.0, 2.0]);
+    }
+}
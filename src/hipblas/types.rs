@@ -1,3 +1,4 @@
+use super::{BlasError, BlasStatus};
 use crate::sys;
 
 #[repr(u32)]
@@ -14,6 +15,213 @@ impl From<Operation> for sys::hipblasOperation_t {
     }
 }
 
+/// Which triangle of a matrix a triangular or symmetric routine reads.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillMode {
+    Upper = 121, // HIPBLAS_FILL_MODE_UPPER
+    Lower = 122, // HIPBLAS_FILL_MODE_LOWER
+    Full = 123,  // HIPBLAS_FILL_MODE_FULL, both triangles
+}
+
+impl From<FillMode> for sys::hipblasFillMode_t {
+    fn from(mode: FillMode) -> Self {
+        mode as sys::hipblasFillMode_t
+    }
+}
+
+impl TryFrom<sys::hipblasFillMode_t> for FillMode {
+    type Error = BlasError;
+
+    fn try_from(mode: sys::hipblasFillMode_t) -> Result<Self, Self::Error> {
+        match mode {
+            121 => Ok(FillMode::Upper),
+            122 => Ok(FillMode::Lower),
+            123 => Ok(FillMode::Full),
+            _ => Err(BlasError::from_status(BlasStatus::InvalidEnum)),
+        }
+    }
+}
+
+/// Whether a triangular matrix has an implicit unit diagonal.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagType {
+    NonUnit = 131, // HIPBLAS_DIAG_NON_UNIT, the diagonal is read from the matrix
+    Unit = 132,    // HIPBLAS_DIAG_UNIT, the diagonal is assumed to be all ones
+}
+
+impl From<DiagType> for sys::hipblasDiagType_t {
+    fn from(diag: DiagType) -> Self {
+        diag as sys::hipblasDiagType_t
+    }
+}
+
+impl TryFrom<sys::hipblasDiagType_t> for DiagType {
+    type Error = BlasError;
+
+    fn try_from(diag: sys::hipblasDiagType_t) -> Result<Self, Self::Error> {
+        match diag {
+            131 => Ok(DiagType::NonUnit),
+            132 => Ok(DiagType::Unit),
+            _ => Err(BlasError::from_status(BlasStatus::InvalidEnum)),
+        }
+    }
+}
+
+/// On which side of the product a triangular or symmetric matrix appears.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SideMode {
+    Left = 141,  // HIPBLAS_SIDE_LEFT, op(A) * B
+    Right = 142, // HIPBLAS_SIDE_RIGHT, B * op(A)
+}
+
+impl From<SideMode> for sys::hipblasSideMode_t {
+    fn from(side: SideMode) -> Self {
+        side as sys::hipblasSideMode_t
+    }
+}
+
+impl TryFrom<sys::hipblasSideMode_t> for SideMode {
+    type Error = BlasError;
+
+    fn try_from(side: sys::hipblasSideMode_t) -> Result<Self, Self::Error> {
+        match side {
+            141 => Ok(SideMode::Left),
+            142 => Ok(SideMode::Right),
+            _ => Err(BlasError::from_status(BlasStatus::InvalidEnum)),
+        }
+    }
+}
+
+/// Element type of a matrix passed to the extended BLAS functions such as [`crate::gemm_ex`].
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataType {
+    R16F = 150, // HIPBLAS_R_16F, real 16 bit floating point
+    R32F = 151, // HIPBLAS_R_32F
+    R64F = 152, // HIPBLAS_R_64F
+    C16F = 153, // HIPBLAS_C_16F, complex 16 bit floating point
+    C32F = 154, // HIPBLAS_C_32F
+    C64F = 155, // HIPBLAS_C_64F
+    R8I = 160,  // HIPBLAS_R_8I, real signed 8 bit integer
+    R8U = 161,  // HIPBLAS_R_8U
+    R32I = 162, // HIPBLAS_R_32I
+    R32U = 163, // HIPBLAS_R_32U
+    R16B = 168, // HIPBLAS_R_16B, real bfloat16
+}
+
+impl DataType {
+    /// Returns the size in bytes of a single element of this type.
+    pub fn size(&self) -> usize {
+        match self {
+            DataType::R8I | DataType::R8U => 1,
+            DataType::R16F | DataType::R16B => 2,
+            DataType::R32F | DataType::R32I | DataType::R32U | DataType::C16F => 4,
+            DataType::R64F | DataType::C32F => 8,
+            DataType::C64F => 16,
+        }
+    }
+}
+
+impl From<DataType> for sys::hipblasDatatype_t {
+    fn from(data_type: DataType) -> Self {
+        data_type as sys::hipblasDatatype_t
+    }
+}
+
+/// Precision used for the internal computation of the extended BLAS functions.
+///
+/// The scalars `alpha` and `beta` must be of the compute type.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComputeType {
+    F16 = 150, // HIPBLAS_R_16F
+    F32 = 151, // HIPBLAS_R_32F
+    F64 = 152, // HIPBLAS_R_64F
+    I32 = 162, // HIPBLAS_R_32I
+}
+
+impl ComputeType {
+    /// Returns the size in bytes of the scalars `alpha` and `beta` for this compute type.
+    pub fn size(&self) -> usize {
+        match self {
+            ComputeType::F16 => 2,
+            ComputeType::F32 | ComputeType::I32 => 4,
+            ComputeType::F64 => 8,
+        }
+    }
+}
+
+impl From<ComputeType> for sys::hipblasDatatype_t {
+    fn from(compute_type: ComputeType) -> Self {
+        compute_type as sys::hipblasDatatype_t
+    }
+}
+
+/// Algorithm selection for the extended GEMM functions.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GemmAlgo {
+    Default = 160, // HIPBLAS_GEMM_DEFAULT
+}
+
+impl From<GemmAlgo> for sys::hipblasGemmAlgo_t {
+    fn from(algo: GemmAlgo) -> Self {
+        algo as sys::hipblasGemmAlgo_t
+    }
+}
+
+/// Where hipBLAS reads scalar arguments such as `alpha` and writes scalar results.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerMode {
+    Host = 0,   // HIPBLAS_POINTER_MODE_HOST, scalars are host pointers
+    Device = 1, // HIPBLAS_POINTER_MODE_DEVICE, scalars are device pointers
+}
+
+impl From<PointerMode> for sys::hipblasPointerMode_t {
+    fn from(mode: PointerMode) -> Self {
+        mode as sys::hipblasPointerMode_t
+    }
+}
+
+impl From<sys::hipblasPointerMode_t> for PointerMode {
+    fn from(mode: sys::hipblasPointerMode_t) -> Self {
+        match mode {
+            0 => PointerMode::Host,
+            _ => PointerMode::Device,
+        }
+    }
+}
+
+/// Whether hipBLAS may use atomic operations in functions that support them.
+///
+/// Atomics can make functions such as GEMM faster, but the order in which
+/// partial results are accumulated then varies between runs.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomicsMode {
+    NotAllowed = 0, // HIPBLAS_ATOMICS_NOT_ALLOWED
+    Allowed = 1,    // HIPBLAS_ATOMICS_ALLOWED
+}
+
+impl From<AtomicsMode> for sys::hipblasAtomicsMode_t {
+    fn from(mode: AtomicsMode) -> Self {
+        mode as sys::hipblasAtomicsMode_t
+    }
+}
+
+impl From<sys::hipblasAtomicsMode_t> for AtomicsMode {
+    fn from(mode: sys::hipblasAtomicsMode_t) -> Self {
+        match mode {
+            0 => AtomicsMode::NotAllowed,
+            _ => AtomicsMode::Allowed,
+        }
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -57,7 +265,7 @@ pub struct Complex32 {
 
 impl Complex32 {
     /// Creates a new complex number from real and imaginary parts
-    pub fn new(r: f32, i: f32) -> Self {
+    pub const fn new(r: f32, i: f32) -> Self {
         Self {
             inner: sys::hipblasComplex { x: r, y: i },
         }
@@ -107,6 +315,181 @@ impl Default for Complex32 {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex64 {
+    inner: sys::hipblasDoubleComplex,
+}
+
+impl Complex64 {
+    /// Creates a new complex number from real and imaginary parts
+    pub const fn new(r: f64, i: f64) -> Self {
+        Self {
+            inner: sys::hipblasDoubleComplex { x: r, y: i },
+        }
+    }
+
+    /// Returns the real part
+    pub fn real(&self) -> f64 {
+        self.inner.x
+    }
+
+    /// Returns the imaginary part
+    pub fn imag(&self) -> f64 {
+        self.inner.y
+    }
+
+    /// Returns the complex conjugate
+    pub fn conj(&self) -> Self {
+        Self::new(self.real(), -self.imag())
+    }
+
+    /// Returns the magnitude (absolute value) of the complex number
+    pub fn abs(&self) -> f64 {
+        (self.real() * self.real() + self.imag() * self.imag()).sqrt()
+    }
+
+    /// Returns the argument (phase) of the complex number in radians
+    pub fn arg(&self) -> f64 {
+        self.imag().atan2(self.real())
+    }
+}
+
+impl From<sys::hipblasDoubleComplex> for Complex64 {
+    fn from(c: sys::hipblasDoubleComplex) -> Self {
+        Self { inner: c }
+    }
+}
+
+impl From<Complex64> for sys::hipblasDoubleComplex {
+    fn from(c: Complex64) -> Self {
+        c.inner
+    }
+}
+
+impl Default for Complex64 {
+    fn default() -> Self {
+        Self::new(0.0, 0.0)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<num_complex::Complex<f32>> for Complex32 {
+    fn from(c: num_complex::Complex<f32>) -> Self {
+        Self::new(c.re, c.im)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<Complex32> for num_complex::Complex<f32> {
+    fn from(c: Complex32) -> Self {
+        Self::new(c.real(), c.imag())
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<num_complex::Complex<f64>> for Complex64 {
+    fn from(c: num_complex::Complex<f64>) -> Self {
+        Self::new(c.re, c.im)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<Complex64> for num_complex::Complex<f64> {
+    fn from(c: Complex64) -> Self {
+        Self::new(c.real(), c.imag())
+    }
+}
+
+/// Converts a [`half::f16`] to the raw bits hipBLAS takes as `hipblasHalf`.
+#[cfg(feature = "half")]
+pub fn f16_to_hipblas(value: half::f16) -> sys::hipblasHalf {
+    value.to_bits()
+}
+
+/// Converts the raw bits of a `hipblasHalf` to a [`half::f16`].
+#[cfg(feature = "half")]
+pub fn hipblas_to_f16(value: sys::hipblasHalf) -> half::f16 {
+    half::f16::from_bits(value)
+}
+
+/// Implements complex arithmetic, and scaling by the real type, for a complex type.
+macro_rules! impl_complex_ops {
+    ($complex:ty, $real:ty) => {
+        impl std::ops::Add for $complex {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self::new(self.real() + rhs.real(), self.imag() + rhs.imag())
+            }
+        }
+
+        impl std::ops::Sub for $complex {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self::new(self.real() - rhs.real(), self.imag() - rhs.imag())
+            }
+        }
+
+        impl std::ops::Mul for $complex {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                Self::new(
+                    self.real() * rhs.real() - self.imag() * rhs.imag(),
+                    self.real() * rhs.imag() + self.imag() * rhs.real(),
+                )
+            }
+        }
+
+        impl std::ops::Div for $complex {
+            type Output = Self;
+
+            /// Divides by multiplying with the conjugate of `rhs`, so division
+            /// by zero yields non-finite parts as with the real types.
+            fn div(self, rhs: Self) -> Self {
+                let denominator = rhs.real() * rhs.real() + rhs.imag() * rhs.imag();
+                let numerator = self * rhs.conj();
+                Self::new(
+                    numerator.real() / denominator,
+                    numerator.imag() / denominator,
+                )
+            }
+        }
+
+        impl std::ops::Neg for $complex {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::new(-self.real(), -self.imag())
+            }
+        }
+
+        impl std::ops::Mul<$real> for $complex {
+            type Output = Self;
+
+            fn mul(self, rhs: $real) -> Self {
+                Self::new(self.real() * rhs, self.imag() * rhs)
+            }
+        }
+
+        impl std::ops::AddAssign for $complex {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl std::ops::MulAssign for $complex {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+impl_complex_ops!(Complex32, f32);
+impl_complex_ops!(Complex64, f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,4 +508,107 @@ mod tests {
         assert_eq!(conj.real(), 1.0);
         assert_eq!(conj.imag(), -2.0);
     }
+
+    #[test]
+    fn test_complex64_creation() {
+        let c = Complex64::new(1.0, 2.0);
+        assert_eq!(c.real(), 1.0);
+        assert_eq!(c.imag(), 2.0);
+
+        let raw: sys::hipblasDoubleComplex = c.into();
+        assert_eq!(Complex64::from(raw), c);
+    }
+
+    #[test]
+    fn test_complex64_conjugate() {
+        let c = Complex64::new(1.0, 2.0);
+        let conj = c.conj();
+        assert_eq!(conj.real(), 1.0);
+        assert_eq!(conj.imag(), -2.0);
+    }
+
+    #[test]
+    fn test_complex_arithmetic() {
+        let a = Complex32::new(1.0, 2.0);
+        let b = Complex32::new(3.0, 4.0);
+
+        assert_eq!(a * b, Complex32::new(-5.0, 10.0));
+        assert_eq!(a + b, Complex32::new(4.0, 6.0));
+        assert_eq!(a - b, Complex32::new(-2.0, -2.0));
+        assert_eq!(-a, Complex32::new(-1.0, -2.0));
+        assert_eq!(a * 2.0, Complex32::new(2.0, 4.0));
+
+        let mut c = a;
+        c += b;
+        c *= b;
+        assert_eq!(c, Complex32::new(-12.0, 34.0));
+    }
+
+    #[test]
+    fn test_complex_division_inverts_multiplication() {
+        let a = Complex32::new(1.0, 2.0);
+        let b = Complex32::new(3.0, -4.0);
+        let quotient = (a * b) / b;
+        assert!((quotient - a).abs() < 1e-6);
+
+        let a = Complex64::new(-0.5, 7.0);
+        let b = Complex64::new(2.5, 1.5);
+        let quotient = (a * b) / b;
+        assert!((quotient - a).abs() < 1e-12);
+        assert_eq!(
+            Complex64::new(1.0, 2.0) * Complex64::new(3.0, 4.0),
+            Complex64::new(-5.0, 10.0)
+        );
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_num_complex_round_trip() {
+        let value = num_complex::Complex::new(1.5f32, -2.0);
+        let converted = Complex32::from(value);
+        assert_eq!(converted, Complex32::new(1.5, -2.0));
+        assert_eq!(num_complex::Complex::<f32>::from(converted), value);
+
+        let value = num_complex::Complex::new(-0.25f64, 3.0);
+        let converted = Complex64::from(value);
+        assert_eq!(converted, Complex64::new(-0.25, 3.0));
+        assert_eq!(num_complex::Complex::<f64>::from(converted), value);
+    }
+
+    #[test]
+    fn test_triangular_enums_round_trip() {
+        for mode in [FillMode::Upper, FillMode::Lower, FillMode::Full] {
+            let raw: sys::hipblasFillMode_t = mode.into();
+            assert_eq!(FillMode::try_from(raw).unwrap(), mode);
+        }
+        for diag in [DiagType::NonUnit, DiagType::Unit] {
+            let raw: sys::hipblasDiagType_t = diag.into();
+            assert_eq!(DiagType::try_from(raw).unwrap(), diag);
+        }
+        for side in [SideMode::Left, SideMode::Right] {
+            let raw: sys::hipblasSideMode_t = side.into();
+            assert_eq!(SideMode::try_from(raw).unwrap(), side);
+        }
+
+        assert_eq!(u32::from(FillMode::Upper), 121);
+        assert_eq!(u32::from(DiagType::Unit), 132);
+        assert_eq!(u32::from(SideMode::Right), 142);
+        assert_eq!(
+            FillMode::try_from(0).unwrap_err().status,
+            BlasStatus::InvalidEnum
+        );
+    }
+
+    #[test]
+    fn test_data_type_size() {
+        assert_eq!(DataType::R8I.size(), std::mem::size_of::<i8>());
+        assert_eq!(
+            DataType::R16F.size(),
+            std::mem::size_of::<sys::hipblasHalf>()
+        );
+        assert_eq!(DataType::R32I.size(), std::mem::size_of::<i32>());
+        assert_eq!(DataType::R64F.size(), std::mem::size_of::<f64>());
+        assert_eq!(DataType::C32F.size(), std::mem::size_of::<Complex32>());
+        assert_eq!(DataType::C64F.size(), std::mem::size_of::<Complex64>());
+    }
 }
@@ -1,4 +1,37 @@
+//! Rust bindings for AMD's HIP runtime and the hipBLAS library.
+//!
+//! # Error handling
+//!
+//! Fallible functions return one of two result aliases, both re-exported from
+//! the crate root:
+//!
+//! * [`HipResult<T>`] - `Result<T, HipError>`, returned by the HIP runtime wrappers
+//! * [`BlasResult<T>`] - `Result<T, BlasError>`, returned by the hipBLAS wrappers
+//!
+//! The crate does not export a bare `Result`, so glob imports never shadow
+//! [`std::result::Result`]. A [`HipError`] converts into a [`BlasError`], so `?`
+//! can propagate runtime errors from functions returning [`BlasResult`].
+//!
+//! ```
+//! use hip_rs::{BlasHandle, BlasResult, HipResult, MemoryPointer};
+//!
+//! fn upload(data: &[f32]) -> HipResult<MemoryPointer<f32>> {
+//!     let ptr = MemoryPointer::alloc(data.len())?;
+//!     ptr.copy_from_host(data)?;
+//!     Ok(ptr)
+//! }
+//!
+//! fn sum(data: &[f32]) -> BlasResult<f32> {
+//!     let handle = BlasHandle::new()?;
+//!     let x = upload(data)?;
+//!     hip_rs::asum(&handle, data.len() as i32, &x, 1)
+//! }
+//!
+//! assert_eq!(sum(&[1.0, -2.0, 3.0]).unwrap(), 6.0);
+//! ```
 #![allow(non_upper_case_globals)]
+#[macro_use]
+mod stats;
 mod core;
 mod hipblas;
 mod result;
@@ -7,4 +40,10 @@ pub mod sys;
 pub use core::*;
 pub use hipblas::*;
 pub use result::*;
+pub use stats::*;
 pub use sys::*;
+
+// The canonical result types, named explicitly so they take precedence over
+// anything the glob re-exports above might bring in
+pub use core::{HipError, HipResult, HipStatus};
+pub use hipblas::{BlasError, BlasResult, BlasStatus};
@@ -0,0 +1,217 @@
+//! Optional bookkeeping of wrapped HIP and hipBLAS calls.
+//!
+//! When the `stats` feature is enabled, every HIP and hipBLAS call made by the crate,
+//! or through [`hip_call!`](crate::hip_call) or [`blas_call!`](crate::blas_call), is
+//! counted per function name, together with the number of calls that returned an error.
+//! Without the feature the bookkeeping compiles down to nothing.
+
+#[cfg(test)]
+use std::cell::RefCell;
+#[cfg(any(test, feature = "stats"))]
+use std::collections::BTreeMap;
+#[cfg(feature = "stats")]
+use std::sync::Mutex;
+
+#[cfg(feature = "stats")]
+static CALL_STATS: Mutex<BTreeMap<&'static str, CallCount>> = Mutex::new(BTreeMap::new());
+
+// Calls made by the current thread, so tests can count their own calls while
+// other tests run concurrently
+#[cfg(test)]
+thread_local! {
+    static THREAD_CALLS: RefCell<BTreeMap<&'static str, u64>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Number of calls and errors recorded for a single API function.
+#[cfg(feature = "stats")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CallCount {
+    /// How many times the function was called
+    pub calls: u64,
+    /// How many of those calls returned an error
+    pub errors: u64,
+}
+
+/// A snapshot of the recorded API call statistics.
+#[cfg(feature = "stats")]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CallStats {
+    counts: BTreeMap<&'static str, CallCount>,
+}
+
+#[cfg(feature = "stats")]
+impl CallStats {
+    /// Returns the counters for the given function name, e.g. `"hipMemcpy"`.
+    pub fn get(&self, name: &str) -> Option<CallCount> {
+        self.counts.get(name).copied()
+    }
+
+    /// Iterates over all recorded functions in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, CallCount)> + '_ {
+        self.counts.iter().map(|(name, count)| (*name, *count))
+    }
+
+    /// Returns the total number of recorded calls.
+    pub fn total_calls(&self) -> u64 {
+        self.counts.values().map(|count| count.calls).sum()
+    }
+
+    /// Returns the total number of recorded errors.
+    pub fn total_errors(&self) -> u64 {
+        self.counts.values().map(|count| count.errors).sum()
+    }
+}
+
+/// Returns a snapshot of the API call statistics collected so far.
+///
+/// # Examples
+/// ```
+/// let stats = hip_rs::call_stats();
+/// for (name, count) in stats.iter() {
+///     println!("{}: {} calls, {} errors", name, count.calls, count.errors);
+/// }
+/// ```
+#[cfg(feature = "stats")]
+pub fn call_stats() -> CallStats {
+    let counts = CALL_STATS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    CallStats {
+        counts: counts.clone(),
+    }
+}
+
+/// Clears all collected API call statistics.
+#[cfg(feature = "stats")]
+pub fn reset_call_stats() {
+    CALL_STATS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
+
+/// Extracts the bare function name from a stringified call expression,
+/// e.g. `sys::hipMemcpy(dst, src, size, kind)` becomes `hipMemcpy`.
+#[cfg(any(test, feature = "stats"))]
+fn function_name(call: &'static str) -> &'static str {
+    let path = call.split('(').next().unwrap_or(call);
+    path.rsplit("::").next().unwrap_or(path).trim()
+}
+
+/// Evaluates a raw HIP or hipBLAS call, records it and returns its status code.
+///
+/// Every runtime and library call of the crate goes through this macro, so that
+/// [`call_stats`] sees all of them. It must be used in an `unsafe` context.
+macro_rules! record_call {
+    ($call:expr) => {{
+        let code = $call;
+        $crate::__record_call(stringify!($call), code == 0);
+        code
+    }};
+}
+
+/// Records a single wrapped API call. Used by `record_call!` and the `hip_call!`
+/// and `blas_call!` macros.
+#[doc(hidden)]
+#[inline]
+pub fn __record_call(call: &'static str, success: bool) {
+    #[cfg(feature = "stats")]
+    {
+        let mut counts = CALL_STATS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let count = counts.entry(function_name(call)).or_default();
+        count.calls += 1;
+        if !success {
+            count.errors += 1;
+        }
+    }
+
+    #[cfg(test)]
+    THREAD_CALLS.with(|calls| *calls.borrow_mut().entry(function_name(call)).or_default() += 1);
+
+    #[cfg(not(feature = "stats"))]
+    let _ = (call, success);
+}
+
+/// Returns how many times the current thread called the given function, e.g.
+/// `"hipMalloc"`, regardless of the `stats` feature.
+#[cfg(test)]
+pub(crate) fn thread_call_count(name: &str) -> u64 {
+    THREAD_CALLS.with(|calls| calls.borrow().get(name).copied().unwrap_or(0))
+}
+
+#[cfg(all(test, feature = "stats"))]
+mod tests {
+    use super::*;
+    use crate::core::HipResult;
+    use crate::result::ResultExt;
+    use crate::{gemm, hip_call, sys, BlasHandle, MemoryPointer, Operation};
+
+    #[test]
+    fn test_function_name() {
+        assert_eq!(
+            function_name("sys::hipMemcpy(dst, src, size, kind)"),
+            "hipMemcpy"
+        );
+        assert_eq!(
+            function_name("hipDeviceSynchronize()"),
+            "hipDeviceSynchronize"
+        );
+    }
+
+    #[test]
+    fn test_call_stats() {
+        let before = call_stats().get("hipGetDeviceCount").unwrap_or_default();
+        let before_set = call_stats().get("hipSetDevice").unwrap_or_default();
+
+        for _ in 0..3 {
+            let mut count = 0;
+            hip_call!(sys::hipGetDeviceCount(&mut count)).unwrap();
+        }
+        let result = hip_call!(sys::hipSetDevice(99));
+        assert!(result.is_err());
+
+        // Other tests may issue the same calls concurrently
+        let after = call_stats().get("hipGetDeviceCount").unwrap();
+        assert!(after.calls >= before.calls + 3);
+
+        let after_set = call_stats().get("hipSetDevice").unwrap();
+        assert!(after_set.calls > before_set.calls);
+        assert!(after_set.errors > before_set.errors);
+    }
+
+    #[test]
+    fn test_call_stats_library_calls() {
+        let before_malloc = call_stats().get("hipMalloc").unwrap_or_default();
+        let before_gemm = call_stats().get("hipblasSgemm").unwrap_or_default();
+
+        let handle = BlasHandle::new().unwrap();
+        let a = MemoryPointer::<f32>::alloc(4).unwrap();
+        let b = MemoryPointer::<f32>::alloc(4).unwrap();
+        let mut c = MemoryPointer::<f32>::alloc(4).unwrap();
+        gemm(
+            &handle,
+            Operation::None,
+            Operation::None,
+            2,
+            2,
+            2,
+            &1.0,
+            &a,
+            2,
+            &b,
+            2,
+            &0.0,
+            &mut c,
+            2,
+        )
+        .unwrap();
+
+        let after_malloc = call_stats().get("hipMalloc").unwrap();
+        assert!(after_malloc.calls >= before_malloc.calls + 3);
+
+        let after_gemm = call_stats().get("hipblasSgemm").unwrap();
+        assert!(after_gemm.calls > before_gemm.calls);
+    }
+}
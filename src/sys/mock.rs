@@ -0,0 +1,3440 @@
+//! Software stand-in for the HIP and hipBLAS bindings, enabled by the `mock` feature.
+//!
+//! Exposes the subset of the bindgen output used by the safe layer, with the same
+//! names and signatures, so the rest of the crate compiles unchanged. The backend
+//! reports a single device with 8 GiB of memory. Device memory is ordinary host
+//! memory, so allocation, copies and memsets behave as on hardware. Streams are
+//! synchronous. hipBLAS routines run a simple host reference implementation,
+//! except mixed precision GEMMs other than fp32, fp16 with fp32 output and
+//! int8, which only validate their arguments.
+//!
+//! Enum values only need to be distinct here and do not match the HIP headers.
+#![allow(clippy::missing_safety_doc)]
+
+use std::collections::{HashMap, HashSet};
+use std::os::raw::{c_char, c_int, c_uchar, c_uint, c_void};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+pub type hipError_t = c_uint;
+pub type hipblasStatus_t = c_uint;
+pub type hipblasOperation_t = c_uint;
+pub type hipblasDatatype_t = c_uint;
+pub type hipblasGemmAlgo_t = c_uint;
+pub type hipblasStride = i64;
+pub type hipblasPointerMode_t = c_uint;
+pub type hipblasAtomicsMode_t = c_uint;
+pub type hipblasFillMode_t = c_uint;
+pub type hipblasDiagType_t = c_uint;
+pub type hipblasSideMode_t = c_uint;
+pub type hipDeviceAttribute_t = c_uint;
+pub type hipDeviceP2PAttr = c_uint;
+pub type hipMemcpyKind = c_uint;
+pub type hipLimit_t = c_uint;
+pub const hipLimit_t_hipLimitStackSize: hipLimit_t = 0;
+pub const hipLimit_t_hipLimitPrintfFifoSize: hipLimit_t = 1;
+pub const hipLimit_t_hipLimitMallocHeapSize: hipLimit_t = 2;
+pub type hipMemPoolAttr = c_uint;
+pub const hipMemPoolAttr_hipMemPoolAttrReservedMemCurrent: hipMemPoolAttr = 5;
+pub const hipMemPoolAttr_hipMemPoolAttrReservedMemHigh: hipMemPoolAttr = 6;
+pub const hipMemPoolAttr_hipMemPoolAttrUsedMemCurrent: hipMemPoolAttr = 7;
+pub const hipMemPoolAttr_hipMemPoolAttrUsedMemHigh: hipMemPoolAttr = 8;
+pub type hipPointer_attribute = c_uint;
+pub type hipMemoryType = c_uint;
+pub type hipMemAllocationType = c_uint;
+pub type hipMemAllocationHandleType = c_uint;
+pub type hipMemLocationType = c_uint;
+
+pub const hipMemcpyKind_hipMemcpyHostToHost: hipMemcpyKind = 0;
+pub const hipMemcpyKind_hipMemcpyHostToDevice: hipMemcpyKind = 1;
+pub const hipMemcpyKind_hipMemcpyDeviceToHost: hipMemcpyKind = 2;
+pub const hipMemcpyKind_hipMemcpyDeviceToDevice: hipMemcpyKind = 3;
+pub const hipMemcpyKind_hipMemcpyDefault: hipMemcpyKind = 4;
+
+pub const hipDeviceP2PAttr_hipDevP2PAttrPerformanceRank: hipDeviceP2PAttr = 0;
+pub const hipDeviceP2PAttr_hipDevP2PAttrAccessSupported: hipDeviceP2PAttr = 1;
+pub const hipDeviceP2PAttr_hipDevP2PAttrNativeAtomicSupported: hipDeviceP2PAttr = 2;
+pub const hipDeviceP2PAttr_hipDevP2PAttrHipArrayAccessSupported: hipDeviceP2PAttr = 3;
+
+pub const hipMemoryType_hipMemoryTypeUnregistered: hipMemoryType = 0;
+pub const hipMemoryType_hipMemoryTypeHost: hipMemoryType = 1;
+pub const hipMemoryType_hipMemoryTypeDevice: hipMemoryType = 2;
+pub const hipMemoryType_hipMemoryTypeManaged: hipMemoryType = 3;
+pub const hipMemoryType_hipMemoryTypeUnified: hipMemoryType = 11;
+
+pub const hipPointer_attribute_HIP_POINTER_ATTRIBUTE_MEMORY_TYPE: hipPointer_attribute = 2;
+pub const hipPointer_attribute_HIP_POINTER_ATTRIBUTE_DEVICE_ORDINAL: hipPointer_attribute = 9;
+
+pub const hipDeviceAttribute_t_hipDeviceAttributeEccEnabled: hipDeviceAttribute_t = 0;
+pub const hipDeviceAttribute_t_hipDeviceAttributeAccessPolicyMaxWindowSize: hipDeviceAttribute_t =
+    1;
+pub const hipDeviceAttribute_t_hipDeviceAttributeAsyncEngineCount: hipDeviceAttribute_t = 2;
+pub const hipDeviceAttribute_t_hipDeviceAttributeCanMapHostMemory: hipDeviceAttribute_t = 3;
+pub const hipDeviceAttribute_t_hipDeviceAttributeCanUseHostPointerForRegisteredMem:
+    hipDeviceAttribute_t = 4;
+pub const hipDeviceAttribute_t_hipDeviceAttributeClockRate: hipDeviceAttribute_t = 5;
+pub const hipDeviceAttribute_t_hipDeviceAttributeComputeMode: hipDeviceAttribute_t = 6;
+pub const hipDeviceAttribute_t_hipDeviceAttributeComputePreemptionSupported: hipDeviceAttribute_t =
+    7;
+pub const hipDeviceAttribute_t_hipDeviceAttributeConcurrentKernels: hipDeviceAttribute_t = 8;
+pub const hipDeviceAttribute_t_hipDeviceAttributeConcurrentManagedAccess: hipDeviceAttribute_t = 9;
+pub const hipDeviceAttribute_t_hipDeviceAttributeCooperativeLaunch: hipDeviceAttribute_t = 10;
+pub const hipDeviceAttribute_t_hipDeviceAttributeCooperativeMultiDeviceLaunch:
+    hipDeviceAttribute_t = 11;
+pub const hipDeviceAttribute_t_hipDeviceAttributeDeviceOverlap: hipDeviceAttribute_t = 12;
+pub const hipDeviceAttribute_t_hipDeviceAttributeDirectManagedMemAccessFromHost:
+    hipDeviceAttribute_t = 13;
+pub const hipDeviceAttribute_t_hipDeviceAttributeGlobalL1CacheSupported: hipDeviceAttribute_t = 14;
+pub const hipDeviceAttribute_t_hipDeviceAttributeHostNativeAtomicSupported: hipDeviceAttribute_t =
+    15;
+pub const hipDeviceAttribute_t_hipDeviceAttributeIntegrated: hipDeviceAttribute_t = 16;
+pub const hipDeviceAttribute_t_hipDeviceAttributeIsMultiGpuBoard: hipDeviceAttribute_t = 17;
+pub const hipDeviceAttribute_t_hipDeviceAttributeKernelExecTimeout: hipDeviceAttribute_t = 18;
+pub const hipDeviceAttribute_t_hipDeviceAttributeL2CacheSize: hipDeviceAttribute_t = 19;
+pub const hipDeviceAttribute_t_hipDeviceAttributeLocalL1CacheSupported: hipDeviceAttribute_t = 20;
+pub const hipDeviceAttribute_t_hipDeviceAttributeLuid: hipDeviceAttribute_t = 21;
+pub const hipDeviceAttribute_t_hipDeviceAttributeLuidDeviceNodeMask: hipDeviceAttribute_t = 22;
+pub const hipDeviceAttribute_t_hipDeviceAttributeComputeCapabilityMajor: hipDeviceAttribute_t = 23;
+pub const hipDeviceAttribute_t_hipDeviceAttributeManagedMemory: hipDeviceAttribute_t = 24;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxBlocksPerMultiProcessor: hipDeviceAttribute_t =
+    25;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxBlockDimX: hipDeviceAttribute_t = 26;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxBlockDimY: hipDeviceAttribute_t = 27;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxBlockDimZ: hipDeviceAttribute_t = 28;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxGridDimX: hipDeviceAttribute_t = 29;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxGridDimY: hipDeviceAttribute_t = 30;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxGridDimZ: hipDeviceAttribute_t = 31;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxSurface1D: hipDeviceAttribute_t = 32;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxSurface1DLayered: hipDeviceAttribute_t = 33;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxSurface2D: hipDeviceAttribute_t = 34;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxSurface2DLayered: hipDeviceAttribute_t = 35;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxSurface3D: hipDeviceAttribute_t = 36;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxSurfaceCubemap: hipDeviceAttribute_t = 37;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxSurfaceCubemapLayered: hipDeviceAttribute_t =
+    38;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture1DWidth: hipDeviceAttribute_t = 39;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture1DLayered: hipDeviceAttribute_t = 40;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture1DLinear: hipDeviceAttribute_t = 41;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture1DMipmap: hipDeviceAttribute_t = 42;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture2DWidth: hipDeviceAttribute_t = 43;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture2DHeight: hipDeviceAttribute_t = 44;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture2DGather: hipDeviceAttribute_t = 45;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture2DLayered: hipDeviceAttribute_t = 46;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture2DLinear: hipDeviceAttribute_t = 47;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture2DMipmap: hipDeviceAttribute_t = 48;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture3DWidth: hipDeviceAttribute_t = 49;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture3DHeight: hipDeviceAttribute_t = 50;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture3DDepth: hipDeviceAttribute_t = 51;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTexture3DAlt: hipDeviceAttribute_t = 52;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTextureCubemap: hipDeviceAttribute_t = 53;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxTextureCubemapLayered: hipDeviceAttribute_t =
+    54;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxThreadsDim: hipDeviceAttribute_t = 55;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxThreadsPerBlock: hipDeviceAttribute_t = 56;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxThreadsPerMultiProcessor: hipDeviceAttribute_t =
+    57;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxPitch: hipDeviceAttribute_t = 58;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMemoryBusWidth: hipDeviceAttribute_t = 59;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMemoryClockRate: hipDeviceAttribute_t = 60;
+pub const hipDeviceAttribute_t_hipDeviceAttributeComputeCapabilityMinor: hipDeviceAttribute_t = 61;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMultiGpuBoardGroupID: hipDeviceAttribute_t = 62;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMultiprocessorCount: hipDeviceAttribute_t = 63;
+pub const hipDeviceAttribute_t_hipDeviceAttributePageableMemoryAccess: hipDeviceAttribute_t = 64;
+pub const hipDeviceAttribute_t_hipDeviceAttributePageableMemoryAccessUsesHostPageTables:
+    hipDeviceAttribute_t = 65;
+pub const hipDeviceAttribute_t_hipDeviceAttributePciBusId: hipDeviceAttribute_t = 66;
+pub const hipDeviceAttribute_t_hipDeviceAttributePciDeviceId: hipDeviceAttribute_t = 67;
+pub const hipDeviceAttribute_t_hipDeviceAttributePciDomainID: hipDeviceAttribute_t = 68;
+pub const hipDeviceAttribute_t_hipDeviceAttributePersistingL2CacheMaxSize: hipDeviceAttribute_t =
+    69;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxRegistersPerBlock: hipDeviceAttribute_t = 70;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxRegistersPerMultiprocessor:
+    hipDeviceAttribute_t = 71;
+pub const hipDeviceAttribute_t_hipDeviceAttributeReservedSharedMemPerBlock: hipDeviceAttribute_t =
+    72;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxSharedMemoryPerBlock: hipDeviceAttribute_t = 73;
+pub const hipDeviceAttribute_t_hipDeviceAttributeSharedMemPerBlockOptin: hipDeviceAttribute_t = 74;
+pub const hipDeviceAttribute_t_hipDeviceAttributeSharedMemPerMultiprocessor: hipDeviceAttribute_t =
+    75;
+pub const hipDeviceAttribute_t_hipDeviceAttributeSingleToDoublePrecisionPerfRatio:
+    hipDeviceAttribute_t = 76;
+pub const hipDeviceAttribute_t_hipDeviceAttributeStreamPrioritiesSupported: hipDeviceAttribute_t =
+    77;
+pub const hipDeviceAttribute_t_hipDeviceAttributeSurfaceAlignment: hipDeviceAttribute_t = 78;
+pub const hipDeviceAttribute_t_hipDeviceAttributeTccDriver: hipDeviceAttribute_t = 79;
+pub const hipDeviceAttribute_t_hipDeviceAttributeTextureAlignment: hipDeviceAttribute_t = 80;
+pub const hipDeviceAttribute_t_hipDeviceAttributeTexturePitchAlignment: hipDeviceAttribute_t = 81;
+pub const hipDeviceAttribute_t_hipDeviceAttributeTotalConstantMemory: hipDeviceAttribute_t = 82;
+pub const hipDeviceAttribute_t_hipDeviceAttributeTotalGlobalMem: hipDeviceAttribute_t = 83;
+pub const hipDeviceAttribute_t_hipDeviceAttributeUnifiedAddressing: hipDeviceAttribute_t = 84;
+pub const hipDeviceAttribute_t_hipDeviceAttributeWarpSize: hipDeviceAttribute_t = 85;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMemoryPoolsSupported: hipDeviceAttribute_t = 86;
+pub const hipDeviceAttribute_t_hipDeviceAttributeVirtualMemoryManagementSupported:
+    hipDeviceAttribute_t = 87;
+pub const hipDeviceAttribute_t_hipDeviceAttributeHostRegisterSupported: hipDeviceAttribute_t = 88;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMemoryPoolSupportedHandleTypes:
+    hipDeviceAttribute_t = 89;
+pub const hipDeviceAttribute_t_hipDeviceAttributeClockInstructionRate: hipDeviceAttribute_t = 90;
+pub const hipDeviceAttribute_t_hipDeviceAttributeMaxSharedMemoryPerMultiprocessor:
+    hipDeviceAttribute_t = 91;
+pub const hipDeviceAttribute_t_hipDeviceAttributeHdpMemFlushCntl: hipDeviceAttribute_t = 92;
+pub const hipDeviceAttribute_t_hipDeviceAttributeHdpRegFlushCntl: hipDeviceAttribute_t = 93;
+pub const hipDeviceAttribute_t_hipDeviceAttributeCooperativeMultiDeviceUnmatchedFunc:
+    hipDeviceAttribute_t = 94;
+pub const hipDeviceAttribute_t_hipDeviceAttributeCooperativeMultiDeviceUnmatchedGridDim:
+    hipDeviceAttribute_t = 95;
+pub const hipDeviceAttribute_t_hipDeviceAttributeCooperativeMultiDeviceUnmatchedBlockDim:
+    hipDeviceAttribute_t = 96;
+pub const hipDeviceAttribute_t_hipDeviceAttributeCooperativeMultiDeviceUnmatchedSharedMem:
+    hipDeviceAttribute_t = 97;
+pub const hipDeviceAttribute_t_hipDeviceAttributeIsLargeBar: hipDeviceAttribute_t = 98;
+pub const hipDeviceAttribute_t_hipDeviceAttributeAsicRevision: hipDeviceAttribute_t = 99;
+pub const hipDeviceAttribute_t_hipDeviceAttributeCanUseStreamWaitValue: hipDeviceAttribute_t = 100;
+pub const hipDeviceAttribute_t_hipDeviceAttributeImageSupport: hipDeviceAttribute_t = 101;
+pub const hipDeviceAttribute_t_hipDeviceAttributePhysicalMultiProcessorCount: hipDeviceAttribute_t =
+    102;
+pub const hipDeviceAttribute_t_hipDeviceAttributeFineGrainSupport: hipDeviceAttribute_t = 103;
+pub const hipDeviceAttribute_t_hipDeviceAttributeWallClockRate: hipDeviceAttribute_t = 104;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ihipStream_t {
+    _unused: [u8; 0],
+}
+pub type hipStream_t = *mut ihipStream_t;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ihipModule_t {
+    _unused: [u8; 0],
+}
+pub type hipModule_t = *mut ihipModule_t;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ihipModuleSymbol_t {
+    _unused: [u8; 0],
+}
+pub type hipFunction_t = *mut ihipModuleSymbol_t;
+pub type hipHostFn_t = Option<unsafe extern "C" fn(user_data: *mut c_void)>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ihipMemPoolHandle_t {
+    _unused: [u8; 0],
+}
+pub type hipMemPool_t = *mut ihipMemPoolHandle_t;
+
+pub type hipblasHandle_t = *mut c_void;
+pub type hipblasHalf = u16;
+pub type hipDeviceptr_t = *mut c_void;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct hipblasComplex {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct hipblasDoubleComplex {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct hipUUID_t {
+    pub bytes: [c_char; 16usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct hipMemLocation {
+    pub type_: hipMemLocationType,
+    pub id: c_int,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct hipMemPoolProps {
+    pub allocType: hipMemAllocationType,
+    pub handleTypes: hipMemAllocationHandleType,
+    pub location: hipMemLocation,
+    pub win32SecurityAttributes: *mut c_void,
+    pub maxSize: usize,
+    pub reserved: [u8; 56usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hipMemPoolPtrExportData {
+    pub reserved: [c_uchar; 64usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hipPointerAttribute_t {
+    pub type_: hipMemoryType,
+    pub device: c_int,
+    pub devicePointer: *mut c_void,
+    pub hostPointer: *mut c_void,
+    pub isManaged: c_int,
+    pub allocationFlags: c_uint,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hipIpcMemHandle_t {
+    pub reserved: [c_char; 64usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hipIpcEventHandle_t {
+    pub reserved: [c_char; 64usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ihipEvent_t {
+    _unused: [u8; 0],
+}
+pub type hipEvent_t = *mut ihipEvent_t;
+
+/// Only the fields read by the safe layer are modelled.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hipDeviceProp_tR0600 {
+    pub luid: [c_char; 8usize],
+    pub luidDeviceNodeMask: c_uint,
+}
+
+const hipSuccess: hipError_t = 0;
+const hipErrorInvalidValue: hipError_t = 1;
+const hipErrorInvalidDevice: hipError_t = 101;
+const hipErrorInvalidImage: hipError_t = 200;
+const hipErrorFileNotFound: hipError_t = 301;
+const hipErrorInvalidHandle: hipError_t = 400;
+const hipErrorNotFound: hipError_t = 500;
+const HIPBLAS_STATUS_SUCCESS: hipblasStatus_t = 0;
+const HIPBLAS_STATUS_NOT_INITIALIZED: hipblasStatus_t = 1;
+const HIPBLAS_STATUS_INVALID_VALUE: hipblasStatus_t = 3;
+const HIPBLAS_OP_N: hipblasOperation_t = 111;
+const HIPBLAS_OP_C: hipblasOperation_t = 113;
+const HIPBLAS_FILL_MODE_UPPER: hipblasFillMode_t = 121;
+const HIPBLAS_DIAG_UNIT: hipblasDiagType_t = 132;
+const HIPBLAS_SIDE_LEFT: hipblasSideMode_t = 141;
+const HIPBLAS_R_16F: hipblasDatatype_t = 150;
+const HIPBLAS_R_32F: hipblasDatatype_t = 151;
+const HIPBLAS_R_8I: hipblasDatatype_t = 160;
+const HIPBLAS_R_32I: hipblasDatatype_t = 162;
+
+/// Total memory reported for the mock device.
+const TOTAL_MEM: usize = 8 << 30;
+/// Alignment of mock device allocations, matching HIP's minimum.
+const ALLOC_ALIGN: usize = 256;
+const DEVICE_NAME: &str = "HIP Mock Device";
+const PCI_BUS_ID: &str = "0000:00:00.0";
+
+/// Live device allocations keyed by address, with their size in bytes.
+static ALLOCATIONS: Mutex<Option<HashMap<usize, usize>>> = Mutex::new(None);
+/// Live pinned host allocations keyed by address, with their size in bytes.
+static HOST_ALLOCATIONS: Mutex<Option<HashMap<usize, usize>>> = Mutex::new(None);
+/// Addresses of live device allocations made with `hipMallocManaged`.
+static MANAGED_ALLOCATIONS: Mutex<Option<HashSet<usize>>> = Mutex::new(None);
+
+fn with_allocations<R>(f: impl FnOnce(&mut HashMap<usize, usize>) -> R) -> R {
+    let mut allocations = ALLOCATIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(allocations.get_or_insert_with(HashMap::new))
+}
+
+fn with_host_allocations<R>(f: impl FnOnce(&mut HashMap<usize, usize>) -> R) -> R {
+    let mut allocations = HOST_ALLOCATIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(allocations.get_or_insert_with(HashMap::new))
+}
+
+fn with_managed_allocations<R>(f: impl FnOnce(&mut HashSet<usize>) -> R) -> R {
+    let mut allocations = MANAGED_ALLOCATIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(allocations.get_or_insert_with(HashSet::new))
+}
+
+fn layout(size: usize) -> std::alloc::Layout {
+    std::alloc::Layout::from_size_align(size.max(1), ALLOC_ALIGN).unwrap()
+}
+
+unsafe fn allocate(ptr: *mut *mut c_void, size: usize) -> hipError_t {
+    if ptr.is_null() {
+        return hipErrorInvalidValue;
+    }
+    if size == 0 {
+        *ptr = std::ptr::null_mut();
+        return hipSuccess;
+    }
+
+    if in_use() + size > TOTAL_MEM {
+        return 2; // hipErrorOutOfMemory
+    }
+
+    let address = std::alloc::alloc_zeroed(layout(size));
+    if address.is_null() {
+        return 2; // hipErrorOutOfMemory
+    }
+    with_allocations(|allocations| allocations.insert(address as usize, size));
+    record_pool_usage();
+    *ptr = address as *mut c_void;
+    hipSuccess
+}
+
+thread_local! {
+    /// The last error returned on this thread, as reported by `hipGetLastError`.
+    static LAST_ERROR: std::cell::Cell<hipError_t> = const { std::cell::Cell::new(hipSuccess) };
+}
+
+fn check_device(device: c_int) -> hipError_t {
+    if device == 0 {
+        hipSuccess
+    } else {
+        LAST_ERROR.with(|error| error.set(hipErrorInvalidDevice));
+        hipErrorInvalidDevice
+    }
+}
+
+pub unsafe fn hipGetLastError() -> hipError_t {
+    LAST_ERROR.with(|error| error.replace(hipSuccess))
+}
+
+pub unsafe fn hipGetErrorString(error: hipError_t) -> *const c_char {
+    let message: &'static [u8] = match error {
+        hipSuccess => b"no error\0",
+        hipErrorInvalidValue => b"invalid argument\0",
+        hipErrorInvalidDevice => b"invalid device ordinal\0",
+        _ => b"unknown error\0",
+    };
+    message.as_ptr() as *const c_char
+}
+
+/// Writes `value` as a NUL terminated string into a buffer of `len` bytes.
+unsafe fn write_c_str(buffer: *mut c_char, len: c_int, value: &str) -> hipError_t {
+    if buffer.is_null() || len <= value.len() as c_int {
+        return hipErrorInvalidValue;
+    }
+    std::ptr::copy_nonoverlapping(value.as_ptr() as *const c_char, buffer, value.len());
+    *buffer.add(value.len()) = 0;
+    hipSuccess
+}
+
+/// Hands out distinct, non-null fake handles for streams, pools and hipBLAS.
+fn unique_handle<T>() -> *mut T {
+    static NEXT: AtomicUsize = AtomicUsize::new(1);
+    (NEXT.fetch_add(1, Ordering::Relaxed) * ALLOC_ALIGN) as *mut T
+}
+
+pub unsafe fn hipInit(_flags: c_uint) -> hipError_t {
+    hipSuccess
+}
+
+pub unsafe fn hipRuntimeGetVersion(version: *mut c_int) -> hipError_t {
+    *version = 60_200_000;
+    hipSuccess
+}
+
+pub unsafe fn hipDeviceSynchronize() -> hipError_t {
+    hipSuccess
+}
+
+pub unsafe fn hipDeviceReset() -> hipError_t {
+    // Like the runtime, a reset releases every allocation on the device
+    with_allocations(|allocations| {
+        for (address, size) in allocations.drain() {
+            std::alloc::dealloc(address as *mut u8, layout(size));
+        }
+    });
+    hipSuccess
+}
+
+pub unsafe fn hipSetDevice(device: c_int) -> hipError_t {
+    check_device(device)
+}
+
+pub unsafe fn hipGetDevice(device: *mut c_int) -> hipError_t {
+    *device = 0;
+    hipSuccess
+}
+
+pub unsafe fn hipGetDeviceCount(count: *mut c_int) -> hipError_t {
+    *count = 1;
+    hipSuccess
+}
+
+pub unsafe fn hipDeviceGetAttribute(
+    value: *mut c_int,
+    attr: hipDeviceAttribute_t,
+    device: c_int,
+) -> hipError_t {
+    let code = check_device(device);
+    if code != hipSuccess {
+        return code;
+    }
+
+    *value = match attr {
+        hipDeviceAttribute_t_hipDeviceAttributeWarpSize => 64,
+        hipDeviceAttribute_t_hipDeviceAttributeMultiprocessorCount => 104,
+        hipDeviceAttribute_t_hipDeviceAttributeClockRate => 1_700_000,
+        hipDeviceAttribute_t_hipDeviceAttributeMemoryClockRate => 1_600_000,
+        hipDeviceAttribute_t_hipDeviceAttributeWallClockRate => 100_000,
+        hipDeviceAttribute_t_hipDeviceAttributeMaxThreadsPerBlock => 1024,
+        hipDeviceAttribute_t_hipDeviceAttributeAsyncEngineCount => 2,
+        hipDeviceAttribute_t_hipDeviceAttributeComputeCapabilityMajor => 9,
+        hipDeviceAttribute_t_hipDeviceAttributeL2CacheSize => 8 << 20,
+        hipDeviceAttribute_t_hipDeviceAttributeManagedMemory => 1,
+        hipDeviceAttribute_t_hipDeviceAttributeConcurrentKernels => 1,
+        hipDeviceAttribute_t_hipDeviceAttributeTextureAlignment => 256,
+        hipDeviceAttribute_t_hipDeviceAttributeTexturePitchAlignment => 256,
+        hipDeviceAttribute_t_hipDeviceAttributeSingleToDoublePrecisionPerfRatio => 2,
+        _ => 0,
+    };
+    hipSuccess
+}
+
+pub unsafe fn hipDeviceComputeCapability(
+    major: *mut c_int,
+    minor: *mut c_int,
+    device: c_int,
+) -> hipError_t {
+    *major = 9;
+    *minor = 0;
+    check_device(device)
+}
+
+pub unsafe fn hipDeviceTotalMem(bytes: *mut usize, device: c_int) -> hipError_t {
+    *bytes = TOTAL_MEM;
+    check_device(device)
+}
+
+pub unsafe fn hipDeviceGetName(name: *mut c_char, len: c_int, device: c_int) -> hipError_t {
+    let code = check_device(device);
+    if code != hipSuccess {
+        return code;
+    }
+    write_c_str(name, len, DEVICE_NAME)
+}
+
+pub unsafe fn hipDeviceGetUuid(_uuid: *mut hipUUID_t, device: c_int) -> hipError_t {
+    check_device(device)
+}
+
+pub unsafe fn hipGetDevicePropertiesR0600(
+    _props: *mut hipDeviceProp_tR0600,
+    device: c_int,
+) -> hipError_t {
+    check_device(device)
+}
+
+pub unsafe fn hipDeviceGetPCIBusId(id: *mut c_char, len: c_int, device: c_int) -> hipError_t {
+    let code = check_device(device);
+    if code != hipSuccess {
+        return code;
+    }
+    write_c_str(id, len, PCI_BUS_ID)
+}
+
+pub unsafe fn hipDeviceGetByPCIBusId(device: *mut c_int, id: *const c_char) -> hipError_t {
+    if std::ffi::CStr::from_ptr(id).to_bytes() != PCI_BUS_ID.as_bytes() {
+        return hipErrorInvalidValue;
+    }
+    *device = 0;
+    hipSuccess
+}
+
+pub unsafe fn hipDeviceGetP2PAttribute(
+    value: *mut c_int,
+    _attr: hipDeviceP2PAttr,
+    src: c_int,
+    dst: c_int,
+) -> hipError_t {
+    // There is no peer to talk to
+    *value = 0;
+    if src == dst {
+        return hipErrorInvalidDevice;
+    }
+    check_device(src) | check_device(dst)
+}
+
+// The only device cannot be its own peer, and there is no other
+pub unsafe fn hipDeviceEnablePeerAccess(_peer_device_id: c_int, _flags: c_uint) -> hipError_t {
+    hipErrorInvalidDevice
+}
+
+pub unsafe fn hipDeviceDisablePeerAccess(_peer_device_id: c_int) -> hipError_t {
+    hipErrorInvalidDevice
+}
+
+static LIMITS: Mutex<Option<HashMap<hipLimit_t, usize>>> = Mutex::new(None);
+
+pub unsafe fn hipDeviceSetLimit(limit: hipLimit_t, value: usize) -> hipError_t {
+    let mut limits = LIMITS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    limits.get_or_insert_with(HashMap::new).insert(limit, value);
+    hipSuccess
+}
+
+pub unsafe fn hipDeviceGetLimit(value: *mut usize, limit: hipLimit_t) -> hipError_t {
+    let limits = LIMITS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    // Unset limits report the 1 MiB default of the printf FIFO and malloc heap
+    *value = limits
+        .as_ref()
+        .and_then(|limits| limits.get(&limit))
+        .copied()
+        .unwrap_or(1 << 20);
+    hipSuccess
+}
+
+pub unsafe fn hipDeviceGetDefaultMemPool(pool: *mut hipMemPool_t, device: c_int) -> hipError_t {
+    *pool = unique_handle();
+    check_device(device)
+}
+
+pub unsafe fn hipDeviceSetMemPool(device: c_int, _pool: hipMemPool_t) -> hipError_t {
+    check_device(device)
+}
+
+pub unsafe fn hipMemPoolCreate(
+    pool: *mut hipMemPool_t,
+    _props: *const hipMemPoolProps,
+) -> hipError_t {
+    *pool = unique_handle();
+    hipSuccess
+}
+
+pub unsafe fn hipMemPoolDestroy(_pool: hipMemPool_t) -> hipError_t {
+    hipSuccess
+}
+
+pub unsafe fn hipMemPoolExportPointer(
+    export_data: *mut hipMemPoolPtrExportData,
+    ptr: *mut c_void,
+) -> hipError_t {
+    if !with_allocations(|allocations| allocations.contains_key(&(ptr as usize))) {
+        return hipErrorInvalidValue;
+    }
+    // As with IPC handles, the export data is simply the address
+    let reserved = &mut (*export_data).reserved;
+    *reserved = [0; 64];
+    reserved[..std::mem::size_of::<usize>()].copy_from_slice(&(ptr as usize).to_le_bytes());
+    hipSuccess
+}
+
+pub unsafe fn hipMemPoolImportPointer(
+    ptr: *mut *mut c_void,
+    _pool: hipMemPool_t,
+    export_data: *mut hipMemPoolPtrExportData,
+) -> hipError_t {
+    let mut address = [0u8; std::mem::size_of::<usize>()];
+    let reserved = &(*export_data).reserved;
+    address.copy_from_slice(&reserved[..std::mem::size_of::<usize>()]);
+    let address = usize::from_le_bytes(address);
+    if !with_allocations(|allocations| allocations.contains_key(&address)) {
+        return hipErrorInvalidValue;
+    }
+    *ptr = address as *mut c_void;
+    hipSuccess
+}
+
+/// Peak bytes allocated since the last reset, shared by every mock pool.
+static POOL_USED_HIGH: Mutex<usize> = Mutex::new(0);
+
+fn in_use() -> usize {
+    with_allocations(|allocations| allocations.values().sum())
+}
+
+fn record_pool_usage() {
+    let mut high = POOL_USED_HIGH
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *high = (*high).max(in_use());
+}
+
+pub unsafe fn hipMemPoolGetAttribute(
+    _pool: hipMemPool_t,
+    attr: hipMemPoolAttr,
+    value: *mut c_void,
+) -> hipError_t {
+    let bytes = match attr {
+        hipMemPoolAttr_hipMemPoolAttrReservedMemCurrent
+        | hipMemPoolAttr_hipMemPoolAttrUsedMemCurrent => in_use(),
+        hipMemPoolAttr_hipMemPoolAttrReservedMemHigh | hipMemPoolAttr_hipMemPoolAttrUsedMemHigh => {
+            *POOL_USED_HIGH
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+        }
+        _ => return hipErrorInvalidValue,
+    };
+    *(value as *mut u64) = bytes as u64;
+    hipSuccess
+}
+
+pub unsafe fn hipMemPoolSetAttribute(
+    _pool: hipMemPool_t,
+    attr: hipMemPoolAttr,
+    value: *mut c_void,
+) -> hipError_t {
+    match attr {
+        hipMemPoolAttr_hipMemPoolAttrReservedMemHigh | hipMemPoolAttr_hipMemPoolAttrUsedMemHigh
+            if *(value as *const u64) == 0 =>
+        {
+            *POOL_USED_HIGH
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = in_use();
+            hipSuccess
+        }
+        _ => hipErrorInvalidValue,
+    }
+}
+
+pub unsafe fn hipMalloc(ptr: *mut *mut c_void, size: usize) -> hipError_t {
+    allocate(ptr, size)
+}
+
+pub unsafe fn hipExtMallocWithFlags(
+    ptr: *mut *mut c_void,
+    size: usize,
+    _flags: c_uint,
+) -> hipError_t {
+    allocate(ptr, size)
+}
+
+pub unsafe fn hipMallocAsync(
+    ptr: *mut *mut c_void,
+    size: usize,
+    _stream: hipStream_t,
+) -> hipError_t {
+    allocate(ptr, size)
+}
+
+pub unsafe fn hipMallocFromPoolAsync(
+    ptr: *mut *mut c_void,
+    size: usize,
+    _pool: hipMemPool_t,
+    _stream: hipStream_t,
+) -> hipError_t {
+    allocate(ptr, size)
+}
+
+pub unsafe fn hipMallocManaged(ptr: *mut *mut c_void, size: usize, _flags: c_uint) -> hipError_t {
+    let code = allocate(ptr, size);
+    if code == hipSuccess {
+        with_managed_allocations(|allocations| allocations.insert(*ptr as usize));
+    }
+    code
+}
+
+pub unsafe fn hipFree(ptr: *mut c_void) -> hipError_t {
+    if ptr.is_null() {
+        return hipSuccess;
+    }
+    match with_allocations(|allocations| allocations.remove(&(ptr as usize))) {
+        Some(size) => {
+            with_managed_allocations(|allocations| allocations.remove(&(ptr as usize)));
+            std::alloc::dealloc(ptr as *mut u8, layout(size));
+            hipSuccess
+        }
+        None => hipErrorInvalidValue,
+    }
+}
+
+pub unsafe fn hipHostMalloc(ptr: *mut *mut c_void, size: usize, _flags: c_uint) -> hipError_t {
+    if ptr.is_null() {
+        return hipErrorInvalidValue;
+    }
+    let address = std::alloc::alloc_zeroed(layout(size));
+    if address.is_null() {
+        return 2; // hipErrorOutOfMemory
+    }
+    with_host_allocations(|allocations| allocations.insert(address as usize, size));
+    *ptr = address as *mut c_void;
+    hipSuccess
+}
+
+pub unsafe fn hipHostFree(ptr: *mut c_void) -> hipError_t {
+    if ptr.is_null() {
+        return hipSuccess;
+    }
+    match with_host_allocations(|allocations| allocations.remove(&(ptr as usize))) {
+        Some(size) => {
+            std::alloc::dealloc(ptr as *mut u8, layout(size));
+            hipSuccess
+        }
+        None => hipErrorInvalidValue,
+    }
+}
+
+pub unsafe fn hipMemGetInfo(free: *mut usize, total: *mut usize) -> hipError_t {
+    let in_use: usize = with_allocations(|allocations| allocations.values().sum());
+    *free = TOTAL_MEM - in_use;
+    *total = TOTAL_MEM;
+    hipSuccess
+}
+
+pub unsafe fn hipMemcpy(
+    dst: *mut c_void,
+    src: *const c_void,
+    size: usize,
+    _kind: hipMemcpyKind,
+) -> hipError_t {
+    if size > 0 && (dst.is_null() || src.is_null()) {
+        return hipErrorInvalidValue;
+    }
+    std::ptr::copy(src as *const u8, dst as *mut u8, size);
+    hipSuccess
+}
+
+pub unsafe fn hipMemcpy2D(
+    dst: *mut c_void,
+    dpitch: usize,
+    src: *const c_void,
+    spitch: usize,
+    width: usize,
+    height: usize,
+    _kind: hipMemcpyKind,
+) -> hipError_t {
+    if width > dpitch || width > spitch {
+        return hipErrorInvalidValue;
+    }
+    if width > 0 && height > 0 && (dst.is_null() || src.is_null()) {
+        return hipErrorInvalidValue;
+    }
+    for row in 0..height {
+        std::ptr::copy(
+            (src as *const u8).add(row * spitch),
+            (dst as *mut u8).add(row * dpitch),
+            width,
+        );
+    }
+    hipSuccess
+}
+
+pub unsafe fn hipMemcpyAsync(
+    dst: *mut c_void,
+    src: *const c_void,
+    size: usize,
+    kind: hipMemcpyKind,
+    _stream: hipStream_t,
+) -> hipError_t {
+    hipMemcpy(dst, src, size, kind)
+}
+
+pub unsafe fn hipMemcpyPeer(
+    dst: *mut c_void,
+    dst_device: c_int,
+    src: *const c_void,
+    src_device: c_int,
+    size: usize,
+) -> hipError_t {
+    let code = check_device(dst_device) | check_device(src_device);
+    if code != hipSuccess {
+        return code;
+    }
+    hipMemcpy(dst, src, size, hipMemcpyKind_hipMemcpyDeviceToDevice)
+}
+
+pub unsafe fn hipMemset(dst: *mut c_void, value: c_int, size: usize) -> hipError_t {
+    if size > 0 && dst.is_null() {
+        return hipErrorInvalidValue;
+    }
+    std::ptr::write_bytes(dst as *mut u8, value as u8, size);
+    hipSuccess
+}
+
+pub unsafe fn hipMemsetAsync(
+    dst: *mut c_void,
+    value: c_int,
+    size: usize,
+    _stream: hipStream_t,
+) -> hipError_t {
+    hipMemset(dst, value, size)
+}
+
+pub unsafe fn hipStreamAttachMemAsync(
+    _stream: hipStream_t,
+    dev_ptr: *mut c_void,
+    _length: usize,
+    _flags: c_uint,
+) -> hipError_t {
+    if with_allocations(|allocations| allocations.contains_key(&(dev_ptr as usize))) {
+        hipSuccess
+    } else {
+        hipErrorInvalidValue
+    }
+}
+
+pub unsafe fn hipMemPrefetchAsync(
+    _ptr: *const c_void,
+    _count: usize,
+    _device: c_int,
+    _stream: hipStream_t,
+) -> hipError_t {
+    hipSuccess
+}
+
+/// Finds the memory type of the allocation containing `ptr`.
+fn allocation_memory_type(ptr: usize) -> Option<hipMemoryType> {
+    let base = |allocations: &mut HashMap<usize, usize>| {
+        allocations
+            .iter()
+            .find(|(&address, &size)| (address..address + size).contains(&ptr))
+            .map(|(&address, _)| address)
+    };
+    if let Some(address) = with_allocations(base) {
+        let memory_type = if with_managed_allocations(|managed| managed.contains(&address)) {
+            hipMemoryType_hipMemoryTypeManaged
+        } else {
+            hipMemoryType_hipMemoryTypeDevice
+        };
+        Some(memory_type)
+    } else {
+        with_host_allocations(base).map(|_| hipMemoryType_hipMemoryTypeHost)
+    }
+}
+
+pub unsafe fn hipPointerGetAttribute(
+    data: *mut c_void,
+    attribute: hipPointer_attribute,
+    ptr: hipDeviceptr_t,
+) -> hipError_t {
+    let Some(memory_type) = allocation_memory_type(ptr as usize) else {
+        return hipErrorInvalidValue;
+    };
+
+    match attribute {
+        hipPointer_attribute_HIP_POINTER_ATTRIBUTE_MEMORY_TYPE => {
+            *(data as *mut c_uint) = memory_type;
+        }
+        hipPointer_attribute_HIP_POINTER_ATTRIBUTE_DEVICE_ORDINAL
+            if memory_type != hipMemoryType_hipMemoryTypeHost =>
+        {
+            *(data as *mut c_int) = 0;
+        }
+        _ => return hipErrorInvalidValue,
+    }
+    hipSuccess
+}
+
+pub unsafe fn hipPointerGetAttributes(
+    attributes: *mut hipPointerAttribute_t,
+    ptr: *const c_void,
+) -> hipError_t {
+    let Some(memory_type) = allocation_memory_type(ptr as usize) else {
+        return hipErrorInvalidValue;
+    };
+
+    // Device memory is host memory here, so both views share the address
+    let (device_pointer, host_pointer) = match memory_type {
+        hipMemoryType_hipMemoryTypeDevice => (ptr as *mut c_void, std::ptr::null_mut()),
+        _ => (ptr as *mut c_void, ptr as *mut c_void),
+    };
+    *attributes = hipPointerAttribute_t {
+        type_: memory_type,
+        device: 0,
+        devicePointer: device_pointer,
+        hostPointer: host_pointer,
+        isManaged: (memory_type == hipMemoryType_hipMemoryTypeManaged) as c_int,
+        allocationFlags: 0,
+    };
+    hipSuccess
+}
+
+pub unsafe fn hipMemGetAddressRange(
+    pbase: *mut hipDeviceptr_t,
+    psize: *mut usize,
+    dptr: hipDeviceptr_t,
+) -> hipError_t {
+    let ptr = dptr as usize;
+    let range = with_allocations(|allocations| {
+        allocations
+            .iter()
+            .find(|(&address, &size)| (address..address + size.max(1)).contains(&ptr))
+            .map(|(&address, &size)| (address, size))
+    });
+    match range {
+        Some((address, size)) => {
+            *pbase = address as hipDeviceptr_t;
+            *psize = size;
+            hipSuccess
+        }
+        None => hipErrorInvalidValue,
+    }
+}
+
+pub unsafe fn hipIpcGetMemHandle(handle: *mut hipIpcMemHandle_t, ptr: *mut c_void) -> hipError_t {
+    if !with_allocations(|allocations| allocations.contains_key(&(ptr as usize))) {
+        return hipErrorInvalidValue;
+    }
+    // The "handle" is simply the address, as every process shares this memory
+    let address = (ptr as usize).to_le_bytes();
+    (*handle).reserved = [0; 64];
+    for (dst, src) in (*handle).reserved.iter_mut().zip(address) {
+        *dst = src as c_char;
+    }
+    hipSuccess
+}
+
+pub unsafe fn hipIpcOpenMemHandle(
+    ptr: *mut *mut c_void,
+    handle: hipIpcMemHandle_t,
+    _flags: c_uint,
+) -> hipError_t {
+    let mut address = [0u8; std::mem::size_of::<usize>()];
+    for (dst, src) in address.iter_mut().zip(handle.reserved) {
+        *dst = src as u8;
+    }
+    *ptr = usize::from_le_bytes(address) as *mut c_void;
+    hipSuccess
+}
+
+pub unsafe fn hipIpcCloseMemHandle(_ptr: *mut c_void) -> hipError_t {
+    hipSuccess
+}
+
+/// `hipEventDisableTiming`
+const EVENT_DISABLE_TIMING: c_uint = 0x2;
+
+/// Live events keyed by handle, with their flags and the time they were last recorded.
+type Events = HashMap<usize, (c_uint, Option<Instant>)>;
+static EVENTS: Mutex<Option<Events>> = Mutex::new(None);
+
+fn with_events<R>(f: impl FnOnce(&mut Events) -> R) -> R {
+    let mut events = EVENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(events.get_or_insert_with(HashMap::new))
+}
+
+pub unsafe fn hipEventCreate(event: *mut hipEvent_t) -> hipError_t {
+    hipEventCreateWithFlags(event, 0)
+}
+
+pub unsafe fn hipEventCreateWithFlags(event: *mut hipEvent_t, flags: c_uint) -> hipError_t {
+    *event = unique_handle();
+    with_events(|events| events.insert(*event as usize, (flags, None)));
+    hipSuccess
+}
+
+pub unsafe fn hipEventDestroy(event: hipEvent_t) -> hipError_t {
+    with_events(|events| events.remove(&(event as usize)));
+    hipSuccess
+}
+
+pub unsafe fn hipEventRecord(event: hipEvent_t, _stream: hipStream_t) -> hipError_t {
+    // Work completes immediately, so the event completes as it is recorded
+    with_events(|events| match events.get_mut(&(event as usize)) {
+        Some((_, recorded)) => {
+            *recorded = Some(Instant::now());
+            hipSuccess
+        }
+        None => hipErrorInvalidHandle,
+    })
+}
+
+pub unsafe fn hipEventSynchronize(event: hipEvent_t) -> hipError_t {
+    if with_events(|events| events.contains_key(&(event as usize))) {
+        hipSuccess
+    } else {
+        hipErrorInvalidHandle
+    }
+}
+
+pub unsafe fn hipEventElapsedTime(ms: *mut f32, start: hipEvent_t, stop: hipEvent_t) -> hipError_t {
+    let recorded =
+        |events: &mut HashMap<usize, (c_uint, Option<Instant>)>, event: hipEvent_t| match events
+            .get(&(event as usize))
+        {
+            Some(&(flags, Some(time))) if flags & EVENT_DISABLE_TIMING == 0 => Some(time),
+            _ => None,
+        };
+    let times = with_events(|events| (recorded(events, start), recorded(events, stop)));
+    match times {
+        (Some(start), Some(stop)) => {
+            *ms = stop.saturating_duration_since(start).as_secs_f32() * 1000.0;
+            hipSuccess
+        }
+        _ => hipErrorInvalidHandle,
+    }
+}
+
+pub unsafe fn hipIpcGetEventHandle(
+    handle: *mut hipIpcEventHandle_t,
+    event: hipEvent_t,
+) -> hipError_t {
+    let address = (event as usize).to_le_bytes();
+    (*handle).reserved = [0; 64];
+    for (dst, src) in (*handle).reserved.iter_mut().zip(address) {
+        *dst = src as c_char;
+    }
+    hipSuccess
+}
+
+pub unsafe fn hipIpcOpenEventHandle(
+    event: *mut hipEvent_t,
+    handle: hipIpcEventHandle_t,
+) -> hipError_t {
+    let mut address = [0u8; std::mem::size_of::<usize>()];
+    for (dst, src) in address.iter_mut().zip(handle.reserved) {
+        *dst = src as u8;
+    }
+    *event = usize::from_le_bytes(address) as hipEvent_t;
+    hipSuccess
+}
+
+pub unsafe fn hipStreamCreate(stream: *mut hipStream_t) -> hipError_t {
+    *stream = unique_handle();
+    hipSuccess
+}
+
+pub unsafe fn hipStreamCreateWithFlags(stream: *mut hipStream_t, _flags: c_uint) -> hipError_t {
+    *stream = unique_handle();
+    hipSuccess
+}
+
+/// Least and greatest stream priority, as reported by current AMD devices.
+const STREAM_PRIORITY_RANGE: (c_int, c_int) = (0, -1);
+
+static STREAM_PRIORITIES: Mutex<Option<HashMap<usize, c_int>>> = Mutex::new(None);
+
+pub unsafe fn hipDeviceGetStreamPriorityRange(
+    least_priority: *mut c_int,
+    greatest_priority: *mut c_int,
+) -> hipError_t {
+    *least_priority = STREAM_PRIORITY_RANGE.0;
+    *greatest_priority = STREAM_PRIORITY_RANGE.1;
+    hipSuccess
+}
+
+pub unsafe fn hipStreamCreateWithPriority(
+    stream: *mut hipStream_t,
+    _flags: c_uint,
+    priority: c_int,
+) -> hipError_t {
+    *stream = unique_handle();
+    let (least, greatest) = STREAM_PRIORITY_RANGE;
+    let mut priorities = STREAM_PRIORITIES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    priorities
+        .get_or_insert_with(HashMap::new)
+        .insert(*stream as usize, priority.clamp(greatest, least));
+    hipSuccess
+}
+
+pub unsafe fn hipStreamGetPriority(stream: hipStream_t, priority: *mut c_int) -> hipError_t {
+    let priorities = STREAM_PRIORITIES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *priority = priorities
+        .as_ref()
+        .and_then(|priorities| priorities.get(&(stream as usize)))
+        .copied()
+        .unwrap_or(STREAM_PRIORITY_RANGE.0);
+    hipSuccess
+}
+
+pub unsafe fn hipStreamDestroy(_stream: hipStream_t) -> hipError_t {
+    hipSuccess
+}
+
+pub unsafe fn hipStreamSynchronize(_stream: hipStream_t) -> hipError_t {
+    hipSuccess
+}
+
+pub unsafe fn hipStreamGetDevice(_stream: hipStream_t, device: *mut c_int) -> hipError_t {
+    *device = 0;
+    hipSuccess
+}
+
+// Streams are synchronous, so the callback runs right away
+pub unsafe fn hipLaunchHostFunc(
+    _stream: hipStream_t,
+    fn_: hipHostFn_t,
+    user_data: *mut c_void,
+) -> hipError_t {
+    match fn_ {
+        Some(callback) => {
+            callback(user_data);
+            hipSuccess
+        }
+        None => hipErrorInvalidValue,
+    }
+}
+
+pub unsafe fn hipStreamQuery(_stream: hipStream_t) -> hipError_t {
+    hipSuccess
+}
+
+pub unsafe fn hipStreamWaitValue32(
+    _stream: hipStream_t,
+    _ptr: *mut c_void,
+    _value: u32,
+    _flags: c_uint,
+    _mask: u32,
+) -> hipError_t {
+    hipSuccess
+}
+
+pub unsafe fn hipStreamWaitValue64(
+    _stream: hipStream_t,
+    _ptr: *mut c_void,
+    _value: u64,
+    _flags: c_uint,
+    _mask: u64,
+) -> hipError_t {
+    hipSuccess
+}
+
+pub unsafe fn hipStreamWriteValue32(
+    _stream: hipStream_t,
+    ptr: *mut c_void,
+    value: u32,
+    _flags: c_uint,
+) -> hipError_t {
+    *(ptr as *mut u32) = value;
+    hipSuccess
+}
+
+pub unsafe fn hipStreamWriteValue64(
+    _stream: hipStream_t,
+    ptr: *mut c_void,
+    value: u64,
+    _flags: c_uint,
+) -> hipError_t {
+    *(ptr as *mut u64) = value;
+    hipSuccess
+}
+
+pub unsafe fn hipblasCreate(handle: *mut hipblasHandle_t) -> hipblasStatus_t {
+    *handle = unique_handle();
+    HIPBLAS_STATUS_SUCCESS
+}
+
+pub unsafe fn hipblasDestroy(handle: hipblasHandle_t) -> hipblasStatus_t {
+    check_blas_handle(handle)
+}
+
+/// The stream last set on each hipBLAS handle, keyed by handle address.
+static BLAS_STREAMS: Mutex<Option<HashMap<usize, usize>>> = Mutex::new(None);
+
+pub unsafe fn hipblasSetStream(handle: hipblasHandle_t, stream: hipStream_t) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status == HIPBLAS_STATUS_SUCCESS {
+        let mut streams = BLAS_STREAMS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        streams
+            .get_or_insert_with(HashMap::new)
+            .insert(handle as usize, stream as usize);
+    }
+    status
+}
+
+pub unsafe fn hipblasGetStream(
+    handle: hipblasHandle_t,
+    stream: *mut hipStream_t,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status == HIPBLAS_STATUS_SUCCESS {
+        let streams = BLAS_STREAMS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let current = streams
+            .as_ref()
+            .and_then(|streams| streams.get(&(handle as usize)))
+            .copied()
+            .unwrap_or(0);
+        *stream = current as hipStream_t;
+    }
+    status
+}
+
+/// The pointer mode last set on each hipBLAS handle, keyed by handle address.
+static BLAS_POINTER_MODES: Mutex<Option<HashMap<usize, hipblasPointerMode_t>>> = Mutex::new(None);
+
+pub unsafe fn hipblasSetPointerMode(
+    handle: hipblasHandle_t,
+    mode: hipblasPointerMode_t,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status == HIPBLAS_STATUS_SUCCESS {
+        let mut modes = BLAS_POINTER_MODES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        modes
+            .get_or_insert_with(HashMap::new)
+            .insert(handle as usize, mode);
+    }
+    status
+}
+
+pub unsafe fn hipblasGetPointerMode(
+    handle: hipblasHandle_t,
+    mode: *mut hipblasPointerMode_t,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status == HIPBLAS_STATUS_SUCCESS {
+        let modes = BLAS_POINTER_MODES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *mode = modes
+            .as_ref()
+            .and_then(|modes| modes.get(&(handle as usize)))
+            .copied()
+            .unwrap_or(0);
+    }
+    status
+}
+
+static BLAS_ATOMICS_MODES: Mutex<Option<HashMap<usize, hipblasAtomicsMode_t>>> = Mutex::new(None);
+
+pub unsafe fn hipblasSetAtomicsMode(
+    handle: hipblasHandle_t,
+    mode: hipblasAtomicsMode_t,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status == HIPBLAS_STATUS_SUCCESS {
+        let mut modes = BLAS_ATOMICS_MODES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        modes
+            .get_or_insert_with(HashMap::new)
+            .insert(handle as usize, mode);
+    }
+    status
+}
+
+pub unsafe fn hipblasGetAtomicsMode(
+    handle: hipblasHandle_t,
+    mode: *mut hipblasAtomicsMode_t,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status == HIPBLAS_STATUS_SUCCESS {
+        let modes = BLAS_ATOMICS_MODES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Atomics are allowed by default
+        *mode = modes
+            .as_ref()
+            .and_then(|modes| modes.get(&(handle as usize)))
+            .copied()
+            .unwrap_or(1);
+    }
+    status
+}
+
+/// Runs `f` over the `n` strided elements of `x` after validating the arguments.
+unsafe fn for_each_strided(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    mut f: impl FnMut(usize, f32),
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS {
+        return status;
+    }
+    if n <= 0 || incx <= 0 {
+        return HIPBLAS_STATUS_SUCCESS;
+    }
+    if x.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+    for i in 0..n as usize {
+        f(i, *x.add(i * incx as usize));
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+pub unsafe fn hipblasSasum(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    result: *mut f32,
+) -> hipblasStatus_t {
+    let mut sum = 0.0;
+    let status = for_each_strided(handle, n, x, incx, |_, value| sum += value.abs());
+    if status == HIPBLAS_STATUS_SUCCESS {
+        *result = sum;
+    }
+    status
+}
+
+/// Finds the 1-based index of the first element whose magnitude wins `better`.
+unsafe fn find_index(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    result: *mut c_int,
+    better: impl Fn(f32, f32) -> bool,
+) -> hipblasStatus_t {
+    let mut best: Option<(usize, f32)> = None;
+    let status = for_each_strided(handle, n, x, incx, |i, value| match best {
+        Some((_, magnitude)) if !better(value.abs(), magnitude) => {}
+        _ => best = Some((i, value.abs())),
+    });
+    if status == HIPBLAS_STATUS_SUCCESS {
+        *result = best.map_or(0, |(i, _)| i as c_int + 1);
+    }
+    status
+}
+
+pub unsafe fn hipblasIsamax(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    result: *mut c_int,
+) -> hipblasStatus_t {
+    find_index(handle, n, x, incx, result, |a, b| a > b)
+}
+
+pub unsafe fn hipblasIsamin(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    result: *mut c_int,
+) -> hipblasStatus_t {
+    find_index(handle, n, x, incx, result, |a, b| a < b)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn hipblasGemmEx(
+    handle: hipblasHandle_t,
+    trans_a: hipblasOperation_t,
+    trans_b: hipblasOperation_t,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: *const c_void,
+    a: *const c_void,
+    a_type: hipblasDatatype_t,
+    lda: c_int,
+    b: *const c_void,
+    b_type: hipblasDatatype_t,
+    ldb: c_int,
+    beta: *const c_void,
+    c: *mut c_void,
+    c_type: hipblasDatatype_t,
+    ldc: c_int,
+    compute_type: hipblasDatatype_t,
+    _algo: hipblasGemmAlgo_t,
+) -> hipblasStatus_t {
+    let status = check_gemm_args(handle, m, n, k);
+    if status != HIPBLAS_STATUS_SUCCESS {
+        return status;
+    }
+
+    // Only the fp32 and int8 combinations are computed, others are accepted as is
+    match (a_type, b_type, c_type, compute_type) {
+        (HIPBLAS_R_32F, HIPBLAS_R_32F, HIPBLAS_R_32F, HIPBLAS_R_32F) => reference_gemm::<f32, f32>(
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            *(alpha as *const f32),
+            a as *const f32,
+            lda,
+            b as *const f32,
+            ldb,
+            *(beta as *const f32),
+            c as *mut f32,
+            ldc,
+        ),
+        (HIPBLAS_R_16F, HIPBLAS_R_16F, HIPBLAS_R_32F, HIPBLAS_R_32F) => {
+            reference_gemm::<Half, f32>(
+                trans_a,
+                trans_b,
+                m,
+                n,
+                k,
+                *(alpha as *const f32),
+                a as *const Half,
+                lda,
+                b as *const Half,
+                ldb,
+                *(beta as *const f32),
+                c as *mut f32,
+                ldc,
+            )
+        }
+        (HIPBLAS_R_8I, HIPBLAS_R_8I, HIPBLAS_R_32I, HIPBLAS_R_32I) => reference_gemm::<i8, i32>(
+            trans_a,
+            trans_b,
+            m,
+            n,
+            k,
+            *(alpha as *const i32),
+            a as *const i8,
+            lda,
+            b as *const i8,
+            ldb,
+            *(beta as *const i32),
+            c as *mut i32,
+            ldc,
+        ),
+        _ => {}
+    }
+    status
+}
+
+fn check_blas_handle(handle: hipblasHandle_t) -> hipblasStatus_t {
+    if handle.is_null() {
+        HIPBLAS_STATUS_NOT_INITIALIZED
+    } else {
+        HIPBLAS_STATUS_SUCCESS
+    }
+}
+
+fn check_gemm_args(handle: hipblasHandle_t, m: c_int, n: c_int, k: c_int) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS {
+        return status;
+    }
+    if m < 0 || n < 0 || k < 0 {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+/// Element types the reference GEMM can compute with.
+trait Scalar: Copy + PartialEq {
+    const ZERO: Self;
+    fn add(self, other: Self) -> Self;
+    fn mul(self, other: Self) -> Self;
+}
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+    fn mul(self, other: Self) -> Self {
+        self * other
+    }
+}
+
+impl Scalar for i32 {
+    const ZERO: Self = 0;
+    fn add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+    fn mul(self, other: Self) -> Self {
+        self.wrapping_mul(other)
+    }
+}
+
+impl Scalar for f64 {
+    const ZERO: Self = 0.0;
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+    fn mul(self, other: Self) -> Self {
+        self * other
+    }
+}
+
+impl Scalar for hipblasComplex {
+    const ZERO: Self = hipblasComplex { x: 0.0, y: 0.0 };
+    fn add(self, other: Self) -> Self {
+        hipblasComplex {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+    fn mul(self, other: Self) -> Self {
+        hipblasComplex {
+            x: self.x * other.x - self.y * other.y,
+            y: self.x * other.y + self.y * other.x,
+        }
+    }
+}
+
+impl Scalar for hipblasDoubleComplex {
+    const ZERO: Self = hipblasDoubleComplex { x: 0.0, y: 0.0 };
+    fn add(self, other: Self) -> Self {
+        hipblasDoubleComplex {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+    fn mul(self, other: Self) -> Self {
+        hipblasDoubleComplex {
+            x: self.x * other.x - self.y * other.y,
+            y: self.x * other.y + self.y * other.x,
+        }
+    }
+}
+
+/// Strided `y = alpha * x + y` on host memory.
+///
+/// Negative increments walk the vectors backwards, as in the reference BLAS.
+unsafe fn reference_axpy<T: Scalar>(
+    handle: hipblasHandle_t,
+    n: c_int,
+    alpha: *const T,
+    x: *const T,
+    incx: c_int,
+    y: *mut T,
+    incy: c_int,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || n <= 0 {
+        return status;
+    }
+    if alpha.is_null() || x.is_null() || y.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let start = |inc: c_int| {
+        if inc < 0 {
+            (n - 1) as isize * -inc as isize
+        } else {
+            0
+        }
+    };
+    let (x_start, y_start) = (start(incx), start(incy));
+    for i in 0..n as isize {
+        let xi = *x.offset(x_start + i * incx as isize);
+        let yi = y.offset(y_start + i * incy as isize);
+        *yi = (*alpha).mul(xi).add(*yi);
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+/// Reduces each vector of a strided batch with `reduce` into `result`.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reduce_strided_batched<T: Copy, R>(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *const T,
+    incx: c_int,
+    stride_x: hipblasStride,
+    batch_count: c_int,
+    result: *mut R,
+    reduce: impl Fn(&[T]) -> R,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS {
+        return status;
+    }
+    if batch_count < 0 || result.is_null() || (n > 0 && batch_count > 0 && x.is_null()) {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    for batch in 0..batch_count as isize {
+        let values: Vec<T> = (0..n.max(0) as isize)
+            .map(|i| *x.offset(batch * stride_x as isize + i * incx.max(1) as isize))
+            .collect();
+        *result.offset(batch) = reduce(&values);
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+/// 1-based index of the first element with the largest magnitude, 0 if empty.
+fn index_of_max_magnitude(values: impl Iterator<Item = f64>) -> c_int {
+    best_index(values.map(f64::abs), |a, b| a > b)
+}
+
+/// 1-based index of the first magnitude that wins `better`, 0 if empty.
+fn best_index(magnitudes: impl Iterator<Item = f64>, better: fn(f64, f64) -> bool) -> c_int {
+    let mut best: Option<(usize, f64)> = None;
+    for (i, magnitude) in magnitudes.enumerate() {
+        if best.is_none_or(|(_, best)| better(magnitude, best)) {
+            best = Some((i, magnitude));
+        }
+    }
+    best.map_or(0, |(i, _)| i as c_int + 1)
+}
+
+macro_rules! mock_iamax_iamin {
+    ($ty:ty, $iamax:ident, $iamin:ident, $magnitude:expr) => {
+        pub unsafe fn $iamax(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *const $ty,
+            incx: c_int,
+            result: *mut c_int,
+        ) -> hipblasStatus_t {
+            reduce_strided_batched(handle, n, x, incx, 0, 1, result, |v| {
+                let magnitude: fn(&$ty) -> f64 = $magnitude;
+                best_index(v.iter().map(magnitude), |a, b| a > b)
+            })
+        }
+
+        pub unsafe fn $iamin(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *const $ty,
+            incx: c_int,
+            result: *mut c_int,
+        ) -> hipblasStatus_t {
+            reduce_strided_batched(handle, n, x, incx, 0, 1, result, |v| {
+                let magnitude: fn(&$ty) -> f64 = $magnitude;
+                best_index(v.iter().map(magnitude), |a, b| a < b)
+            })
+        }
+    };
+}
+
+mock_iamax_iamin!(f64, hipblasIdamax, hipblasIdamin, |x| x.abs());
+mock_iamax_iamin!(hipblasComplex, hipblasIcamax, hipblasIcamin, |c| {
+    (c.x.abs() + c.y.abs()) as f64
+});
+mock_iamax_iamin!(hipblasDoubleComplex, hipblasIzamax, hipblasIzamin, |c| {
+    c.x.abs() + c.y.abs()
+});
+
+macro_rules! mock_strided_reductions {
+    ($ty:ty, $asum:ident, $nrm2:ident, $iamax:ident) => {
+        pub unsafe fn $asum(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *const $ty,
+            incx: c_int,
+            stride_x: hipblasStride,
+            batch_count: c_int,
+            result: *mut $ty,
+        ) -> hipblasStatus_t {
+            reduce_strided_batched(handle, n, x, incx, stride_x, batch_count, result, |v| {
+                v.iter().map(|x| x.abs()).sum()
+            })
+        }
+
+        pub unsafe fn $nrm2(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *const $ty,
+            incx: c_int,
+            stride_x: hipblasStride,
+            batch_count: c_int,
+            result: *mut $ty,
+        ) -> hipblasStatus_t {
+            reduce_strided_batched(handle, n, x, incx, stride_x, batch_count, result, |v| {
+                v.iter().map(|x| x * x).sum::<$ty>().sqrt()
+            })
+        }
+
+        pub unsafe fn $iamax(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *const $ty,
+            incx: c_int,
+            stride_x: hipblasStride,
+            batch_count: c_int,
+            result: *mut c_int,
+        ) -> hipblasStatus_t {
+            reduce_strided_batched(handle, n, x, incx, stride_x, batch_count, result, |v| {
+                index_of_max_magnitude(v.iter().map(|&x| x as f64))
+            })
+        }
+    };
+}
+
+mock_strided_reductions!(
+    f32,
+    hipblasSasumStridedBatched,
+    hipblasSnrm2StridedBatched,
+    hipblasIsamaxStridedBatched
+);
+mock_strided_reductions!(
+    f64,
+    hipblasDasumStridedBatched,
+    hipblasDnrm2StridedBatched,
+    hipblasIdamaxStridedBatched
+);
+
+macro_rules! mock_nrm2 {
+    ($name:ident, $ty:ty, $real:ty, $square:expr) => {
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *const $ty,
+            incx: c_int,
+            result: *mut $real,
+        ) -> hipblasStatus_t {
+            reduce_strided_batched(handle, n, x, incx, 0, 1, result, |v| {
+                let square: fn(&$ty) -> $real = $square;
+                v.iter().map(square).sum::<$real>().sqrt()
+            })
+        }
+    };
+}
+
+macro_rules! mock_asum {
+    ($name:ident, $ty:ty, $real:ty, $magnitude:expr) => {
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *const $ty,
+            incx: c_int,
+            result: *mut $real,
+        ) -> hipblasStatus_t {
+            reduce_strided_batched(handle, n, x, incx, 0, 1, result, |v| {
+                let magnitude: fn(&$ty) -> $real = $magnitude;
+                v.iter().map(magnitude).sum()
+            })
+        }
+    };
+}
+
+mock_asum!(hipblasDasum, f64, f64, |x| x.abs());
+mock_asum!(hipblasScasum, hipblasComplex, f32, |c| c.x.abs()
+    + c.y.abs());
+mock_asum!(hipblasDzasum, hipblasDoubleComplex, f64, |c| c.x.abs()
+    + c.y.abs());
+
+mock_nrm2!(hipblasSnrm2, f32, f32, |x| x * x);
+mock_nrm2!(hipblasDnrm2, f64, f64, |x| x * x);
+mock_nrm2!(hipblasScnrm2, hipblasComplex, f32, |c| c.x * c.x
+    + c.y * c.y);
+mock_nrm2!(hipblasDznrm2, hipblasDoubleComplex, f64, |c| c.x * c.x
+    + c.y * c.y);
+
+/// Strided dot product on host memory.
+unsafe fn reference_dot<T: Scalar>(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *const T,
+    incx: c_int,
+    y: *const T,
+    incy: c_int,
+    result: *mut T,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS {
+        return status;
+    }
+    if result.is_null() || (n > 0 && (x.is_null() || y.is_null())) {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let start = |inc: c_int| {
+        if inc < 0 {
+            (n - 1) as isize * -inc as isize
+        } else {
+            0
+        }
+    };
+    let (x_start, y_start) = (start(incx), start(incy));
+    let mut sum = T::ZERO;
+    for i in 0..n.max(0) as isize {
+        let xi = *x.offset(x_start + i * incx as isize);
+        let yi = *y.offset(y_start + i * incy as isize);
+        sum = sum.add(xi.mul(yi));
+    }
+    *result = sum;
+    HIPBLAS_STATUS_SUCCESS
+}
+
+pub unsafe fn hipblasSdot(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    result: *mut f32,
+) -> hipblasStatus_t {
+    reference_dot(handle, n, x, incx, y, incy, result)
+}
+
+pub unsafe fn hipblasDdot(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    result: *mut f64,
+) -> hipblasStatus_t {
+    reference_dot(handle, n, x, incx, y, incy, result)
+}
+
+/// Complex types whose conjugate the reference DOTC can take.
+trait Conjugate: Scalar {
+    fn conj(self) -> Self;
+}
+
+impl Conjugate for hipblasComplex {
+    fn conj(self) -> Self {
+        hipblasComplex {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Conjugate for hipblasDoubleComplex {
+    fn conj(self) -> Self {
+        hipblasDoubleComplex {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+}
+
+macro_rules! mock_dotc {
+    ($name:ident, $ty:ty) => {
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *const $ty,
+            incx: c_int,
+            y: *const $ty,
+            incy: c_int,
+            result: *mut $ty,
+        ) -> hipblasStatus_t {
+            if x.is_null() {
+                return reference_dot(handle, n, x, incx, y, incy, result);
+            }
+            // conj(x) . y, with x conjugated into a host copy first. The copy keeps
+            // the stride so that negative increments walk it the same way.
+            let len = 1 + (n.max(1) - 1) as usize * incx.unsigned_abs() as usize;
+            let conjugated: Vec<$ty> = (0..len).map(|i| (*x.add(i)).conj()).collect();
+            reference_dot(handle, n, conjugated.as_ptr(), incx, y, incy, result)
+        }
+    };
+}
+
+mock_dotc!(hipblasCdotc, hipblasComplex);
+mock_dotc!(hipblasZdotc, hipblasDoubleComplex);
+
+/// Strided `x = alpha * x` on host memory, with a scalar that may differ in type.
+unsafe fn reference_scal<A: Copy + Into<T>, T: Scalar>(
+    handle: hipblasHandle_t,
+    n: c_int,
+    alpha: *const A,
+    x: *mut T,
+    incx: c_int,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || n <= 0 || incx <= 0 {
+        return status;
+    }
+    if alpha.is_null() || x.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let alpha: T = (*alpha).into();
+    for i in 0..n as usize {
+        let xi = x.add(i * incx as usize);
+        *xi = alpha.mul(*xi);
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+impl From<f32> for hipblasComplex {
+    fn from(x: f32) -> Self {
+        hipblasComplex { x, y: 0.0 }
+    }
+}
+
+impl From<f64> for hipblasDoubleComplex {
+    fn from(x: f64) -> Self {
+        hipblasDoubleComplex { x, y: 0.0 }
+    }
+}
+
+macro_rules! mock_scal {
+    ($name:ident, $alpha:ty, $ty:ty) => {
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            n: c_int,
+            alpha: *const $alpha,
+            x: *mut $ty,
+            incx: c_int,
+        ) -> hipblasStatus_t {
+            reference_scal(handle, n, alpha, x, incx)
+        }
+    };
+}
+
+mock_scal!(hipblasSscal, f32, f32);
+mock_scal!(hipblasDscal, f64, f64);
+mock_scal!(hipblasCscal, hipblasComplex, hipblasComplex);
+mock_scal!(hipblasZscal, hipblasDoubleComplex, hipblasDoubleComplex);
+mock_scal!(hipblasCsscal, f32, hipblasComplex);
+mock_scal!(hipblasZdscal, f64, hipblasDoubleComplex);
+
+/// Strided element pairs `(x[i], y[i])` of two vectors on host memory.
+unsafe fn strided_pairs<T>(
+    n: c_int,
+    x: *mut T,
+    incx: c_int,
+    y: *mut T,
+    incy: c_int,
+) -> impl Iterator<Item = (*mut T, *mut T)> {
+    let start = move |inc: c_int| {
+        if inc < 0 {
+            (n - 1) as isize * -inc as isize
+        } else {
+            0
+        }
+    };
+    let (x_start, y_start) = (start(incx), start(incy));
+    (0..n.max(0) as isize).map(move |i| {
+        (
+            x.offset(x_start + i * incx as isize),
+            y.offset(y_start + i * incy as isize),
+        )
+    })
+}
+
+macro_rules! mock_copy_swap {
+    ($ty:ty, $copy:ident, $swap:ident) => {
+        pub unsafe fn $copy(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *const $ty,
+            incx: c_int,
+            y: *mut $ty,
+            incy: c_int,
+        ) -> hipblasStatus_t {
+            let status = check_blas_handle(handle);
+            if status != HIPBLAS_STATUS_SUCCESS || n <= 0 {
+                return status;
+            }
+            if x.is_null() || y.is_null() {
+                return HIPBLAS_STATUS_INVALID_VALUE;
+            }
+            for (xi, yi) in strided_pairs(n, x as *mut $ty, incx, y, incy) {
+                *yi = *xi;
+            }
+            HIPBLAS_STATUS_SUCCESS
+        }
+
+        pub unsafe fn $swap(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *mut $ty,
+            incx: c_int,
+            y: *mut $ty,
+            incy: c_int,
+        ) -> hipblasStatus_t {
+            let status = check_blas_handle(handle);
+            if status != HIPBLAS_STATUS_SUCCESS || n <= 0 {
+                return status;
+            }
+            if x.is_null() || y.is_null() {
+                return HIPBLAS_STATUS_INVALID_VALUE;
+            }
+            for (xi, yi) in strided_pairs(n, x, incx, y, incy) {
+                std::ptr::swap(xi, yi);
+            }
+            HIPBLAS_STATUS_SUCCESS
+        }
+    };
+}
+
+mock_copy_swap!(f32, hipblasScopy, hipblasSswap);
+mock_copy_swap!(f64, hipblasDcopy, hipblasDswap);
+mock_copy_swap!(hipblasComplex, hipblasCcopy, hipblasCswap);
+mock_copy_swap!(hipblasDoubleComplex, hipblasZcopy, hipblasZswap);
+
+/// Scalars the reference Givens rotation can negate, on top of conjugating.
+trait Rotate: Conjugate {
+    fn neg(self) -> Self;
+}
+
+impl Conjugate for f32 {
+    fn conj(self) -> Self {
+        self
+    }
+}
+
+impl Conjugate for f64 {
+    fn conj(self) -> Self {
+        self
+    }
+}
+
+impl Rotate for f32 {
+    fn neg(self) -> Self {
+        -self
+    }
+}
+
+impl Rotate for f64 {
+    fn neg(self) -> Self {
+        -self
+    }
+}
+
+impl Rotate for hipblasComplex {
+    fn neg(self) -> Self {
+        hipblasComplex {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Rotate for hipblasDoubleComplex {
+    fn neg(self) -> Self {
+        hipblasDoubleComplex {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+/// Strided `x = c * x + s * y`, `y = c * y - conj(s) * x` on host memory.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_rot<R: Copy + Into<T>, T: Rotate>(
+    handle: hipblasHandle_t,
+    n: c_int,
+    x: *mut T,
+    incx: c_int,
+    y: *mut T,
+    incy: c_int,
+    c: *const R,
+    s: *const T,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || n <= 0 {
+        return status;
+    }
+    if x.is_null() || y.is_null() || c.is_null() || s.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let (c, s): (T, T) = ((*c).into(), *s);
+    for (xi, yi) in strided_pairs(n, x, incx, y, incy) {
+        let (xv, yv) = (*xi, *yi);
+        *xi = c.mul(xv).add(s.mul(yv));
+        *yi = c.mul(yv).add(s.conj().neg().mul(xv));
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_rot {
+    ($name:ident, $real:ty, $ty:ty) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            n: c_int,
+            x: *mut $ty,
+            incx: c_int,
+            y: *mut $ty,
+            incy: c_int,
+            c: *const $real,
+            s: *const $ty,
+        ) -> hipblasStatus_t {
+            reference_rot(handle, n, x, incx, y, incy, c, s)
+        }
+    };
+}
+
+mock_rot!(hipblasSrot, f32, f32);
+mock_rot!(hipblasDrot, f64, f64);
+mock_rot!(hipblasCrot, f32, hipblasComplex);
+mock_rot!(hipblasZrot, f64, hipblasDoubleComplex);
+
+macro_rules! mock_real_rotg {
+    ($name:ident, $ty:ty) => {
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            a: *mut $ty,
+            b: *mut $ty,
+            c: *mut $ty,
+            s: *mut $ty,
+        ) -> hipblasStatus_t {
+            let status = check_blas_handle(handle);
+            if status != HIPBLAS_STATUS_SUCCESS {
+                return status;
+            }
+            if a.is_null() || b.is_null() || c.is_null() || s.is_null() {
+                return HIPBLAS_STATUS_INVALID_VALUE;
+            }
+
+            // Reference BLAS SROTG
+            let (av, bv) = (*a, *b);
+            let roe = if av.abs() > bv.abs() { av } else { bv };
+            let scale = av.abs() + bv.abs();
+            if scale == 0.0 {
+                (*c, *s, *a, *b) = (1.0, 0.0, 0.0, 0.0);
+                return HIPBLAS_STATUS_SUCCESS;
+            }
+            let r = scale * ((av / scale).powi(2) + (bv / scale).powi(2)).sqrt();
+            let r = if roe < 0.0 { -r } else { r };
+            let (cv, sv) = (av / r, bv / r);
+            let z = if av.abs() > bv.abs() {
+                sv
+            } else if cv != 0.0 {
+                1.0 / cv
+            } else {
+                1.0
+            };
+            (*c, *s, *a, *b) = (cv, sv, r, z);
+            HIPBLAS_STATUS_SUCCESS
+        }
+    };
+}
+
+mock_real_rotg!(hipblasSrotg, f32);
+mock_real_rotg!(hipblasDrotg, f64);
+
+macro_rules! mock_complex_rotg {
+    ($name:ident, $real:ty, $ty:ident) => {
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            a: *mut $ty,
+            b: *mut $ty,
+            c: *mut $real,
+            s: *mut $ty,
+        ) -> hipblasStatus_t {
+            let status = check_blas_handle(handle);
+            if status != HIPBLAS_STATUS_SUCCESS {
+                return status;
+            }
+            if a.is_null() || b.is_null() || c.is_null() || s.is_null() {
+                return HIPBLAS_STATUS_INVALID_VALUE;
+            }
+
+            // Reference BLAS CROTG
+            let (av, bv) = (*a, *b);
+            let abs_a = av.x.hypot(av.y);
+            let abs_b = bv.x.hypot(bv.y);
+            if abs_a == 0.0 {
+                *c = 0.0;
+                *s = $ty { x: 1.0, y: 0.0 };
+                *a = bv;
+                return HIPBLAS_STATUS_SUCCESS;
+            }
+            let scale = abs_a + abs_b;
+            let norm = scale * ((abs_a / scale).powi(2) + (abs_b / scale).powi(2)).sqrt();
+            let alpha = $ty {
+                x: av.x / abs_a,
+                y: av.y / abs_a,
+            };
+            let sv = alpha.mul(bv.conj());
+            *c = abs_a / norm;
+            *s = $ty {
+                x: sv.x / norm,
+                y: sv.y / norm,
+            };
+            *a = $ty {
+                x: alpha.x * norm,
+                y: alpha.y * norm,
+            };
+            HIPBLAS_STATUS_SUCCESS
+        }
+    };
+}
+
+mock_complex_rotg!(hipblasCrotg, f32, hipblasComplex);
+mock_complex_rotg!(hipblasZrotg, f64, hipblasDoubleComplex);
+
+macro_rules! mock_axpy {
+    ($name:ident, $ty:ty) => {
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            n: c_int,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: c_int,
+            y: *mut $ty,
+            incy: c_int,
+        ) -> hipblasStatus_t {
+            reference_axpy(handle, n, alpha, x, incx, y, incy)
+        }
+    };
+}
+
+mock_axpy!(hipblasSaxpy, f32);
+mock_axpy!(hipblasDaxpy, f64);
+mock_axpy!(hipblasCaxpy, hipblasComplex);
+mock_axpy!(hipblasZaxpy, hipblasDoubleComplex);
+
+/// The bits of an IEEE 754 half precision value, widened to f32 by the reference GEMM.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+struct Half(u16);
+
+impl From<Half> for f32 {
+    fn from(half: Half) -> f32 {
+        let sign = if half.0 & 0x8000 != 0 { -1.0 } else { 1.0 };
+        let exponent = i32::from((half.0 >> 10) & 0x1f);
+        let mantissa = f32::from(half.0 & 0x3ff);
+        sign * match exponent {
+            0 => mantissa * 2f32.powi(-24),
+            0x1f if mantissa == 0.0 => f32::INFINITY,
+            0x1f => f32::NAN,
+            _ => (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+        }
+    }
+}
+
+impl From<f32> for Half {
+    /// Rounds to the nearest half precision value, flushing tiny values to zero.
+    fn from(value: f32) -> Half {
+        let bits = value.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exponent = ((bits >> 23) & 0xff) as i32 - 127 + 15;
+        let mantissa = bits & 0x7f_ffff;
+        Half(if value.is_nan() {
+            0x7e00
+        } else if exponent >= 0x1f {
+            sign | 0x7c00
+        } else if exponent <= 0 {
+            if exponent < -10 {
+                sign
+            } else {
+                sign | ((mantissa | 0x80_0000) >> (14 - exponent)) as u16
+            }
+        } else {
+            // A carry out of the mantissa correctly bumps the exponent
+            sign | (((exponent as u32) << 10) + (mantissa >> 13) + ((mantissa >> 12) & 1)) as u16
+        })
+    }
+}
+
+/// Column-major `c = alpha * op(a) * op(b) + beta * c` on host memory.
+///
+/// Conjugate transposes are treated as plain transposes.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_gemm<A: Copy + Into<T>, T: Scalar>(
+    trans_a: hipblasOperation_t,
+    trans_b: hipblasOperation_t,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: T,
+    a: *const A,
+    lda: c_int,
+    b: *const A,
+    ldb: c_int,
+    beta: T,
+    c: *mut T,
+    ldc: c_int,
+) {
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+    for j in 0..n {
+        for i in 0..m {
+            let mut sum = T::ZERO;
+            for p in 0..k {
+                let a_ip: T = if trans_a == HIPBLAS_OP_N {
+                    *a.add(i + p * lda)
+                } else {
+                    *a.add(p + i * lda)
+                }
+                .into();
+                let b_pj: T = if trans_b == HIPBLAS_OP_N {
+                    *b.add(p + j * ldb)
+                } else {
+                    *b.add(j + p * ldb)
+                }
+                .into();
+                sum = sum.add(a_ip.mul(b_pj));
+            }
+
+            // As in BLAS, C is not read when beta is zero
+            let c_ij = c.add(i + j * ldc);
+            *c_ij = if beta == T::ZERO {
+                alpha.mul(sum)
+            } else {
+                alpha.mul(sum).add(beta.mul(*c_ij))
+            };
+        }
+    }
+}
+
+/// Strided `y = alpha * op(A) * x + beta * y` on host memory for a column-major A.
+///
+/// Conjugate transposes are treated as plain transposes.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_gemv<T: Scalar>(
+    handle: hipblasHandle_t,
+    trans: hipblasOperation_t,
+    m: c_int,
+    n: c_int,
+    alpha: *const T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: *const T,
+    y: *mut T,
+    incy: c_int,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || m <= 0 || n <= 0 {
+        return status;
+    }
+    if alpha.is_null() || beta.is_null() || a.is_null() || x.is_null() || y.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let (rows, cols) = if trans == HIPBLAS_OP_N {
+        (m, n)
+    } else {
+        (n, m)
+    };
+    let element = |len: c_int, inc: c_int, i: c_int| {
+        let start = if inc < 0 { (len - 1) * -inc } else { 0 };
+        (start + i * inc) as isize
+    };
+    let (alpha, beta) = (*alpha, *beta);
+    for i in 0..rows {
+        let mut sum = T::ZERO;
+        for j in 0..cols {
+            let a_ij = if trans == HIPBLAS_OP_N {
+                *a.add((i + j * lda) as usize)
+            } else {
+                *a.add((j + i * lda) as usize)
+            };
+            sum = sum.add(a_ij.mul(*x.offset(element(cols, incx, j))));
+        }
+
+        // As in BLAS, y is not read when beta is zero
+        let y_i = y.offset(element(rows, incy, i));
+        *y_i = if beta == T::ZERO {
+            alpha.mul(sum)
+        } else {
+            alpha.mul(sum).add(beta.mul(*y_i))
+        };
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_gemv {
+    ($name:ident, $ty:ty) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            trans: hipblasOperation_t,
+            m: c_int,
+            n: c_int,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: c_int,
+            x: *const $ty,
+            incx: c_int,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: c_int,
+        ) -> hipblasStatus_t {
+            reference_gemv(handle, trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+        }
+    };
+}
+
+mock_gemv!(hipblasSgemv, f32);
+mock_gemv!(hipblasDgemv, f64);
+mock_gemv!(hipblasCgemv, hipblasComplex);
+mock_gemv!(hipblasZgemv, hipblasDoubleComplex);
+
+/// Strided `A = alpha * x * y^T + A` on host memory, with y optionally conjugated.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_ger<T: Conjugate>(
+    handle: hipblasHandle_t,
+    m: c_int,
+    n: c_int,
+    alpha: *const T,
+    x: *const T,
+    incx: c_int,
+    y: *const T,
+    incy: c_int,
+    a: *mut T,
+    lda: c_int,
+    conjugate: bool,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || m <= 0 || n <= 0 {
+        return status;
+    }
+    if alpha.is_null() || x.is_null() || y.is_null() || a.is_null() || lda < m {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let element = |len: c_int, inc: c_int, i: c_int| {
+        let start = if inc < 0 { (len - 1) * -inc } else { 0 };
+        (start + i * inc) as isize
+    };
+    for j in 0..n {
+        let y_j = *y.offset(element(n, incy, j));
+        let y_j = if conjugate { y_j.conj() } else { y_j };
+        let scaled = (*alpha).mul(y_j);
+        for i in 0..m {
+            let a_ij = a.add((i + j * lda) as usize);
+            *a_ij = (*a_ij).add((*x.offset(element(m, incx, i))).mul(scaled));
+        }
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_ger {
+    ($name:ident, $ty:ty, $conjugate:expr) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            m: c_int,
+            n: c_int,
+            alpha: *const $ty,
+            x: *const $ty,
+            incx: c_int,
+            y: *const $ty,
+            incy: c_int,
+            a: *mut $ty,
+            lda: c_int,
+        ) -> hipblasStatus_t {
+            reference_ger(handle, m, n, alpha, x, incx, y, incy, a, lda, $conjugate)
+        }
+    };
+}
+
+mock_ger!(hipblasSger, f32, false);
+mock_ger!(hipblasDger, f64, false);
+mock_ger!(hipblasCgeru, hipblasComplex, false);
+mock_ger!(hipblasCgerc, hipblasComplex, true);
+mock_ger!(hipblasZgeru, hipblasDoubleComplex, false);
+mock_ger!(hipblasZgerc, hipblasDoubleComplex, true);
+
+/// Scalars the reference triangular solve can divide.
+trait Divide: Rotate {
+    fn div(self, other: Self) -> Self;
+}
+
+impl Divide for f32 {
+    fn div(self, other: Self) -> Self {
+        self / other
+    }
+}
+
+impl Divide for f64 {
+    fn div(self, other: Self) -> Self {
+        self / other
+    }
+}
+
+impl Divide for hipblasComplex {
+    fn div(self, other: Self) -> Self {
+        let denominator = other.x * other.x + other.y * other.y;
+        hipblasComplex {
+            x: (self.x * other.x + self.y * other.y) / denominator,
+            y: (self.y * other.x - self.x * other.y) / denominator,
+        }
+    }
+}
+
+impl Divide for hipblasDoubleComplex {
+    fn div(self, other: Self) -> Self {
+        let denominator = other.x * other.x + other.y * other.y;
+        hipblasDoubleComplex {
+            x: (self.x * other.x + self.y * other.y) / denominator,
+            y: (self.y * other.x - self.x * other.y) / denominator,
+        }
+    }
+}
+
+/// Strided in-place solve of `op(A) * x = b` on host memory for a triangular,
+/// column-major A.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_trsv<T: Divide>(
+    handle: hipblasHandle_t,
+    uplo: hipblasFillMode_t,
+    trans: hipblasOperation_t,
+    diag: hipblasDiagType_t,
+    n: c_int,
+    a: *const T,
+    lda: c_int,
+    x: *mut T,
+    incx: c_int,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || n <= 0 {
+        return status;
+    }
+    if a.is_null() || x.is_null() || lda < n || incx == 0 {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    // Element (i, j) of op(A)
+    let op_a = |i: c_int, j: c_int| {
+        if trans == HIPBLAS_OP_N {
+            *a.add((i + j * lda) as usize)
+        } else if trans == HIPBLAS_OP_C {
+            (*a.add((j + i * lda) as usize)).conj()
+        } else {
+            *a.add((j + i * lda) as usize)
+        }
+    };
+    let start = if incx < 0 { (n - 1) * -incx } else { 0 };
+    let x_at = |i: c_int| x.offset((start + i * incx) as isize);
+
+    // Transposing swaps which triangle of op(A) holds the data
+    let upper = (uplo == HIPBLAS_FILL_MODE_UPPER) == (trans == HIPBLAS_OP_N);
+    let rows: Vec<c_int> = if upper {
+        (0..n).rev().collect()
+    } else {
+        (0..n).collect()
+    };
+    for &i in &rows {
+        let mut sum = *x_at(i);
+        let solved: Vec<c_int> = if upper {
+            (i + 1..n).collect()
+        } else {
+            (0..i).collect()
+        };
+        for j in solved {
+            sum = sum.add(op_a(i, j).mul(*x_at(j)).neg());
+        }
+        *x_at(i) = if diag == HIPBLAS_DIAG_UNIT {
+            sum
+        } else {
+            sum.div(op_a(i, i))
+        };
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_trsv {
+    ($name:ident, $ty:ty) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            uplo: hipblasFillMode_t,
+            trans: hipblasOperation_t,
+            diag: hipblasDiagType_t,
+            n: c_int,
+            a: *const $ty,
+            lda: c_int,
+            x: *mut $ty,
+            incx: c_int,
+        ) -> hipblasStatus_t {
+            reference_trsv(handle, uplo, trans, diag, n, a, lda, x, incx)
+        }
+    };
+}
+
+mock_trsv!(hipblasStrsv, f32);
+mock_trsv!(hipblasDtrsv, f64);
+mock_trsv!(hipblasCtrsv, hipblasComplex);
+mock_trsv!(hipblasZtrsv, hipblasDoubleComplex);
+
+/// Strided `y = alpha * A * x + beta * y` on host memory for a column-major A
+/// stored in the `uplo` triangle, mirrored symmetrically or Hermitian.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_symv<T: Conjugate>(
+    handle: hipblasHandle_t,
+    uplo: hipblasFillMode_t,
+    n: c_int,
+    alpha: *const T,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    beta: *const T,
+    y: *mut T,
+    incy: c_int,
+    hermitian: bool,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || n <= 0 {
+        return status;
+    }
+    if alpha.is_null() || beta.is_null() || a.is_null() || x.is_null() || y.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+    if lda < n {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let stored = |i: c_int, j: c_int| (i <= j) == (uplo == HIPBLAS_FILL_MODE_UPPER);
+    let a_ij = |i: c_int, j: c_int| {
+        if i == j || stored(i, j) {
+            *a.add((i + j * lda) as usize)
+        } else if hermitian {
+            (*a.add((j + i * lda) as usize)).conj()
+        } else {
+            *a.add((j + i * lda) as usize)
+        }
+    };
+    let element = |inc: c_int, i: c_int| {
+        let start = if inc < 0 { (n - 1) * -inc } else { 0 };
+        (start + i * inc) as isize
+    };
+    let (alpha, beta) = (*alpha, *beta);
+    for i in 0..n {
+        let mut sum = T::ZERO;
+        for j in 0..n {
+            sum = sum.add(a_ij(i, j).mul(*x.offset(element(incx, j))));
+        }
+
+        // As in BLAS, y is not read when beta is zero
+        let y_i = y.offset(element(incy, i));
+        *y_i = if beta == T::ZERO {
+            alpha.mul(sum)
+        } else {
+            alpha.mul(sum).add(beta.mul(*y_i))
+        };
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_symv {
+    ($name:ident, $ty:ty, $hermitian:expr) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            uplo: hipblasFillMode_t,
+            n: c_int,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: c_int,
+            x: *const $ty,
+            incx: c_int,
+            beta: *const $ty,
+            y: *mut $ty,
+            incy: c_int,
+        ) -> hipblasStatus_t {
+            reference_symv(
+                handle, uplo, n, alpha, a, lda, x, incx, beta, y, incy, $hermitian,
+            )
+        }
+    };
+}
+
+mock_symv!(hipblasSsymv, f32, false);
+mock_symv!(hipblasDsymv, f64, false);
+mock_symv!(hipblasChemv, hipblasComplex, true);
+mock_symv!(hipblasZhemv, hipblasDoubleComplex, true);
+
+/// `C = alpha * op(A) + beta * op(B)` on host memory for column-major matrices.
+///
+/// Conjugate transposes are treated as plain transposes.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_geam<T: Scalar>(
+    handle: hipblasHandle_t,
+    trans_a: hipblasOperation_t,
+    trans_b: hipblasOperation_t,
+    m: c_int,
+    n: c_int,
+    alpha: *const T,
+    a: *const T,
+    lda: c_int,
+    beta: *const T,
+    b: *const T,
+    ldb: c_int,
+    c: *mut T,
+    ldc: c_int,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || m <= 0 || n <= 0 {
+        return status;
+    }
+    if alpha.is_null() || beta.is_null() || a.is_null() || c.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let op = |matrix: *const T, trans: hipblasOperation_t, ld: c_int, i: c_int, j: c_int| {
+        if trans == HIPBLAS_OP_N {
+            *matrix.add((i + j * ld) as usize)
+        } else {
+            *matrix.add((j + i * ld) as usize)
+        }
+    };
+    // Computed into a copy, hipBLAS allows C to alias A or B
+    let (alpha, beta) = (*alpha, *beta);
+    let mut result = Vec::with_capacity((m * n) as usize);
+    for j in 0..n {
+        for i in 0..m {
+            let mut value = alpha.mul(op(a, trans_a, lda, i, j));
+            // As in BLAS, B is not read when beta is zero
+            if beta != T::ZERO {
+                value = value.add(beta.mul(op(b, trans_b, ldb, i, j)));
+            }
+            result.push(value);
+        }
+    }
+    for j in 0..n {
+        for i in 0..m {
+            *c.add((i + j * ldc) as usize) = result[(i + j * m) as usize];
+        }
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_geam {
+    ($name:ident, $ty:ty) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            trans_a: hipblasOperation_t,
+            trans_b: hipblasOperation_t,
+            m: c_int,
+            n: c_int,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: c_int,
+            beta: *const $ty,
+            b: *const $ty,
+            ldb: c_int,
+            c: *mut $ty,
+            ldc: c_int,
+        ) -> hipblasStatus_t {
+            reference_geam(
+                handle, trans_a, trans_b, m, n, alpha, a, lda, beta, b, ldb, c, ldc,
+            )
+        }
+    };
+}
+
+mock_geam!(hipblasSgeam, f32);
+mock_geam!(hipblasDgeam, f64);
+mock_geam!(hipblasCgeam, hipblasComplex);
+mock_geam!(hipblasZgeam, hipblasDoubleComplex);
+
+/// Element (i, j) of op(A) for a triangular, column-major A.
+#[allow(clippy::too_many_arguments)]
+unsafe fn triangular_element<T: Conjugate>(
+    a: *const T,
+    lda: c_int,
+    uplo: hipblasFillMode_t,
+    trans: hipblasOperation_t,
+    diag: hipblasDiagType_t,
+    one: T,
+    i: c_int,
+    j: c_int,
+) -> T {
+    let (row, col) = if trans == HIPBLAS_OP_N {
+        (i, j)
+    } else {
+        (j, i)
+    };
+    let value = if row == col && diag == HIPBLAS_DIAG_UNIT {
+        one
+    } else if row == col || (row < col) == (uplo == HIPBLAS_FILL_MODE_UPPER) {
+        *a.add((row + col * lda) as usize)
+    } else {
+        T::ZERO
+    };
+    if trans == HIPBLAS_OP_C {
+        value.conj()
+    } else {
+        value
+    }
+}
+
+/// `C = alpha * op(A) * B` or `C = alpha * B * op(A)` on host memory for a
+/// triangular, column-major A.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_trmm<T: Conjugate>(
+    handle: hipblasHandle_t,
+    side: hipblasSideMode_t,
+    uplo: hipblasFillMode_t,
+    trans: hipblasOperation_t,
+    diag: hipblasDiagType_t,
+    m: c_int,
+    n: c_int,
+    alpha: *const T,
+    a: *const T,
+    lda: c_int,
+    b: *const T,
+    ldb: c_int,
+    c: *mut T,
+    ldc: c_int,
+    one: T,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || m <= 0 || n <= 0 {
+        return status;
+    }
+    if alpha.is_null() || a.is_null() || b.is_null() || c.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let op_a = |i, j| triangular_element(a, lda, uplo, trans, diag, one, i, j);
+    let b_at = |i: c_int, j: c_int| *b.add((i + j * ldb) as usize);
+    let k = if side == HIPBLAS_SIDE_LEFT { m } else { n };
+    for j in 0..n {
+        for i in 0..m {
+            let mut sum = T::ZERO;
+            for p in 0..k {
+                sum = sum.add(if side == HIPBLAS_SIDE_LEFT {
+                    op_a(i, p).mul(b_at(p, j))
+                } else {
+                    b_at(i, p).mul(op_a(p, j))
+                });
+            }
+            *c.add((i + j * ldc) as usize) = (*alpha).mul(sum);
+        }
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_trmm {
+    ($name:ident, $ty:ty, $one:expr) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            side: hipblasSideMode_t,
+            uplo: hipblasFillMode_t,
+            trans: hipblasOperation_t,
+            diag: hipblasDiagType_t,
+            m: c_int,
+            n: c_int,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: c_int,
+            b: *const $ty,
+            ldb: c_int,
+            c: *mut $ty,
+            ldc: c_int,
+        ) -> hipblasStatus_t {
+            reference_trmm(
+                handle, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb, c, ldc, $one,
+            )
+        }
+    };
+}
+
+mock_trmm!(hipblasStrmm, f32, 1.0);
+mock_trmm!(hipblasDtrmm, f64, 1.0);
+mock_trmm!(hipblasCtrmm, hipblasComplex, hipblasComplex::from(1.0));
+mock_trmm!(
+    hipblasZtrmm,
+    hipblasDoubleComplex,
+    hipblasDoubleComplex::from(1.0)
+);
+
+/// In-place solve of `op(A) * X = alpha * B` or `X * op(A) = alpha * B` on host
+/// memory for a triangular, column-major A.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_trsm<T: Divide>(
+    handle: hipblasHandle_t,
+    side: hipblasSideMode_t,
+    uplo: hipblasFillMode_t,
+    trans: hipblasOperation_t,
+    diag: hipblasDiagType_t,
+    m: c_int,
+    n: c_int,
+    alpha: *const T,
+    a: *const T,
+    lda: c_int,
+    b: *mut T,
+    ldb: c_int,
+    one: T,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || m <= 0 || n <= 0 {
+        return status;
+    }
+    if alpha.is_null() || a.is_null() || b.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let op_a = |i, j| triangular_element(a, lda, uplo, trans, diag, one, i, j);
+    let b_at = |i: c_int, j: c_int| b.add((i + j * ldb) as usize);
+    // Transposing swaps which triangle of op(A) holds the data
+    let upper = (uplo == HIPBLAS_FILL_MODE_UPPER) == (trans == HIPBLAS_OP_N);
+    let order = |k: c_int, ascending: bool| -> Vec<c_int> {
+        if ascending {
+            (0..k).collect()
+        } else {
+            (0..k).rev().collect()
+        }
+    };
+
+    if side == HIPBLAS_SIDE_LEFT {
+        // Each column of X solves op(A) * x = alpha * b
+        for j in 0..n {
+            for i in order(m, !upper) {
+                let mut sum = (*alpha).mul(*b_at(i, j));
+                let solved = if upper { i + 1..m } else { 0..i };
+                for p in solved {
+                    sum = sum.add(op_a(i, p).mul(*b_at(p, j)).neg());
+                }
+                *b_at(i, j) = sum.div(op_a(i, i));
+            }
+        }
+    } else {
+        // Each row of X solves x * op(A) = alpha * b
+        for i in 0..m {
+            for j in order(n, upper) {
+                let mut sum = (*alpha).mul(*b_at(i, j));
+                let solved = if upper { 0..j } else { j + 1..n };
+                for p in solved {
+                    sum = sum.add((*b_at(i, p)).mul(op_a(p, j)).neg());
+                }
+                *b_at(i, j) = sum.div(op_a(j, j));
+            }
+        }
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_trsm {
+    ($name:ident, $ty:ty, $one:expr) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            side: hipblasSideMode_t,
+            uplo: hipblasFillMode_t,
+            trans: hipblasOperation_t,
+            diag: hipblasDiagType_t,
+            m: c_int,
+            n: c_int,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: c_int,
+            b: *mut $ty,
+            ldb: c_int,
+        ) -> hipblasStatus_t {
+            reference_trsm(
+                handle, side, uplo, trans, diag, m, n, alpha, a, lda, b, ldb, $one,
+            )
+        }
+    };
+}
+
+mock_trsm!(hipblasStrsm, f32, 1.0);
+mock_trsm!(hipblasDtrsm, f64, 1.0);
+mock_trsm!(hipblasCtrsm, hipblasComplex, hipblasComplex::from(1.0));
+mock_trsm!(
+    hipblasZtrsm,
+    hipblasDoubleComplex,
+    hipblasDoubleComplex::from(1.0)
+);
+
+/// `C = alpha * A * B + beta * C` (left) or `C = alpha * B * A + beta * C` (right) on
+/// host memory for a symmetric, column-major A.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_symm<T: Scalar>(
+    handle: hipblasHandle_t,
+    side: hipblasSideMode_t,
+    uplo: hipblasFillMode_t,
+    m: c_int,
+    n: c_int,
+    alpha: *const T,
+    a: *const T,
+    lda: c_int,
+    b: *const T,
+    ldb: c_int,
+    beta: *const T,
+    c: *mut T,
+    ldc: c_int,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || m <= 0 || n <= 0 {
+        return status;
+    }
+    if alpha.is_null() || beta.is_null() || a.is_null() || b.is_null() || c.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let stored = |i: c_int, j: c_int| (i <= j) == (uplo == HIPBLAS_FILL_MODE_UPPER);
+    let a_ij = |i: c_int, j: c_int| {
+        if stored(i, j) {
+            *a.add((i + j * lda) as usize)
+        } else {
+            *a.add((j + i * lda) as usize)
+        }
+    };
+    let b_at = |i: c_int, j: c_int| *b.add((i + j * ldb) as usize);
+    let k = if side == HIPBLAS_SIDE_LEFT { m } else { n };
+    for j in 0..n {
+        for i in 0..m {
+            let mut sum = T::ZERO;
+            for p in 0..k {
+                sum = sum.add(if side == HIPBLAS_SIDE_LEFT {
+                    a_ij(i, p).mul(b_at(p, j))
+                } else {
+                    b_at(i, p).mul(a_ij(p, j))
+                });
+            }
+            let c_ij = c.add((i + j * ldc) as usize);
+            *c_ij = (*alpha).mul(sum).add((*beta).mul(*c_ij));
+        }
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_symm {
+    ($name:ident, $ty:ty) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            side: hipblasSideMode_t,
+            uplo: hipblasFillMode_t,
+            m: c_int,
+            n: c_int,
+            alpha: *const $ty,
+            a: *const $ty,
+            lda: c_int,
+            b: *const $ty,
+            ldb: c_int,
+            beta: *const $ty,
+            c: *mut $ty,
+            ldc: c_int,
+        ) -> hipblasStatus_t {
+            reference_symm(
+                handle, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+            )
+        }
+    };
+}
+
+mock_symm!(hipblasSsymm, f32);
+mock_symm!(hipblasDsymm, f64);
+mock_symm!(hipblasCsymm, hipblasComplex);
+mock_symm!(hipblasZsymm, hipblasDoubleComplex);
+
+/// `C = diag(x) * A` (left) or `C = A * diag(x)` (right) on host memory.
+#[allow(clippy::too_many_arguments)]
+unsafe fn reference_dgmm<T: Scalar>(
+    handle: hipblasHandle_t,
+    side: hipblasSideMode_t,
+    m: c_int,
+    n: c_int,
+    a: *const T,
+    lda: c_int,
+    x: *const T,
+    incx: c_int,
+    c: *mut T,
+    ldc: c_int,
+) -> hipblasStatus_t {
+    let status = check_blas_handle(handle);
+    if status != HIPBLAS_STATUS_SUCCESS || m <= 0 || n <= 0 {
+        return status;
+    }
+    if a.is_null() || x.is_null() || c.is_null() {
+        return HIPBLAS_STATUS_INVALID_VALUE;
+    }
+
+    let k = if side == HIPBLAS_SIDE_LEFT { m } else { n };
+    let x_at = |i: c_int| {
+        let start = if incx < 0 { (k - 1) * -incx } else { 0 };
+        *x.offset((start + i * incx) as isize)
+    };
+    for j in 0..n {
+        for i in 0..m {
+            let scale = if side == HIPBLAS_SIDE_LEFT {
+                x_at(i)
+            } else {
+                x_at(j)
+            };
+            *c.add((i + j * ldc) as usize) = scale.mul(*a.add((i + j * lda) as usize));
+        }
+    }
+    HIPBLAS_STATUS_SUCCESS
+}
+
+macro_rules! mock_dgmm {
+    ($name:ident, $ty:ty) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name(
+            handle: hipblasHandle_t,
+            side: hipblasSideMode_t,
+            m: c_int,
+            n: c_int,
+            a: *const $ty,
+            lda: c_int,
+            x: *const $ty,
+            incx: c_int,
+            c: *mut $ty,
+            ldc: c_int,
+        ) -> hipblasStatus_t {
+            reference_dgmm(handle, side, m, n, a, lda, x, incx, c, ldc)
+        }
+    };
+}
+
+mock_dgmm!(hipblasSdgmm, f32);
+mock_dgmm!(hipblasDdgmm, f64);
+mock_dgmm!(hipblasCdgmm, hipblasComplex);
+mock_dgmm!(hipblasZdgmm, hipblasDoubleComplex);
+
+macro_rules! mock_gemm {
+    ($gemm:ident, $batched:ident, $strided:ident, $t:ty, $compute:expr) => {
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $gemm(
+            handle: hipblasHandle_t,
+            trans_a: hipblasOperation_t,
+            trans_b: hipblasOperation_t,
+            m: c_int,
+            n: c_int,
+            k: c_int,
+            alpha: *const $t,
+            a: *const $t,
+            lda: c_int,
+            b: *const $t,
+            ldb: c_int,
+            beta: *const $t,
+            c: *mut $t,
+            ldc: c_int,
+        ) -> hipblasStatus_t {
+            let status = check_gemm_args(handle, m, n, k);
+            if status == HIPBLAS_STATUS_SUCCESS {
+                $compute(
+                    trans_a, trans_b, m, n, k, *alpha, a, lda, b, ldb, *beta, c, ldc,
+                );
+            }
+            status
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $batched(
+            handle: hipblasHandle_t,
+            trans_a: hipblasOperation_t,
+            trans_b: hipblasOperation_t,
+            m: c_int,
+            n: c_int,
+            k: c_int,
+            alpha: *const $t,
+            a: *const *const $t,
+            lda: c_int,
+            b: *const *const $t,
+            ldb: c_int,
+            beta: *const $t,
+            c: *const *mut $t,
+            ldc: c_int,
+            batch_count: c_int,
+        ) -> hipblasStatus_t {
+            let status = check_gemm_args(handle, m, n, k);
+            if status == HIPBLAS_STATUS_SUCCESS {
+                for i in 0..batch_count.max(0) as usize {
+                    $compute(
+                        trans_a,
+                        trans_b,
+                        m,
+                        n,
+                        k,
+                        *alpha,
+                        *a.add(i),
+                        lda,
+                        *b.add(i),
+                        ldb,
+                        *beta,
+                        *c.add(i),
+                        ldc,
+                    );
+                }
+            }
+            status
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $strided(
+            handle: hipblasHandle_t,
+            trans_a: hipblasOperation_t,
+            trans_b: hipblasOperation_t,
+            m: c_int,
+            n: c_int,
+            k: c_int,
+            alpha: *const $t,
+            a: *const $t,
+            lda: c_int,
+            stride_a: hipblasStride,
+            b: *const $t,
+            ldb: c_int,
+            stride_b: hipblasStride,
+            beta: *const $t,
+            c: *mut $t,
+            ldc: c_int,
+            stride_c: hipblasStride,
+            batch_count: c_int,
+        ) -> hipblasStatus_t {
+            let status = check_gemm_args(handle, m, n, k);
+            if status == HIPBLAS_STATUS_SUCCESS {
+                for i in 0..batch_count.max(0) as isize {
+                    $compute(
+                        trans_a,
+                        trans_b,
+                        m,
+                        n,
+                        k,
+                        *alpha,
+                        a.offset(i * stride_a as isize),
+                        lda,
+                        b.offset(i * stride_b as isize),
+                        ldb,
+                        *beta,
+                        c.offset(i * stride_c as isize),
+                        ldc,
+                    );
+                }
+            }
+            status
+        }
+    };
+}
+
+/// Half precision GEMMs accumulate in f32 and round C back to half precision.
+#[allow(clippy::too_many_arguments)]
+unsafe fn half_gemm(
+    trans_a: hipblasOperation_t,
+    trans_b: hipblasOperation_t,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: hipblasHalf,
+    a: *const hipblasHalf,
+    lda: c_int,
+    b: *const hipblasHalf,
+    ldb: c_int,
+    beta: hipblasHalf,
+    c: *mut hipblasHalf,
+    ldc: c_int,
+) {
+    let (rows, ld) = (m as usize, ldc as usize);
+    let mut wide = vec![0.0f32; ld * n as usize];
+    for (index, value) in wide.iter_mut().enumerate() {
+        if index % ld < rows {
+            *value = Half(*c.add(index)).into();
+        }
+    }
+
+    reference_gemm::<Half, f32>(
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        Half(alpha).into(),
+        a as *const Half,
+        lda,
+        b as *const Half,
+        ldb,
+        Half(beta).into(),
+        wide.as_mut_ptr(),
+        ldc,
+    );
+
+    for (index, value) in wide.into_iter().enumerate() {
+        if index % ld < rows {
+            *c.add(index) = Half::from(value).0;
+        }
+    }
+}
+
+mock_gemm!(
+    hipblasHgemm,
+    hipblasHgemmBatched,
+    hipblasHgemmStridedBatched,
+    hipblasHalf,
+    half_gemm
+);
+mock_gemm!(
+    hipblasSgemm,
+    hipblasSgemmBatched,
+    hipblasSgemmStridedBatched,
+    f32,
+    reference_gemm::<f32, f32>
+);
+mock_gemm!(
+    hipblasDgemm,
+    hipblasDgemmBatched,
+    hipblasDgemmStridedBatched,
+    f64,
+    reference_gemm::<f64, f64>
+);
+mock_gemm!(
+    hipblasCgemm,
+    hipblasCgemmBatched,
+    hipblasCgemmStridedBatched,
+    hipblasComplex,
+    reference_gemm::<hipblasComplex, hipblasComplex>
+);
+mock_gemm!(
+    hipblasZgemm,
+    hipblasZgemmBatched,
+    hipblasZgemmStridedBatched,
+    hipblasDoubleComplex,
+    reference_gemm::<hipblasDoubleComplex, hipblasDoubleComplex>
+);
+
+/// Magic number at the start of every code object, which are ELF files.
+const ELF_MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+
+pub unsafe fn hipModuleLoad(module: *mut hipModule_t, fname: *const c_char) -> hipError_t {
+    let Ok(path) = std::ffi::CStr::from_ptr(fname).to_str() else {
+        return hipErrorInvalidValue;
+    };
+    match std::fs::read(path) {
+        Ok(image) if image.starts_with(&ELF_MAGIC) => {
+            *module = unique_handle();
+            hipSuccess
+        }
+        Ok(_) => hipErrorInvalidImage,
+        Err(_) => hipErrorFileNotFound,
+    }
+}
+
+pub unsafe fn hipModuleLoadData(module: *mut hipModule_t, image: *const c_void) -> hipError_t {
+    if image.is_null() {
+        return hipErrorInvalidValue;
+    }
+    // Stop at the first mismatch, as the length of the image is not known
+    let image = image as *const u8;
+    if !(0..ELF_MAGIC.len()).all(|i| *image.add(i) == ELF_MAGIC[i]) {
+        return hipErrorInvalidImage;
+    }
+    *module = unique_handle();
+    hipSuccess
+}
+
+/// Code objects are never parsed, so no module has any functions.
+pub unsafe fn hipModuleGetFunction(
+    _function: *mut hipFunction_t,
+    _module: hipModule_t,
+    _kname: *const c_char,
+) -> hipError_t {
+    hipErrorNotFound
+}
+
+pub unsafe fn hipModuleUnload(_module: hipModule_t) -> hipError_t {
+    hipSuccess
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{get_device_count, mem_get_info, Device, DeviceAttribute, MemoryPointer};
+
+    #[test]
+    fn test_mock_device_queries() {
+        assert_eq!(get_device_count().unwrap(), 1);
+
+        let device = Device::new(0);
+        assert_eq!(device.device_total_mem().unwrap(), super::TOTAL_MEM);
+        assert_eq!(device.get_device_name().unwrap(), super::DEVICE_NAME);
+        assert_eq!(device.get_attribute(DeviceAttribute::WarpSize).unwrap(), 64);
+
+        assert!(Device::new(1).device_total_mem().is_err());
+    }
+
+    #[test]
+    fn test_mock_allocation_round_trip() {
+        let ptr = MemoryPointer::<u32>::alloc(1024).unwrap();
+        let (free, total) = mem_get_info().unwrap();
+        assert!(free <= total - 1024 * std::mem::size_of::<u32>());
+
+        let data: Vec<u32> = (0..1024).collect();
+        ptr.copy_from_host(&data).unwrap();
+
+        let mut result = vec![0u32; 1024];
+        ptr.copy_to_host(&mut result).unwrap();
+        assert_eq!(result, data);
+    }
+}